@@ -61,20 +61,32 @@ impl IyziAuthV2Generator {
     }
 
     fn get_payload(uri: &str, request_str: &str) -> String {
-        let start_index: Option<usize> = uri.find("/v2");
-        let end_index: Option<usize> = uri.find("?");
-        let uri_path = if end_index.is_none() {
-            uri.chars().skip(start_index.unwrap()).collect::<String>()
-        } else {
-            uri.chars()
-                .skip(start_index.unwrap())
-                .take(end_index.unwrap() - start_index.unwrap())
-                .collect::<String>()
-        };
-        return if request_str.is_empty() {
+        let uri_path = IyziAuthV2Generator::get_uri_path(uri);
+        if request_str.is_empty() {
             uri_path
         } else {
             format!("{}{}", uri_path, request_str)
+        }
+    }
+
+    /// Returns the signed part of `uri`: the path starting at `/v2` for v2 endpoints, otherwise
+    /// the whole path after the host, without the query string in both cases.
+    pub fn get_uri_path(uri: &str) -> String {
+        let path = match uri.find("/v2") {
+            Some(start_index) => &uri[start_index..],
+            None => match uri.find("://") {
+                Some(scheme_index) => {
+                    let authority = &uri[scheme_index + 3..];
+                    authority
+                        .find('/')
+                        .map_or("", |path_index| &authority[path_index..])
+                }
+                None => uri,
+            },
         };
+        match path.find('?') {
+            Some(end_index) => path[..end_index].to_string(),
+            None => path.to_string(),
+        }
     }
 }
@@ -9,6 +9,8 @@ pub mod model;
 pub mod options;
 pub mod requests;
 pub mod resource;
+pub mod secret;
+pub mod verification;
 
 mod client;
 mod types;
@@ -0,0 +1,605 @@
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use crate::client::HttpClient;
+use crate::model::CardAssociation;
+use crate::model::CardFamily;
+use crate::model::CardType;
+use crate::model::Phase;
+use crate::options::Options;
+use crate::requests::serialize_price;
+use crate::requests::CreateBasicBkmInitializeRequest;
+use crate::requests::CreateBasicPaymentRequest;
+use crate::requests::CreatePaymentPostAuthRequest;
+use crate::requests::CreateThreedsPaymentRequest;
+use crate::requests::RetrieveBkmRequest;
+use crate::requests::Validate;
+use crate::requests::Validator;
+use crate::resource::IyzipayResource;
+use crate::types::Result;
+
+/// Installment prices a bank offers in a basic BKM Express payment.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BkmInstallment {
+    bank_id: Option<u64>,
+
+    installment_prices: Option<Vec<BkmInstallmentPrice>>,
+}
+
+impl BkmInstallment {
+    pub fn new() -> Self {
+        BkmInstallment::default()
+    }
+
+    pub fn set_bank_id<T: Into<u64>>(&mut self, bank_id: T) {
+        self.bank_id = Some(bank_id.into());
+    }
+
+    pub fn set_installment_prices<T: Into<Vec<BkmInstallmentPrice>>>(
+        &mut self,
+        installment_prices: T,
+    ) {
+        self.installment_prices = Some(installment_prices.into());
+    }
+
+    pub fn bank_id(&self) -> Option<&u64> {
+        self.bank_id.as_ref()
+    }
+    pub fn installment_prices(&self) -> Option<&Vec<BkmInstallmentPrice>> {
+        self.installment_prices.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BkmInstallmentPrice {
+    installment_number: Option<u8>,
+
+    #[serde(serialize_with = "serialize_price")]
+    total_price: Option<BigDecimal>,
+}
+
+impl BkmInstallmentPrice {
+    pub fn new() -> Self {
+        BkmInstallmentPrice::default()
+    }
+
+    pub fn set_installment_number<T: Into<u8>>(&mut self, installment_number: T) {
+        self.installment_number = Some(installment_number.into());
+    }
+
+    pub fn set_total_price<T: Into<BigDecimal>>(&mut self, total_price: T) {
+        self.total_price = Some(total_price.into());
+    }
+
+    pub fn installment_number(&self) -> Option<&u8> {
+        self.installment_number.as_ref()
+    }
+    pub fn total_price(&self) -> Option<&BigDecimal> {
+        self.total_price.as_ref()
+    }
+}
+
+/// Result of a basic payment, which has no basket item transactions.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BasicPaymentResource {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    price: Option<BigDecimal>,
+
+    paid_price: Option<BigDecimal>,
+
+    installment: Option<u8>,
+
+    currency: Option<String>,
+
+    payment_id: Option<String>,
+
+    merchant_commission_rate: Option<BigDecimal>,
+
+    merchant_commission_rate_amount: Option<BigDecimal>,
+
+    iyzi_commission_fee: Option<BigDecimal>,
+
+    card_type: Option<CardType>,
+
+    card_association: Option<CardAssociation>,
+
+    card_family: Option<CardFamily>,
+
+    card_token: Option<String>,
+
+    card_user_key: Option<String>,
+
+    bin_number: Option<String>,
+
+    payment_transaction_id: Option<String>,
+
+    auth_code: Option<String>,
+
+    connector_name: Option<String>,
+
+    phase: Option<Phase>,
+}
+
+impl BasicPaymentResource {
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_installment<T: Into<u8>>(&mut self, installment: T) {
+        self.installment = Some(installment.into());
+    }
+
+    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_merchant_commission_rate<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate: T,
+    ) {
+        self.merchant_commission_rate = Some(merchant_commission_rate.into());
+    }
+
+    pub fn set_merchant_commission_rate_amount<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate_amount: T,
+    ) {
+        self.merchant_commission_rate_amount = Some(merchant_commission_rate_amount.into());
+    }
+
+    pub fn set_iyzi_commission_fee<T: Into<BigDecimal>>(&mut self, iyzi_commission_fee: T) {
+        self.iyzi_commission_fee = Some(iyzi_commission_fee.into());
+    }
+
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
+        self.card_type = Some(card_type.into());
+    }
+
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
+        self.card_association = Some(card_association.into());
+    }
+
+    pub fn set_card_family<T: Into<CardFamily>>(&mut self, card_family: T) {
+        self.card_family = Some(card_family.into());
+    }
+
+    pub fn set_card_token<T: Into<String>>(&mut self, card_token: T) {
+        self.card_token = Some(card_token.into());
+    }
+
+    pub fn set_card_user_key<T: Into<String>>(&mut self, card_user_key: T) {
+        self.card_user_key = Some(card_user_key.into());
+    }
+
+    pub fn set_bin_number<T: Into<String>>(&mut self, bin_number: T) {
+        self.bin_number = Some(bin_number.into());
+    }
+
+    pub fn set_payment_transaction_id<T: Into<String>>(&mut self, payment_transaction_id: T) {
+        self.payment_transaction_id = Some(payment_transaction_id.into());
+    }
+
+    pub fn set_auth_code<T: Into<String>>(&mut self, auth_code: T) {
+        self.auth_code = Some(auth_code.into());
+    }
+
+    pub fn set_connector_name<T: Into<String>>(&mut self, connector_name: T) {
+        self.connector_name = Some(connector_name.into());
+    }
+
+    pub fn set_phase<T: Into<Phase>>(&mut self, phase: T) {
+        self.phase = Some(phase.into());
+    }
+
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn installment(&self) -> Option<&u8> {
+        self.installment.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn merchant_commission_rate(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate.as_ref()
+    }
+    pub fn merchant_commission_rate_amount(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate_amount.as_ref()
+    }
+    pub fn iyzi_commission_fee(&self) -> Option<&BigDecimal> {
+        self.iyzi_commission_fee.as_ref()
+    }
+    pub fn card_type(&self) -> Option<&CardType> {
+        self.card_type.as_ref()
+    }
+    pub fn card_association(&self) -> Option<&CardAssociation> {
+        self.card_association.as_ref()
+    }
+    pub fn card_family(&self) -> Option<&CardFamily> {
+        self.card_family.as_ref()
+    }
+    pub fn card_token(&self) -> Option<&String> {
+        self.card_token.as_ref()
+    }
+    pub fn card_user_key(&self) -> Option<&String> {
+        self.card_user_key.as_ref()
+    }
+    pub fn bin_number(&self) -> Option<&String> {
+        self.bin_number.as_ref()
+    }
+    pub fn payment_transaction_id(&self) -> Option<&String> {
+        self.payment_transaction_id.as_ref()
+    }
+    pub fn auth_code(&self) -> Option<&String> {
+        self.auth_code.as_ref()
+    }
+    pub fn connector_name(&self) -> Option<&String> {
+        self.connector_name.as_ref()
+    }
+    pub fn phase(&self) -> Option<&Phase> {
+        self.phase.as_ref()
+    }
+}
+
+impl std::ops::Deref for BasicPaymentResource {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicPayment {
+    #[serde(flatten)]
+    resource: BasicPaymentResource,
+}
+
+impl BasicPayment {
+    pub fn create(req: &CreateBasicPaymentRequest, options: &Options) -> Result<BasicPayment> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/auth/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for BasicPayment {
+    type Target = BasicPaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicPaymentPreAuth {
+    #[serde(flatten)]
+    resource: BasicPaymentResource,
+}
+
+impl BasicPaymentPreAuth {
+    pub fn create(
+        req: &CreateBasicPaymentRequest,
+        options: &Options,
+    ) -> Result<BasicPaymentPreAuth> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/preauth/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for BasicPaymentPreAuth {
+    type Target = BasicPaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicPaymentPostAuth {
+    #[serde(flatten)]
+    resource: BasicPaymentResource,
+}
+
+impl BasicPaymentPostAuth {
+    pub fn create(
+        req: &CreatePaymentPostAuthRequest,
+        options: &Options,
+    ) -> Result<BasicPaymentPostAuth> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/postauth/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for BasicPaymentPostAuth {
+    type Target = BasicPaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BasicThreedsInitialize {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    #[serde(rename = "threeDSHtmlContent")]
+    html_content: Option<String>,
+}
+
+impl BasicThreedsInitialize {
+    pub fn create(
+        req: &CreateBasicPaymentRequest,
+        options: &Options,
+    ) -> Result<BasicThreedsInitialize> {
+        let mut validator = Validator::new();
+        validator.required("callbackUrl", req.callback_url());
+        validator.finish()?;
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/3dsecure/initialize/basic"
+        );
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_html_content<T: Into<String>>(&mut self, html_content: T) {
+        self.html_content = Some(html_content.into());
+    }
+
+    pub fn html_content(&self) -> Option<&String> {
+        self.html_content.as_ref()
+    }
+}
+
+impl std::ops::Deref for BasicThreedsInitialize {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BasicThreedsInitializePreAuth {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    #[serde(rename = "threeDSHtmlContent")]
+    html_content: Option<String>,
+}
+
+impl BasicThreedsInitializePreAuth {
+    pub fn create(
+        req: &CreateBasicPaymentRequest,
+        options: &Options,
+    ) -> Result<BasicThreedsInitializePreAuth> {
+        let mut validator = Validator::new();
+        validator.required("callbackUrl", req.callback_url());
+        validator.finish()?;
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/3dsecure/initialize/preauth/basic"
+        );
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_html_content<T: Into<String>>(&mut self, html_content: T) {
+        self.html_content = Some(html_content.into());
+    }
+
+    pub fn html_content(&self) -> Option<&String> {
+        self.html_content.as_ref()
+    }
+}
+
+impl std::ops::Deref for BasicThreedsInitializePreAuth {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BasicThreedsPayment {
+    #[serde(flatten)]
+    resource: BasicPaymentResource,
+}
+
+impl BasicThreedsPayment {
+    pub fn create(
+        req: &CreateThreedsPaymentRequest,
+        options: &Options,
+    ) -> Result<BasicThreedsPayment> {
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/3dsecure/auth/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for BasicThreedsPayment {
+    type Target = BasicPaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BasicBkmInitialize {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    html_content: Option<String>,
+
+    token: Option<String>,
+}
+
+impl BasicBkmInitialize {
+    pub fn create(
+        req: &CreateBasicBkmInitializeRequest,
+        options: &Options,
+    ) -> Result<BasicBkmInitialize> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/bkm/initialize/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_html_content<T: Into<String>>(&mut self, html_content: T) {
+        self.html_content = Some(html_content.into());
+    }
+
+    pub fn set_token<T: Into<String>>(&mut self, token: T) {
+        self.token = Some(token.into());
+    }
+
+    pub fn html_content(&self) -> Option<&String> {
+        self.html_content.as_ref()
+    }
+    pub fn token(&self) -> Option<&String> {
+        self.token.as_ref()
+    }
+}
+
+impl std::ops::Deref for BasicBkmInitialize {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct BasicBkm {
+    #[serde(flatten)]
+    resource: BasicPaymentResource,
+
+    token: Option<String>,
+
+    callback_url: Option<String>,
+}
+
+impl BasicBkm {
+    pub fn retrieve(req: &RetrieveBkmRequest, options: &Options) -> Result<BasicBkm> {
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/bkm/auth/detail/basic");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_token<T: Into<String>>(&mut self, token: T) {
+        self.token = Some(token.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn token(&self) -> Option<&String> {
+        self.token.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for BasicBkm {
+    type Target = BasicPaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
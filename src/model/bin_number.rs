@@ -1,6 +1,8 @@
 use crate::client::HttpClient;
+use crate::model::CardAssociation;
+use crate::model::CardFamily;
+use crate::model::CardType;
 use crate::options::Options;
-use crate::requests::PKISerialize;
 use crate::requests::RetrieveBinNumberRequest;
 use crate::resource::IyzipayResource;
 use crate::types::Result;
@@ -13,11 +15,11 @@ pub struct BinNumber {
 
     bin_number: Option<String>,
 
-    card_type: Option<String>,
+    card_type: Option<CardType>,
 
-    card_association: Option<String>,
+    card_association: Option<CardAssociation>,
 
-    card_family: Option<String>,
+    card_family: Option<CardFamily>,
 
     bank_name: Option<String>,
 
@@ -29,10 +31,11 @@ pub struct BinNumber {
 impl BinNumber {
     pub fn retrieve(req: &RetrieveBinNumberRequest, options: &Options) -> Result<BinNumber> {
         let request = serde_json::to_string(req)?;
+        let uri = format!("{}{}", options.base_url(), "/payment/bin/check");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/bin/check").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let bin_number = res.json()?;
         Ok(bin_number)
@@ -42,15 +45,15 @@ impl BinNumber {
         self.bin_number = Some(bin_number.into());
     }
 
-    pub fn set_card_type<T: Into<String>>(&mut self, card_type: T) {
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
         self.card_type = Some(card_type.into());
     }
 
-    pub fn set_card_association<T: Into<String>>(&mut self, card_association: T) {
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
         self.card_association = Some(card_association.into());
     }
 
-    pub fn set_card_family<T: Into<String>>(&mut self, card_family: T) {
+    pub fn set_card_family<T: Into<CardFamily>>(&mut self, card_family: T) {
         self.card_family = Some(card_family.into());
     }
 
@@ -69,13 +72,13 @@ impl BinNumber {
     pub fn bin_number(&self) -> Option<&String> {
         self.bin_number.as_ref()
     }
-    pub fn card_type(&self) -> Option<&String> {
+    pub fn card_type(&self) -> Option<&CardType> {
         self.card_type.as_ref()
     }
-    pub fn card_association(&self) -> Option<&String> {
+    pub fn card_association(&self) -> Option<&CardAssociation> {
         self.card_association.as_ref()
     }
-    pub fn card_family(&self) -> Option<&String> {
+    pub fn card_family(&self) -> Option<&CardFamily> {
         self.card_family.as_ref()
     }
     pub fn bank_name(&self) -> Option<&String> {
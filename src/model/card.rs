@@ -1,17 +1,55 @@
 use log::debug;
 
 use crate::client::HttpClient;
+use crate::model::bin_number;
+use crate::model::mask_card_number;
+use crate::model::normalize_card_number;
+use crate::model::normalize_expire_month;
+use crate::model::normalize_expire_year;
 use crate::options::Options;
 use crate::requests::CreateCardManagementPageInitializeRequest;
 use crate::requests::CreateCardRequest;
 use crate::requests::DeleteCardRequest;
-use crate::requests::PKISerialize;
 use crate::requests::RequestStringBuilder;
 use crate::requests::RetrieveCardListRequest;
 use crate::requests::RetrieveCardManagementPageCardRequest;
+use crate::requests::Validate;
+use crate::requests::Validator;
+use crate::requests::MAX_NAME_LENGTH;
 use crate::resource::IyzipayResource;
+use crate::secret::deserialize_card_number;
+use crate::secret::Secret;
 use crate::types::Result;
 
+lenient_enum! {
+    pub enum CardType {
+        CreditCard => "CREDIT_CARD",
+        DebitCard => "DEBIT_CARD",
+        PrepaidCard => "PREPAID_CARD",
+    }
+}
+
+lenient_enum! {
+    pub enum CardAssociation {
+        Visa => "VISA",
+        MasterCard => "MASTER_CARD",
+        Amex => "AMERICAN_EXPRESS" | "AMEX",
+        Troy => "TROY",
+    }
+}
+
+lenient_enum! {
+    pub enum CardFamily {
+        Bonus => "Bonus",
+        Axess => "Axess",
+        World => "World",
+        Maximum => "Maximum",
+        Paraf => "Paraf",
+        CardFinans => "CardFinans",
+        Advantage => "Advantage",
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Card {
@@ -32,11 +70,11 @@ pub struct Card {
 
     last_four_digits: Option<String>,
 
-    card_type: Option<String>,
+    card_type: Option<CardType>,
 
-    card_association: Option<String>,
+    card_association: Option<CardAssociation>,
 
-    card_family: Option<String>,
+    card_family: Option<CardFamily>,
 
     card_bank_code: Option<i64>,
 
@@ -60,10 +98,11 @@ impl Card {
     pub fn create(req: &CreateCardRequest, options: &Options) -> Result<Card> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/cardstorage/card");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/cardstorage/card").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -72,10 +111,11 @@ impl Card {
     pub fn delete(req: &DeleteCardRequest, options: &Options) -> Result<Card> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/cardstorage/card");
         let res = HttpClient::create().delete(
-            format!("{}{}", options.base_url(), "/cardstorage/card").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -109,15 +149,15 @@ impl Card {
         self.last_four_digits = Some(last_four_digits.into());
     }
 
-    pub fn set_card_type<T: Into<String>>(&mut self, card_type: T) {
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
         self.card_type = Some(card_type.into());
     }
 
-    pub fn set_card_association<T: Into<String>>(&mut self, card_association: T) {
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
         self.card_association = Some(card_association.into());
     }
 
-    pub fn set_card_family<T: Into<String>>(&mut self, card_family: T) {
+    pub fn set_card_family<T: Into<CardFamily>>(&mut self, card_family: T) {
         self.card_family = Some(card_family.into());
     }
 
@@ -162,13 +202,13 @@ impl Card {
     pub fn last_four_digits(&self) -> Option<&String> {
         self.last_four_digits.as_ref()
     }
-    pub fn card_type(&self) -> Option<&String> {
+    pub fn card_type(&self) -> Option<&CardType> {
         self.card_type.as_ref()
     }
-    pub fn card_association(&self) -> Option<&String> {
+    pub fn card_association(&self) -> Option<&CardAssociation> {
         self.card_association.as_ref()
     }
-    pub fn card_family(&self) -> Option<&String> {
+    pub fn card_family(&self) -> Option<&CardFamily> {
         self.card_family.as_ref()
     }
     pub fn card_bank_code(&self) -> Option<&i64> {
@@ -210,10 +250,11 @@ impl CardList {
     pub fn retrieve(req: &RetrieveCardListRequest, options: &Options) -> Result<CardList> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/cardstorage/cards");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/cardstorage/cards").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -240,11 +281,12 @@ impl CardList {
 pub struct CardInformation {
     card_alias: Option<String>,
 
-    card_number: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_card_number")]
+    card_number: Option<Secret>,
 
-    expire_year: Option<String>,
+    expire_year: Option<Secret>,
 
-    expire_month: Option<String>,
+    expire_month: Option<Secret>,
 
     card_holder_name: Option<String>,
 }
@@ -258,32 +300,89 @@ impl CardInformation {
         self.card_alias = Some(card_alias.into());
     }
 
+    /// Sets the card number without spaces and dashes.
     pub fn set_card_number<S: Into<String>>(&mut self, card_number: S) {
-        self.card_number = Some(card_number.into());
+        let card_number = Secret::new(card_number);
+        self.card_number = Some(Secret::card_number(normalize_card_number(
+            card_number.expose(),
+        )));
     }
 
+    /// Sets the year, expanding two digit years such as `30` to `2030`.
     pub fn set_expire_year<S: Into<String>>(&mut self, expire_year: S) {
-        self.expire_year = Some(expire_year.into());
+        let expire_year = Secret::new(expire_year);
+        self.expire_year = Some(
+            normalize_expire_year(expire_year.expose())
+                .map(Secret::new)
+                .unwrap_or(expire_year),
+        );
     }
 
+    /// Sets the month, padding it to two digits.
     pub fn set_expire_month<S: Into<String>>(&mut self, expire_month: S) {
-        self.expire_month = Some(expire_month.into());
+        let expire_month = Secret::new(expire_month);
+        self.expire_month = Some(
+            normalize_expire_month(expire_month.expose())
+                .map(Secret::new)
+                .unwrap_or(expire_month),
+        );
     }
 
     pub fn set_card_holder_name<S: Into<String>>(&mut self, card_holder_name: S) {
         self.card_holder_name = Some(card_holder_name.into());
     }
+
+    pub fn card_alias(&self) -> Option<&String> {
+        self.card_alias.as_ref()
+    }
+    pub fn card_number(&self) -> Option<&Secret> {
+        self.card_number.as_ref()
+    }
+    pub fn expire_year(&self) -> Option<&Secret> {
+        self.expire_year.as_ref()
+    }
+    pub fn expire_month(&self) -> Option<&Secret> {
+        self.expire_month.as_ref()
+    }
+    pub fn card_holder_name(&self) -> Option<&String> {
+        self.card_holder_name.as_ref()
+    }
+
+    /// Returns the association detected from the card number.
+    pub fn detected_card_association(&self) -> Option<CardAssociation> {
+        self.card_number
+            .as_ref()
+            .map(Secret::expose)
+            .and_then(CardAssociation::from_card_number)
+    }
+
+    pub fn bin_number(&self) -> Option<String> {
+        self.card_number
+            .as_ref()
+            .map(Secret::expose)
+            .and_then(bin_number)
+    }
+
+    /// Returns the card number with all but the BIN and the last four digits masked.
+    pub fn masked_card_number(&self) -> Option<String> {
+        self.card_number
+            .as_ref()
+            .map(|card_number| mask_card_number(card_number.expose()))
+    }
 }
 
-impl PKISerialize for CardInformation {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("cardAlias", self.card_alias.as_ref());
-        ser.append_option("cardNumber", self.card_number.as_ref());
-        ser.append_option("expireYear", self.expire_year.as_ref());
-        ser.append_option("expireMonth", self.expire_month.as_ref());
-        ser.append_option("cardHolderName", self.card_holder_name.as_ref());
-        Option::from(ser.build(true))
+impl Validate for CardInformation {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.max_length(
+            "cardHolderName",
+            self.card_holder_name.as_ref(),
+            MAX_NAME_LENGTH,
+        );
+        validator.card_number("cardNumber", self.card_number.as_ref().map(Secret::expose));
+        validator.expiry(
+            self.expire_month.as_ref().map(Secret::expose),
+            self.expire_year.as_ref().map(Secret::expose),
+        );
     }
 }
 
@@ -307,10 +406,11 @@ impl CardManagementPageInitialize {
     ) -> Result<CardManagementPageInitialize> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/v1/card-management/pages");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/v1/card-management/pages").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -344,11 +444,14 @@ impl CardManagementPageCard {
     ) -> Result<CardManagementPageCard> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = Self::prepare_retrieve_card_management_page_card_request(req, options);
         let res = HttpClient::create().get(
-            Self::prepare_retrieve_card_management_page_card_request(&req, &options).as_str(),
-            Some(IyzipayResource::get_http_headers(
-                req.serialize().unwrap_or_default(),
-                &options,
+            uri.as_str(),
+            Some(IyzipayResource::get_http_headers_for(
+                uri.as_str(),
+                "",
+                req,
+                options,
             )),
         )?;
         let response = res.json()?;
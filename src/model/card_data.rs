@@ -0,0 +1,144 @@
+use chrono::Datelike;
+use chrono::NaiveDate;
+use zeroize::Zeroizing;
+
+use crate::model::CardAssociation;
+
+const BIN_NUMBER_LENGTH: usize = 6;
+
+/// Removes spaces and dashes, e.g. `5528 7900 0000 0008` becomes `5528790000000008`.
+pub fn normalize_card_number(card_number: &str) -> String {
+    // Reserved up front, as growing the string would leave copies of the digits behind.
+    let mut normalized = String::with_capacity(card_number.len());
+    normalized.extend(
+        card_number
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-'),
+    );
+    normalized
+}
+
+/// Checks the Luhn (mod 10) check digit of a card number.
+// `u32::is_multiple_of` needs Rust 1.87.
+#[allow(unknown_lints, clippy::manual_is_multiple_of)]
+pub fn is_luhn_valid(card_number: &str) -> bool {
+    let card_number = Zeroizing::new(normalize_card_number(card_number));
+    if card_number.is_empty() || !card_number.chars().all(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let sum: u32 = card_number
+        .chars()
+        .rev()
+        .filter_map(|c| c.to_digit(10))
+        .enumerate()
+        .map(|(index, digit)| match (index % 2, digit * 2) {
+            (0, _) => digit,
+            (_, doubled) if doubled > 9 => doubled - 9,
+            (_, doubled) => doubled,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Returns the first six digits of a card number, which identify the issuer.
+pub fn bin_number(card_number: &str) -> Option<String> {
+    let card_number = Zeroizing::new(normalize_card_number(card_number));
+    if card_number.len() < BIN_NUMBER_LENGTH || !card_number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(card_number[..BIN_NUMBER_LENGTH].to_string())
+}
+
+/// Keeps the BIN and the last four digits of a card number, e.g. `552879******0008`. Shorter
+/// numbers only keep the last four digits.
+pub fn mask_card_number(card_number: &str) -> String {
+    let card_number = Zeroizing::new(normalize_card_number(card_number));
+    let length = card_number.chars().count();
+    let last_four = length.saturating_sub(4);
+    let first = if length >= BIN_NUMBER_LENGTH + 4 {
+        BIN_NUMBER_LENGTH
+    } else {
+        0
+    };
+    card_number
+        .chars()
+        .enumerate()
+        .map(|(index, c)| {
+            if index < first || index >= last_four {
+                c
+            } else {
+                '*'
+            }
+        })
+        .collect()
+}
+
+/// Pads a month to two digits, e.g. `1` becomes `01`. Returns `None` if it is not a month.
+pub fn normalize_expire_month(expire_month: &str) -> Option<String> {
+    match expire_month.trim().parse::<u32>() {
+        Ok(month) if (1..=12).contains(&month) && expire_month.trim().len() <= 2 => {
+            Some(format!("{:02}", month))
+        }
+        _ => None,
+    }
+}
+
+/// Expands a two digit year to four digits, e.g. `30` becomes `2030`. Returns `None` if it is not
+/// a year.
+pub fn normalize_expire_year(expire_year: &str) -> Option<String> {
+    let expire_year = expire_year.trim();
+    match (expire_year.len(), expire_year.parse::<u32>()) {
+        (2, Ok(year)) => Some(format!("{}", 2000 + year)),
+        (4, Ok(year)) => Some(format!("{}", year)),
+        _ => None,
+    }
+}
+
+/// Cards can be used until the end of their expire month.
+pub fn is_expired(expire_month: &str, expire_year: &str, today: NaiveDate) -> Option<bool> {
+    let month = normalize_expire_month(expire_month)?.parse::<u32>().ok()?;
+    let year = normalize_expire_year(expire_year)?.parse::<i32>().ok()?;
+    Some((year, month) < (today.year(), today.month()))
+}
+
+impl CardAssociation {
+    /// Detects the association from the leading digits of a card number.
+    pub fn from_card_number(card_number: &str) -> Option<CardAssociation> {
+        let card_number = Zeroizing::new(normalize_card_number(card_number));
+        let prefix = |length: usize| -> Option<u32> {
+            card_number
+                .get(..length)
+                .and_then(|digits| digits.parse().ok())
+        };
+        if card_number.starts_with('4') {
+            Some(CardAssociation::Visa)
+        } else if card_number.starts_with("34") || card_number.starts_with("37") {
+            Some(CardAssociation::Amex)
+        } else if card_number.starts_with("9792") {
+            Some(CardAssociation::Troy)
+        } else if prefix(2).is_some_and(|p| (51..=55).contains(&p))
+            || prefix(6).is_some_and(|p| (222_100..=272_099).contains(&p))
+        {
+            Some(CardAssociation::MasterCard)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the lengths a card number of the association can have.
+    pub fn card_number_lengths(&self) -> &'static [usize] {
+        match self {
+            CardAssociation::Visa => &[13, 16, 19],
+            CardAssociation::MasterCard | CardAssociation::Troy => &[16],
+            CardAssociation::Amex => &[15],
+            CardAssociation::Unknown(_) => &[12, 13, 14, 15, 16, 17, 18, 19],
+        }
+    }
+
+    pub fn cvc_length(&self) -> usize {
+        match self {
+            CardAssociation::Amex => 4,
+            _ => 3,
+        }
+    }
+}
@@ -4,8 +4,8 @@ use crate::client::HttpClient;
 use crate::model::payment::PaymentResource;
 use crate::options::Options;
 use crate::requests::CreateCheckoutFormInitializeRequest;
-use crate::requests::PKISerialize;
 use crate::requests::RetrieveCheckoutFormRequest;
+use crate::requests::Validate;
 use crate::resource::IyzipayResource;
 use crate::types::Result;
 
@@ -22,17 +22,18 @@ impl CheckoutFormInitialize {
         req: &CreateCheckoutFormInitializeRequest,
         options: &Options,
     ) -> Result<CheckoutFormInitialize> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/iyzipos/checkoutform/initialize/auth/ecom"
+        );
         let res = HttpClient::create().post(
-            format!(
-                "{}{}",
-                options.base_url(),
-                "/payment/iyzipos/checkoutform/initialize/auth/ecom"
-            )
-            .as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -116,15 +117,15 @@ impl CheckoutForm {
     pub fn retrieve(req: &RetrieveCheckoutFormRequest, options: &Options) -> Result<CheckoutForm> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/iyzipos/checkoutform/auth/ecom/detail"
+        );
         let res = HttpClient::create().post(
-            format!(
-                "{}{}",
-                options.base_url(),
-                "/payment/iyzipos/checkoutform/auth/ecom/detail"
-            )
-            .as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -0,0 +1,79 @@
+use std::net::IpAddr;
+
+/// Country calling code used for phone numbers without one.
+pub const DEFAULT_COUNTRY_CODE: &str = "90";
+
+const MAX_EMAIL_LENGTH: usize = 254;
+
+const MAX_EMAIL_LOCAL_PART_LENGTH: usize = 64;
+
+/// Converts a phone number to E.164 with the Turkish country code as default, e.g.
+/// `0535 000 00 00` becomes `+905350000000`. Returns `None` if it is not a phone number.
+pub fn normalize_phone_number(phone_number: &str) -> Option<String> {
+    normalize_phone_number_for(phone_number, DEFAULT_COUNTRY_CODE)
+}
+
+/// Like `normalize_phone_number`, but adds `country_code` to numbers without one. Numbers starting
+/// with `+` or `00` keep their country code, and a leading `0` trunk prefix is removed.
+pub fn normalize_phone_number_for(phone_number: &str, country_code: &str) -> Option<String> {
+    let phone_number = phone_number.trim();
+    let (international, rest) = match phone_number.strip_prefix('+') {
+        Some(rest) => (true, rest),
+        None => (false, phone_number),
+    };
+    let separator = |c: char| c.is_whitespace() || c == '-' || c == '.' || c == '(' || c == ')';
+    if rest.chars().any(|c| !c.is_ascii_digit() && !separator(c)) {
+        return None;
+    }
+    let digits: String = rest.chars().filter(char::is_ascii_digit).collect();
+    let digits = if international {
+        digits
+    } else if let Some(digits) = digits.strip_prefix("00") {
+        digits.to_string()
+    } else {
+        format!(
+            "{}{}",
+            country_code,
+            digits.strip_prefix('0').unwrap_or(&digits)
+        )
+    };
+    if (8..=15).contains(&digits.len()) && !digits.starts_with('0') {
+        Some(format!("+{}", digits))
+    } else {
+        None
+    }
+}
+
+/// Checks that an email has a local part and a domain with a top level domain, e.g.
+/// `email@email.com`. Quoted local parts and IP address domains are not accepted.
+pub fn is_valid_email(email: &str) -> bool {
+    let (local_part, domain) = match email.rsplit_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let local_part_valid = !local_part.is_empty()
+        && local_part.len() <= MAX_EMAIL_LOCAL_PART_LENGTH
+        && !local_part.starts_with('.')
+        && !local_part.ends_with('.')
+        && !local_part.contains("..")
+        && local_part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~.-".contains(c));
+    let labels: Vec<&str> = domain.split('.').collect();
+    let domain_valid = labels.len() >= 2
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && labels
+            .last()
+            .is_some_and(|tld| tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()));
+    email.len() <= MAX_EMAIL_LENGTH && local_part_valid && domain_valid
+}
+
+/// Parses an IPv4 or IPv6 address, e.g. `85.34.78.112` or `2001:db8::1`.
+pub fn parse_ip(ip: &str) -> Option<IpAddr> {
+    ip.trim().parse().ok()
+}
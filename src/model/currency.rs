@@ -1,35 +1,103 @@
-use std::fmt;
-
-#[derive(Debug, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
-pub enum Currency {
-    TRY,
-    EUR,
-    USD,
-    GBP,
-    IRR,
-    NOK,
-    RUB,
-    CHF,
+lenient_enum! {
+    /// ISO 4217 currencies used with iyzico. Currencies not listed here deserialize to `Unknown`
+    /// and can still be sent with `set_currency_raw`.
+    pub enum Currency {
+        TRY => "TRY",
+        EUR => "EUR",
+        USD => "USD",
+        GBP => "GBP",
+        IRR => "IRR",
+        NOK => "NOK",
+        RUB => "RUB",
+        CHF => "CHF",
+        AED => "AED",
+        AUD => "AUD",
+        AZN => "AZN",
+        BGN => "BGN",
+        BHD => "BHD",
+        CAD => "CAD",
+        CNY => "CNY",
+        CZK => "CZK",
+        DKK => "DKK",
+        GEL => "GEL",
+        HUF => "HUF",
+        JPY => "JPY",
+        KWD => "KWD",
+        KZT => "KZT",
+        PLN => "PLN",
+        QAR => "QAR",
+        RON => "RON",
+        SAR => "SAR",
+        SEK => "SEK",
+        UAH => "UAH",
+    }
 }
 
 impl Currency {
-    pub fn value(&self) -> &'static str {
-        match self {
-            Currency::TRY => "TRY",
-            Currency::EUR => "EUR",
-            Currency::USD => "USD",
-            Currency::GBP => "GBP",
-            Currency::IRR => "IRR",
-            Currency::NOK => "NOK",
-            Currency::RUB => "RUB",
-            Currency::CHF => "CHF",
-        }
+    /// Returns every currency except `Unknown`.
+    pub fn all() -> &'static [Currency] {
+        Currency::VARIANTS
+    }
+
+    /// Finds a currency by its ISO 4217 numeric code, e.g. `949` for `TRY`.
+    pub fn from_numeric_code(numeric_code: u16) -> Option<Currency> {
+        Currency::VARIANTS
+            .iter()
+            .find(|currency| currency.numeric_code() == Some(numeric_code))
+            .cloned()
+    }
+
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Currency::Unknown(_))
+    }
+
+    /// ISO 4217 numeric code of the currency, `None` for `Unknown`.
+    pub fn numeric_code(&self) -> Option<u16> {
+        self.metadata().map(|(numeric_code, _, _)| numeric_code)
+    }
+
+    /// Number of decimal places of the currency as defined by ISO 4217, 2 for `Unknown`.
+    pub fn minor_units(&self) -> u32 {
+        self.metadata().map_or(2, |(_, minor_units, _)| minor_units)
+    }
+
+    /// Symbol of the currency, e.g. `₺` for `TRY`, `None` for `Unknown`.
+    pub fn symbol(&self) -> Option<&'static str> {
+        self.metadata().map(|(_, _, symbol)| symbol)
     }
-}
 
-impl std::fmt::Display for Currency {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.value())
+    fn metadata(&self) -> Option<(u16, u32, &'static str)> {
+        let metadata = match self {
+            Currency::TRY => (949, 2, "₺"),
+            Currency::EUR => (978, 2, "€"),
+            Currency::USD => (840, 2, "$"),
+            Currency::GBP => (826, 2, "£"),
+            Currency::IRR => (364, 2, "﷼"),
+            Currency::NOK => (578, 2, "kr"),
+            Currency::RUB => (643, 2, "₽"),
+            Currency::CHF => (756, 2, "CHF"),
+            Currency::AED => (784, 2, "د.إ"),
+            Currency::AUD => (36, 2, "A$"),
+            Currency::AZN => (944, 2, "₼"),
+            Currency::BGN => (975, 2, "лв"),
+            Currency::BHD => (48, 3, ".د.ب"),
+            Currency::CAD => (124, 2, "C$"),
+            Currency::CNY => (156, 2, "¥"),
+            Currency::CZK => (203, 2, "Kč"),
+            Currency::DKK => (208, 2, "kr"),
+            Currency::GEL => (981, 2, "₾"),
+            Currency::HUF => (348, 2, "Ft"),
+            Currency::JPY => (392, 0, "¥"),
+            Currency::KWD => (414, 3, "د.ك"),
+            Currency::KZT => (398, 2, "₸"),
+            Currency::PLN => (985, 2, "zł"),
+            Currency::QAR => (634, 2, "ر.ق"),
+            Currency::RON => (946, 2, "lei"),
+            Currency::SAR => (682, 2, "﷼"),
+            Currency::SEK => (752, 2, "kr"),
+            Currency::UAH => (980, 2, "₴"),
+            Currency::Unknown(_) => return None,
+        };
+        Some(metadata)
     }
 }
@@ -0,0 +1,235 @@
+use std::fmt;
+
+use chrono::DateTime;
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::NaiveTime;
+use chrono::TimeZone;
+use chrono::Utc;
+use serde::de;
+use serde::Deserializer;
+use serde::Serializer;
+
+/// Format of the dates iyzico sends and expects, e.g. `2013-04-21 15:12:09`.
+pub const DATE_TIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// Parses a date in `DATE_TIME_FORMAT`. ISO 8601 dates with a `T` separator and dates without a
+/// time, which become midnight, are accepted as well.
+pub fn parse_date_time(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    NaiveDateTime::parse_from_str(value, DATE_TIME_FORMAT)
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f"))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+}
+
+/// A value the date setters accept: chrono dates, or strings for compatibility, which are parsed
+/// with `parse_date_time`. Request setters send a string that is not a date as it is, while the
+/// date getters return `None` for it.
+pub trait IntoDateTime: Sized {
+    fn into_date_time(self) -> Option<NaiveDateTime>;
+
+    /// The date, or the string it was given as when that is not a date.
+    fn into_date_time_or_raw(self) -> Option<Result<NaiveDateTime, String>> {
+        self.into_date_time().map(Ok)
+    }
+}
+
+impl IntoDateTime for NaiveDateTime {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        Some(self)
+    }
+}
+
+impl IntoDateTime for NaiveDate {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        Some(self.and_time(NaiveTime::MIN))
+    }
+}
+
+impl IntoDateTime for DateTime<Utc> {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        Some(self.naive_utc())
+    }
+}
+
+impl IntoDateTime for &str {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        parse_date_time(self)
+    }
+
+    fn into_date_time_or_raw(self) -> Option<Result<NaiveDateTime, String>> {
+        Some(parse_date_time(self).ok_or_else(|| self.to_string()))
+    }
+}
+
+impl IntoDateTime for String {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        parse_date_time(&self)
+    }
+
+    fn into_date_time_or_raw(self) -> Option<Result<NaiveDateTime, String>> {
+        Some(parse_date_time(&self).ok_or(self))
+    }
+}
+
+impl IntoDateTime for &String {
+    fn into_date_time(self) -> Option<NaiveDateTime> {
+        parse_date_time(self)
+    }
+
+    fn into_date_time_or_raw(self) -> Option<Result<NaiveDateTime, String>> {
+        Some(parse_date_time(self).ok_or_else(|| self.to_string()))
+    }
+}
+
+/// A date of a request, or the string it was set from when that is not a date.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum DateTimeField {
+    Parsed(NaiveDateTime),
+    Raw(String),
+}
+
+impl DateTimeField {
+    pub(crate) fn from<T: IntoDateTime>(value: T) -> Option<DateTimeField> {
+        value.into_date_time_or_raw().map(|value| match value {
+            Ok(date_time) => DateTimeField::Parsed(date_time),
+            Err(raw) => DateTimeField::Raw(raw),
+        })
+    }
+
+    pub(crate) fn date_time(&self) -> Option<&NaiveDateTime> {
+        match self {
+            DateTimeField::Parsed(date_time) => Some(date_time),
+            DateTimeField::Raw(_) => None,
+        }
+    }
+
+    /// The date in `DATE_TIME_FORMAT`, or the string as it was set.
+    pub(crate) fn format(&self) -> String {
+        match self {
+            DateTimeField::Parsed(date_time) => date_time.format(DATE_TIME_FORMAT).to_string(),
+            DateTimeField::Raw(raw) => raw.to_owned(),
+        }
+    }
+}
+
+impl serde::Serialize for DateTimeField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.format())
+    }
+}
+
+/// `deserialize_with` helper reading a `DateTimeField`, keeping a string that is not a date.
+pub(crate) fn deserialize_date_time_field<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<DateTimeField>, D::Error> {
+    Ok(deserializer
+        .deserialize_any(OptionalStringVisitor)?
+        .and_then(DateTimeField::from))
+}
+
+/// `serialize_with` helper writing dates in `DATE_TIME_FORMAT`, in JSON and in the PKI string.
+pub fn serialize_date_time<S: Serializer>(
+    date_time: &Option<NaiveDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match date_time {
+        Some(date_time) => {
+            serializer.serialize_some(&date_time.format(DATE_TIME_FORMAT).to_string())
+        }
+        None => serializer.serialize_none(),
+    }
+}
+
+/// `deserialize_with` helper reading dates with `parse_date_time`. Anything else becomes `None`
+/// instead of failing the whole response.
+pub fn deserialize_date_time<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<NaiveDateTime>, D::Error> {
+    Ok(deserializer
+        .deserialize_any(OptionalStringVisitor)?
+        .and_then(|value| parse_date_time(&value)))
+}
+
+/// `serialize_with` helper writing a timestamp as milliseconds since the epoch, as iyzico sends
+/// `systemTime`.
+pub fn serialize_timestamp<S: Serializer>(
+    timestamp: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match timestamp {
+        Some(timestamp) => serializer.serialize_some(&timestamp.timestamp_millis()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// `deserialize_with` helper reading milliseconds since the epoch, as a number or a string.
+pub fn deserialize_timestamp<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error> {
+    Ok(deserializer
+        .deserialize_any(OptionalStringVisitor)?
+        .and_then(|value| value.parse::<i64>().ok())
+        .and_then(|millis| Utc.timestamp_millis_opt(millis).single()))
+}
+
+/// Reads strings and numbers as a string, and anything else as `None`.
+struct OptionalStringVisitor;
+
+impl<'de> de::Visitor<'de> for OptionalStringVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a date")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Some((value as i64).to_string()))
+    }
+
+    fn visit_bool<E: de::Error>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<de::IgnoredAny>()?.is_some() {}
+        Ok(None)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map
+            .next_entry::<de::IgnoredAny, de::IgnoredAny>()?
+            .is_some()
+        {}
+        Ok(None)
+    }
+}
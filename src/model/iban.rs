@@ -0,0 +1,170 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+const TR_IBAN_LENGTH: usize = 26;
+
+/// Banks by the five digit bank code of TR IBANs.
+const TR_BANKS: &[(&str, &str)] = &[
+    ("00010", "Türkiye Cumhuriyeti Ziraat Bankası"),
+    ("00012", "Türkiye Halk Bankası"),
+    ("00015", "Türkiye Vakıflar Bankası"),
+    ("00032", "Türk Ekonomi Bankası"),
+    ("00046", "Akbank"),
+    ("00059", "Şekerbank"),
+    ("00062", "Türkiye Garanti Bankası"),
+    ("00064", "Türkiye İş Bankası"),
+    ("00067", "Yapı ve Kredi Bankası"),
+    ("00099", "ING Bank"),
+    ("00103", "Fibabanka"),
+    ("00111", "QNB Finansbank"),
+    ("00123", "HSBC Bank"),
+    ("00124", "Alternatifbank"),
+    ("00134", "Denizbank"),
+    ("00135", "Anadolubank"),
+    ("00146", "Odea Bank"),
+    ("00203", "Albaraka Türk Katılım Bankası"),
+    ("00205", "Kuveyt Türk Katılım Bankası"),
+    ("00206", "Türkiye Finans Katılım Bankası"),
+    ("00209", "Ziraat Katılım Bankası"),
+    ("00210", "Vakıf Katılım Bankası"),
+];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IbanError {
+    InvalidCharacters,
+    InvalidLength { expected: String, found: usize },
+    InvalidChecksum,
+}
+
+impl fmt::Display for IbanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            IbanError::InvalidCharacters => write!(f, "must only contain letters and digits"),
+            IbanError::InvalidLength { expected, found } => {
+                write!(f, "must be {} characters long, found {}", expected, found)
+            }
+            IbanError::InvalidChecksum => write!(f, "has an invalid check digit"),
+        }
+    }
+}
+
+impl Error for IbanError {}
+
+/// An IBAN (ISO 13616) with a valid mod-97 checksum, stored without spaces and in upper case.
+/// TR IBANs must be 26 characters long, other countries are only checked against the 15 to 34
+/// characters any IBAN has.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iban(String);
+
+impl Iban {
+    pub fn parse(iban: &str) -> Result<Iban, IbanError> {
+        let iban = Iban::normalize(iban);
+        let valid_characters = iban.chars().all(|c| c.is_ascii_alphanumeric())
+            && iban.chars().take(2).all(|c| c.is_ascii_alphabetic())
+            && iban.chars().skip(2).take(2).all(|c| c.is_ascii_digit());
+        if !valid_characters {
+            return Err(IbanError::InvalidCharacters);
+        }
+        if iban.starts_with("TR") && iban.len() != TR_IBAN_LENGTH {
+            return Err(IbanError::InvalidLength {
+                expected: TR_IBAN_LENGTH.to_string(),
+                found: iban.len(),
+            });
+        }
+        if !(15..=34).contains(&iban.len()) {
+            return Err(IbanError::InvalidLength {
+                expected: String::from("15 to 34"),
+                found: iban.len(),
+            });
+        }
+        if Iban::remainder(&iban) != 1 {
+            return Err(IbanError::InvalidChecksum);
+        }
+        Ok(Iban(iban))
+    }
+
+    /// Removes whitespace and upper cases, e.g. `tr18 0006 2001` becomes `TR1800062001`.
+    pub fn normalize(iban: &str) -> String {
+        iban.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_uppercase)
+            .collect()
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+
+    pub fn country_code(&self) -> &str {
+        &self.0[..2]
+    }
+
+    pub fn check_digits(&self) -> &str {
+        &self.0[2..4]
+    }
+
+    pub fn bban(&self) -> &str {
+        &self.0[4..]
+    }
+
+    /// Returns the five digit bank code of a TR IBAN.
+    pub fn bank_code(&self) -> Option<&str> {
+        if self.country_code() == "TR" {
+            Some(&self.0[4..9])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the name of the bank of a TR IBAN, if it is a known bank.
+    pub fn bank_name(&self) -> Option<&'static str> {
+        let bank_code = self.bank_code()?;
+        TR_BANKS
+            .iter()
+            .find(|(code, _)| *code == bank_code)
+            .map(|(_, name)| *name)
+    }
+
+    /// Groups the IBAN by four characters, e.g. `TR18 0006 2001 1900 0006 6723 15`.
+    pub fn formatted(&self) -> String {
+        let chars: Vec<char> = self.0.chars().collect();
+        let groups: Vec<String> = chars.chunks(4).map(|c| c.iter().collect()).collect();
+        groups.join(" ")
+    }
+
+    fn remainder(iban: &str) -> u32 {
+        iban.chars()
+            .cycle()
+            .skip(4)
+            .take(iban.len())
+            .filter_map(|c| c.to_digit(36))
+            .fold(0, |remainder, digit| {
+                if digit < 10 {
+                    (remainder * 10 + digit) % 97
+                } else {
+                    (remainder * 100 + digit) % 97
+                }
+            })
+    }
+}
+
+impl FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(iban: &str) -> Result<Self, Self::Err> {
+        Iban::parse(iban)
+    }
+}
+
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Iban> for String {
+    fn from(iban: Iban) -> Self {
+        iban.0
+    }
+}
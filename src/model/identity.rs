@@ -0,0 +1,56 @@
+/// How an identity number is validated.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum IdentityNumberType {
+    /// A Turkish national identity number (TCKN), including foreigner identity numbers starting
+    /// with 99.
+    #[default]
+    Tckn,
+    /// A passport or other foreign identity document number.
+    Foreign,
+}
+
+fn digits(value: &str, length: usize) -> Option<Vec<u32>> {
+    if value.len() != length {
+        return None;
+    }
+    value.chars().map(|c| c.to_digit(10)).collect()
+}
+
+/// Checks the length, leading digit and both check digits of a Turkish national identity number.
+pub fn is_valid_tckn(tckn: &str) -> bool {
+    let d = match digits(tckn, 11) {
+        Some(d) if d[0] != 0 => d,
+        _ => return false,
+    };
+    let odd: u32 = d[0..9].iter().step_by(2).sum();
+    let even: u32 = d[1..8].iter().step_by(2).sum();
+    let tenth = (odd * 7 + 10 * 10 - even) % 10;
+    let eleventh = d[0..10].iter().sum::<u32>() % 10;
+    d[9] == tenth && d[10] == eleventh
+}
+
+/// Checks the length and check digit of a Turkish tax number (VKN).
+pub fn is_valid_vkn(vkn: &str) -> bool {
+    let d = match digits(vkn, 10) {
+        Some(d) => d,
+        None => return false,
+    };
+    let sum: u32 = d[0..9]
+        .iter()
+        .enumerate()
+        .map(|(index, digit)| {
+            let shifted = (digit + 9 - index as u32) % 10;
+            match (shifted * 2u32.pow(9 - index as u32)) % 9 {
+                0 if shifted != 0 => 9,
+                weighted => weighted,
+            }
+        })
+        .sum();
+    d[9] == (10 - sum % 10) % 10
+}
+
+/// Accepts 5 to 20 letters and digits, which covers passport and most foreign identity numbers.
+pub fn is_valid_foreign_identity_number(identity_number: &str) -> bool {
+    (5..=20).contains(&identity_number.len())
+        && identity_number.chars().all(|c| c.is_ascii_alphanumeric())
+}
@@ -2,8 +2,10 @@ use bigdecimal::BigDecimal;
 use log::debug;
 
 use crate::client::HttpClient;
+use crate::model::CardAssociation;
+use crate::model::CardFamily;
+use crate::model::CardType;
 use crate::options::Options;
-use crate::requests::PKISerialize;
 use crate::requests::RetrieveInstallmentInfoRequest;
 use crate::resource::IyzipayResource;
 use crate::types::Result;
@@ -24,10 +26,11 @@ impl InstallmentInfo {
     ) -> Result<InstallmentInfo> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/iyzipos/installment");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/iyzipos/installment").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -59,11 +62,11 @@ pub struct InstallmentDetail {
 
     price: Option<BigDecimal>,
 
-    card_type: Option<String>,
+    card_type: Option<CardType>,
 
-    card_association: Option<String>,
+    card_association: Option<CardAssociation>,
 
-    card_family_name: Option<String>,
+    card_family_name: Option<CardFamily>,
 
     force3ds: Option<u8>,
 
@@ -83,15 +86,15 @@ impl InstallmentDetail {
         self.price = Some(price.into());
     }
 
-    pub fn set_card_type<T: Into<String>>(&mut self, card_type: T) {
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
         self.card_type = Some(card_type.into());
     }
 
-    pub fn set_card_association<T: Into<String>>(&mut self, card_association: T) {
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
         self.card_association = Some(card_association.into());
     }
 
-    pub fn set_card_family_name<T: Into<String>>(&mut self, card_family_name: T) {
+    pub fn set_card_family_name<T: Into<CardFamily>>(&mut self, card_family_name: T) {
         self.card_family_name = Some(card_family_name.into());
     }
 
@@ -129,13 +132,13 @@ impl InstallmentDetail {
     pub fn price(&self) -> Option<&BigDecimal> {
         self.price.as_ref()
     }
-    pub fn card_type(&self) -> Option<&String> {
+    pub fn card_type(&self) -> Option<&CardType> {
         self.card_type.as_ref()
     }
-    pub fn card_association(&self) -> Option<&String> {
+    pub fn card_association(&self) -> Option<&CardAssociation> {
         self.card_association.as_ref()
     }
-    pub fn card_family_name(&self) -> Option<&String> {
+    pub fn card_family_name(&self) -> Option<&CardFamily> {
         self.card_family_name.as_ref()
     }
     pub fn force3ds(&self) -> Option<&u8> {
@@ -166,6 +169,12 @@ pub struct InstallmentPrice {
     total_price: Option<BigDecimal>,
 
     installment_number: Option<u8>,
+
+    campaign_id: Option<String>,
+
+    plus_installment: Option<u8>,
+
+    deferral_months: Option<u8>,
 }
 
 impl InstallmentPrice {
@@ -181,6 +190,18 @@ impl InstallmentPrice {
         self.installment_number = Some(installment_number.into());
     }
 
+    pub fn set_campaign_id<T: Into<String>>(&mut self, campaign_id: T) {
+        self.campaign_id = Some(campaign_id.into());
+    }
+
+    pub fn set_plus_installment<T: Into<u8>>(&mut self, plus_installment: T) {
+        self.plus_installment = Some(plus_installment.into());
+    }
+
+    pub fn set_deferral_months<T: Into<u8>>(&mut self, deferral_months: T) {
+        self.deferral_months = Some(deferral_months.into());
+    }
+
     pub fn installment_price(&self) -> Option<&BigDecimal> {
         self.installment_price.as_ref()
     }
@@ -190,4 +211,21 @@ impl InstallmentPrice {
     pub fn installment_number(&self) -> Option<&u8> {
         self.installment_number.as_ref()
     }
+    /// Set when the option is a bank campaign, select it with
+    /// `CreatePaymentRequest::select_installment_price`.
+    pub fn campaign_id(&self) -> Option<&String> {
+        self.campaign_id.as_ref()
+    }
+    /// The installments the bank adds on top of `installment_number` at no extra cost.
+    pub fn plus_installment(&self) -> Option<&u8> {
+        self.plus_installment.as_ref()
+    }
+    /// The months the first installment is deferred by.
+    pub fn deferral_months(&self) -> Option<&u8> {
+        self.deferral_months.as_ref()
+    }
+
+    pub fn is_campaign(&self) -> bool {
+        self.campaign_id.is_some()
+    }
 }
@@ -2,13 +2,16 @@ use bigdecimal::BigDecimal;
 use log::debug;
 
 use crate::client::HttpClient;
+use crate::model::normalize_phone_number;
 use crate::model::payment::PaymentItem;
 use crate::model::Address;
 use crate::options::Options;
+use crate::requests::serialize_price;
 use crate::requests::CreateIyziupFormInitializeRequest;
-use crate::requests::PKISerialize;
-use crate::requests::RequestStringBuilder;
 use crate::requests::RetrieveIyziupFormRequest;
+use crate::requests::Validate;
+use crate::requests::Validator;
+use crate::requests::MAX_NAME_LENGTH;
 use crate::resource::IyzipayResource;
 use crate::types::Result;
 
@@ -41,11 +44,13 @@ impl InitialConsumer {
     }
 
     pub fn set_email<T: Into<String>>(&mut self, email: T) {
-        self.email = Some(email.into());
+        self.email = Some(email.into().trim().to_string());
     }
 
+    /// Sets the phone number in E.164, e.g. `+905350000000`, if it can be normalized.
     pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
-        self.gsm_number = Some(gsm_number.into());
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
     }
 
     pub fn set_address_list<T: Into<Vec<IyziupAddress>>>(&mut self, address_list: T) {
@@ -69,15 +74,12 @@ impl InitialConsumer {
     }
 }
 
-impl PKISerialize for InitialConsumer {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("surname", self.surname.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("gsmNumber", self.gsm_number.as_ref());
-        ser.append_option("addressList", self.address_list.serialize());
-        Option::from(ser.build(true))
+impl Validate for InitialConsumer {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.max_length("name", self.name.as_ref(), MAX_NAME_LENGTH);
+        validator.max_length("surname", self.surname.as_ref(), MAX_NAME_LENGTH);
+        validator.phone_number("gsmNumber", self.gsm_number.as_ref());
+        validator.email("email", self.email.as_ref());
     }
 }
 
@@ -156,26 +158,13 @@ impl IyziupAddress {
     }
 }
 
-impl PKISerialize for IyziupAddress {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("alias", self.alias.as_ref());
-        ser.append_option("addressLine1", self.address_line1.as_ref());
-        ser.append_option("addressLine2", self.address_line2.as_ref());
-        ser.append_option("zipCode", self.zip_code.as_ref());
-        ser.append_option("contactName", self.contact_name.as_ref());
-        ser.append_option("city", self.city.as_ref());
-        ser.append_option("country", self.country.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
 pub struct OrderItem {
     id: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
     name: Option<String>,
@@ -216,7 +205,11 @@ impl OrderItem {
         self.category2 = Some(category2.into());
     }
 
-    pub fn set_item_type<T: Into<String>>(&mut self, item_type: T) {
+    pub fn set_item_type(&mut self, item_type: OrderItemType) {
+        self.item_type = Some(item_type.value().to_string());
+    }
+
+    pub fn set_item_type_raw<T: Into<String>>(&mut self, item_type: T) {
         self.item_type = Some(item_type.into());
     }
 
@@ -254,21 +247,6 @@ impl OrderItem {
     }
 }
 
-impl PKISerialize for OrderItem {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("id", self.id.as_ref());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("category1", self.category1.as_ref());
-        ser.append_option("category2", self.category2.as_ref());
-        ser.append_option("itemType", self.item_type.as_ref());
-        ser.append_option("itemUrl", self.item_url.as_ref());
-        ser.append_option("itemDescription", self.item_description.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 pub enum OrderItemType {
     Physical,
     Virtual,
@@ -298,10 +276,11 @@ impl IyziupFormInitialize {
     ) -> Result<IyziupFormInitialize> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/v1/iyziup/form/initialize");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/v1/iyziup/form/initialize").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -386,10 +365,11 @@ impl IyziupForm {
     pub fn retrieve(req: &RetrieveIyziupFormRequest, options: &Options) -> Result<IyziupForm> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/v1/iyziup/form/order/retrieve");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/v1/iyziup/form/order/retrieve").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -482,11 +462,13 @@ impl Consumer {
     }
 
     pub fn set_email<T: Into<String>>(&mut self, email: T) {
-        self.email = Some(email.into());
+        self.email = Some(email.into().trim().to_string());
     }
 
+    /// Sets the phone number in E.164, e.g. `+905350000000`, if it can be normalized.
     pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
-        self.gsm_number = Some(gsm_number.into());
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
     }
 
     pub fn name(&self) -> Option<&String> {
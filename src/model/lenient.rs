@@ -0,0 +1,148 @@
+use std::fmt;
+
+use serde::de;
+
+/// Declares a response enum that never fails to deserialize: values are matched ignoring case
+/// and separators (`MASTER_CARD`, `Master Card`, `mastercard`), numbers are accepted as well as
+/// strings and anything else ends up in `Unknown`. The first value of a variant is the one that
+/// is serialized.
+macro_rules! lenient_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $($variant:ident => $value:literal $(| $alias:literal)*,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($variant,)*
+            Unknown(String),
+        }
+
+        impl $name {
+            /// Every variant except `Unknown`, in the order they are declared.
+            pub const VARIANTS: &'static [$name] = &[$($name::$variant,)*];
+
+            pub fn value(&self) -> &str {
+                match self {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(value) => value.as_str(),
+                }
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(value: &str) -> Self {
+                let key = crate::model::lenient::normalize(value);
+                $(
+                    if key == crate::model::lenient::normalize($value)
+                        $(|| key == crate::model::lenient::normalize($alias))*
+                    {
+                        return $name::$variant;
+                    }
+                )*
+                $name::Unknown(value.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(value: String) -> Self {
+                $name::from(value.as_str())
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(value: &str) -> std::result::Result<Self, Self::Err> {
+                Ok($name::from(value))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "{}", self.value())
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+                match self.value().parse::<i64>() {
+                    Ok(value) => serializer.serialize_i64(value),
+                    Err(_) => serializer.serialize_str(self.value()),
+                }
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+                deserializer
+                    .deserialize_any(crate::model::lenient::LenientVisitor)
+                    .map($name::from)
+            }
+        }
+    };
+}
+
+/// Upper cases `value` and drops everything but letters and digits, keeping a leading `-` so
+/// that negative numbers stay distinct.
+pub fn normalize(value: &str) -> String {
+    let value = value.trim();
+    let sign = if value.starts_with('-') { "-" } else { "" };
+    let digits: String = value
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_uppercase)
+        .collect();
+    format!("{}{}", sign, digits)
+}
+
+/// Reads strings, numbers and booleans as their text.
+pub struct LenientVisitor;
+
+impl<'de> de::Visitor<'de> for LenientVisitor {
+    type Value = String;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string or a number")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(v.to_string())
+    }
+}
+
+/// `deserialize_with` helper for ids iyzico sends as a number in some responses and as a string
+/// in others.
+pub fn deserialize_string<'de, D: de::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    struct LenientString(String);
+
+    impl<'de> de::Deserialize<'de> for LenientString {
+        fn deserialize<D: de::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer
+                .deserialize_any(LenientVisitor)
+                .map(LenientString)
+        }
+    }
+
+    Ok(<Option<LenientString> as de::Deserialize>::deserialize(deserializer)?.map(|value| value.0))
+}
@@ -0,0 +1,70 @@
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use crate::client::HttpClient;
+use crate::options::Options;
+use crate::requests::RetrieveLoyaltyRequest;
+use crate::requests::Validate;
+use crate::resource::IyzipayResource;
+use crate::types::Result;
+
+/// The reward points of a card and the amount they are worth in the requested currency.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Loyalty {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    points: Option<BigDecimal>,
+
+    amount: Option<BigDecimal>,
+
+    currency: Option<String>,
+}
+
+impl Loyalty {
+    pub fn retrieve(req: &RetrieveLoyaltyRequest, options: &Options) -> Result<Loyalty> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/loyalty/inquire");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_points<T: Into<BigDecimal>>(&mut self, points: T) {
+        self.points = Some(points.into());
+    }
+
+    pub fn set_amount<T: Into<BigDecimal>>(&mut self, amount: T) {
+        self.amount = Some(amount.into());
+    }
+
+    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn points(&self) -> Option<&BigDecimal> {
+        self.points.as_ref()
+    }
+    /// The most that can be paid with the points, used as `Reward::set_reward_amount`.
+    pub fn amount(&self) -> Option<&BigDecimal> {
+        self.amount.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+}
+
+impl std::ops::Deref for Loyalty {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
@@ -1,13 +1,48 @@
 pub use self::api::Api;
+pub use self::basic::BasicBkm;
+pub use self::basic::BasicBkmInitialize;
+pub use self::basic::BasicPayment;
+pub use self::basic::BasicPaymentPostAuth;
+pub use self::basic::BasicPaymentPreAuth;
+pub use self::basic::BasicPaymentResource;
+pub use self::basic::BasicThreedsInitialize;
+pub use self::basic::BasicThreedsInitializePreAuth;
+pub use self::basic::BasicThreedsPayment;
+pub use self::basic::BkmInstallment;
+pub use self::basic::BkmInstallmentPrice;
 pub use self::bin_number::BinNumber;
 pub use self::card::Card;
+pub use self::card::CardAssociation;
+pub use self::card::CardFamily;
 pub use self::card::CardInformation;
 pub use self::card::CardList;
 pub use self::card::CardManagementPageCard;
 pub use self::card::CardManagementPageInitialize;
+pub use self::card::CardType;
+pub use self::card_data::bin_number;
+pub use self::card_data::is_expired;
+pub use self::card_data::is_luhn_valid;
+pub use self::card_data::mask_card_number;
+pub use self::card_data::normalize_card_number;
+pub use self::card_data::normalize_expire_month;
+pub use self::card_data::normalize_expire_year;
 pub use self::checkout::CheckoutForm;
 pub use self::checkout::CheckoutFormInitialize;
+pub use self::contact::is_valid_email;
+pub use self::contact::normalize_phone_number;
+pub use self::contact::normalize_phone_number_for;
+pub use self::contact::parse_ip;
+pub use self::contact::DEFAULT_COUNTRY_CODE;
 pub use self::currency::Currency;
+pub use self::date::parse_date_time;
+pub use self::date::IntoDateTime;
+pub use self::date::DATE_TIME_FORMAT;
+pub use self::iban::Iban;
+pub use self::iban::IbanError;
+pub use self::identity::is_valid_foreign_identity_number;
+pub use self::identity::is_valid_tckn;
+pub use self::identity::is_valid_vkn;
+pub use self::identity::IdentityNumberType;
 pub use self::installment::InstallmentDetail;
 pub use self::installment::InstallmentInfo;
 pub use self::installment::InstallmentPrice;
@@ -27,44 +62,102 @@ pub use self::iyziup::IyziupPayment;
 pub use self::iyziup::OrderItem;
 pub use self::iyziup::OrderItemType;
 pub use self::locale::Locale;
+pub use self::loyalty::Loyalty;
+pub use self::money::Money;
+pub use self::money::MoneyError;
 pub use self::payment::Address;
+pub use self::payment::AmountBasedRefund;
 pub use self::payment::BasketItem;
 pub use self::payment::BasketItemType;
 pub use self::payment::Bkm;
 pub use self::payment::BkmInitialize;
 pub use self::payment::Buyer;
 pub use self::payment::Cancel;
+pub use self::payment::FraudStatus;
 pub use self::payment::Payment;
 pub use self::payment::PaymentCard;
 pub use self::payment::PaymentChannel;
 pub use self::payment::PaymentGroup;
 pub use self::payment::PaymentItem;
+pub use self::payment::PaymentPostAuth;
+pub use self::payment::PaymentPreAuth;
+pub use self::payment::PaymentStatus;
 pub use self::payment::PeccoInitialize;
 pub use self::payment::PeccoPayment;
+pub use self::payment::Phase;
 pub use self::payment::Refund;
 pub use self::payment::RefundReason;
+pub use self::payment::RefundToBalance;
+pub use self::payment::Reward;
 pub use self::payment::ThreedsInitialize;
+pub use self::payment::ThreedsInitializePreAuth;
 pub use self::payment::ThreedsPayment;
+pub use self::payment::TransactionStatus;
+pub use self::reporting::PaymentDetailsReport;
+pub use self::reporting::ReportingCancel;
+pub use self::reporting::ReportingItemTransaction;
+pub use self::reporting::ReportingPayment;
+pub use self::reporting::ReportingRefund;
+pub use self::reporting::ReportingTransaction;
+pub use self::reporting::ReportingTransactionType;
+pub use self::reporting::TransactionReport;
+pub use self::reporting::TransactionReportPages;
 pub use self::status::Status;
 pub use self::sub_merchant::Apm;
 pub use self::sub_merchant::ApmType;
 pub use self::sub_merchant::Approval;
 pub use self::sub_merchant::BouncedBankTransferList;
+pub use self::sub_merchant::CrossBookingFromSubMerchant;
+pub use self::sub_merchant::CrossBookingToSubMerchant;
 pub use self::sub_merchant::Disapproval;
 pub use self::sub_merchant::PayoutCompletedTransaction;
 pub use self::sub_merchant::PayoutCompletedTransactionList;
+pub use self::sub_merchant::SettlementToBalance;
 pub use self::sub_merchant::SubMerchant;
 pub use self::sub_merchant::SubMerchantType;
+pub use self::subscription::PaymentInterval;
+pub use self::subscription::PlanPaymentType;
+pub use self::subscription::PricingPlan;
+pub use self::subscription::PricingPlanItem;
+pub use self::subscription::Subscription;
+pub use self::subscription::SubscriptionCardUpdate;
+pub use self::subscription::SubscriptionCheckoutForm;
+pub use self::subscription::SubscriptionCheckoutFormInitialize;
+pub use self::subscription::SubscriptionCustomer;
+pub use self::subscription::SubscriptionCustomerItem;
+pub use self::subscription::SubscriptionItem;
+pub use self::subscription::SubscriptionItemStatus;
+pub use self::subscription::SubscriptionOrder;
+pub use self::subscription::SubscriptionOrderStatus;
+pub use self::subscription::SubscriptionPaging;
+pub use self::subscription::SubscriptionProduct;
+pub use self::subscription::SubscriptionProductItem;
+pub use self::subscription::SubscriptionResource;
+pub use self::subscription::SubscriptionStatus;
+pub use self::subscription::SubscriptionUpgradePeriod;
+
+#[macro_use]
+mod lenient;
 
 mod api;
+mod basic;
 mod bin_number;
 mod card;
+mod card_data;
 mod checkout;
+mod contact;
 mod currency;
+pub(crate) mod date;
+mod iban;
+mod identity;
 mod installment;
 mod iyzilink;
 mod iyziup;
 mod locale;
+mod loyalty;
+mod money;
 mod payment;
+mod reporting;
 mod status;
 mod sub_merchant;
+mod subscription;
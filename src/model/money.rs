@@ -0,0 +1,142 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+
+use crate::model::Currency;
+use crate::requests::RequestFormatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MoneyError {
+    CurrencyMismatch { expected: Currency, found: Currency },
+    InvalidAmount(String),
+}
+
+impl fmt::Display for MoneyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MoneyError::CurrencyMismatch { expected, found } => {
+                write!(
+                    f,
+                    "currency mismatch: expected {}, found {}",
+                    expected, found
+                )
+            }
+            MoneyError::InvalidAmount(amount) => write!(f, "invalid amount: {}", amount),
+        }
+    }
+}
+
+impl Error for MoneyError {}
+
+/// An amount in a currency, rounded half up to the minor units of the currency (e.g. `1.005 TRY`
+/// becomes `1.01 TRY`). Amounts in different currencies can not be added or subtracted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Money {
+    amount: BigDecimal,
+
+    currency: Currency,
+}
+
+impl Money {
+    pub fn new<T: Into<BigDecimal>>(amount: T, currency: Currency) -> Self {
+        Money {
+            amount: round_half_up(&amount.into(), currency.minor_units() as i64),
+            currency,
+        }
+    }
+
+    pub fn parse(amount: &str, currency: Currency) -> Result<Self, MoneyError> {
+        BigDecimal::from_str(amount.trim())
+            .map(|amount| Money::new(amount, currency))
+            .map_err(|_| MoneyError::InvalidAmount(amount.to_string()))
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Money::new(BigDecimal::from(0), currency)
+    }
+
+    /// Sums `items`, failing on the first amount in another currency.
+    pub fn sum<'a, I: IntoIterator<Item = &'a Money>>(
+        currency: Currency,
+        items: I,
+    ) -> Result<Self, MoneyError> {
+        items
+            .into_iter()
+            .try_fold(Money::zero(currency), |sum, item| sum.checked_add(item))
+    }
+
+    pub fn amount(&self) -> &BigDecimal {
+        &self.amount
+    }
+
+    pub fn currency(&self) -> &Currency {
+        &self.currency
+    }
+
+    pub fn checked_add(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        Ok(Money::new(
+            &self.amount + &other.amount,
+            self.currency.clone(),
+        ))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money, MoneyError> {
+        self.same_currency(other)?;
+        Ok(Money::new(
+            &self.amount - &other.amount,
+            self.currency.clone(),
+        ))
+    }
+
+    pub fn multiply<T: Into<BigDecimal>>(&self, factor: T) -> Money {
+        Money::new(&self.amount * factor.into(), self.currency.clone())
+    }
+
+    /// Formats the amount like `RequestFormatter::format_price`, e.g. `1.0` or `0.25`.
+    pub fn format_price(&self) -> String {
+        RequestFormatter::format_price(&self.amount)
+    }
+
+    /// Fails with `MoneyError::CurrencyMismatch` unless `other` is in the currency of `self`.
+    pub fn same_currency(&self, other: &Money) -> Result<(), MoneyError> {
+        if self.currency == other.currency {
+            Ok(())
+        } else {
+            Err(MoneyError::CurrencyMismatch {
+                expected: self.currency.clone(),
+                found: other.currency.clone(),
+            })
+        }
+    }
+}
+
+/// Rounds half away from zero on the digits alone, as `BigDecimal::round` panics on amounts that
+/// do not fit in an `i128`.
+fn round_half_up(amount: &BigDecimal, scale: i64) -> BigDecimal {
+    let (digits, _) = amount.with_scale(scale + 1).into_bigint_and_exponent();
+    let ten = BigInt::from(10);
+    let last_digit = &digits % &ten;
+    let mut rounded = digits / ten;
+    if last_digit >= BigInt::from(5) {
+        rounded += 1;
+    } else if last_digit <= BigInt::from(-5) {
+        rounded -= 1;
+    }
+    BigDecimal::new(rounded, scale)
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {}", self.format_price(), self.currency)
+    }
+}
+
+impl From<Money> for BigDecimal {
+    fn from(money: Money) -> Self {
+        money.amount
+    }
+}
@@ -1,22 +1,48 @@
 use std::fmt;
 
 use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+use chrono::NaiveDateTime;
 use log::debug;
 
 use crate::client::HttpClient;
+use crate::model::bin_number;
+use crate::model::date::{deserialize_date_time, serialize_date_time};
+use crate::model::date::{deserialize_date_time_field, DateTimeField};
+use crate::model::mask_card_number;
+use crate::model::normalize_card_number;
+use crate::model::normalize_expire_month;
+use crate::model::normalize_expire_year;
+use crate::model::normalize_phone_number;
+use crate::model::CardAssociation;
+use crate::model::CardFamily;
+use crate::model::CardType;
+use crate::model::Currency;
+use crate::model::IdentityNumberType;
+use crate::model::IntoDateTime;
+use crate::model::Money;
 use crate::options::Options;
+use crate::requests::serialize_price;
+use crate::requests::CreateAmountBasedRefundRequest;
 use crate::requests::CreateCancelRequest;
+use crate::requests::CreatePaymentPostAuthRequest;
 use crate::requests::CreatePaymentRequest;
 use crate::requests::CreatePeccoInitializeRequest;
 use crate::requests::CreatePeccoPaymentRequest;
 use crate::requests::CreateRefundRequest;
+use crate::requests::CreateRefundToBalanceRequest;
 use crate::requests::CreateThreedsPaymentRequest;
-use crate::requests::PKISerialize;
-use crate::requests::RequestStringBuilder;
 use crate::requests::RetrieveBkmRequest;
 use crate::requests::RetrievePaymentRequest;
+use crate::requests::Validate;
+use crate::requests::ValidationErrors;
+use crate::requests::Validator;
+use crate::requests::Violation;
+use crate::requests::MAX_NAME_LENGTH;
 use crate::requests::{CreateBkmInitializeRequest, UpdatePaymentItemRequest};
 use crate::resource::IyzipayResource;
+use crate::secret::deserialize_card_number;
+use crate::secret::Secret;
 use crate::types::Result;
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -58,6 +84,7 @@ impl std::fmt::Display for PaymentChannel {
 pub enum PaymentGroup {
     Product,
     Listing,
+    /// Recurring payments of the subscription API, see `Subscription`.
     Subscription,
 }
 
@@ -125,12 +152,39 @@ impl Default for RefundReason {
     }
 }
 
-impl PKISerialize for Option<RefundReason> {
-    fn serialize(&self) -> Option<String> {
-        match &self {
-            Some(val) => Option::from(val.value().to_string()),
-            None => None,
-        }
+lenient_enum! {
+    pub enum PaymentStatus {
+        Success => "SUCCESS",
+        Failure => "FAILURE",
+        InitThreeds => "INIT_THREEDS",
+        CallbackThreeds => "CALLBACK_THREEDS",
+        BkmPosSelected => "BKM_POS_SELECTED",
+        CallbackPecco => "CALLBACK_PECCO",
+    }
+}
+
+lenient_enum! {
+    /// Result of iyzico's fraud check, sent as `1`, `0` and `-1`.
+    pub enum FraudStatus {
+        Approved => "1",
+        Pending => "0",
+        Rejected => "-1",
+    }
+}
+
+lenient_enum! {
+    /// Marketplace approval state of a basket item, sent as a number.
+    pub enum TransactionStatus {
+        WaitingForApproval => "1",
+        Approved => "2",
+    }
+}
+
+lenient_enum! {
+    pub enum Phase {
+        Auth => "AUTH",
+        PreAuth => "PRE_AUTH",
+        PostAuth => "POST_AUTH",
     }
 }
 
@@ -140,6 +194,7 @@ impl PKISerialize for Option<RefundReason> {
 pub struct BasketItem {
     id: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
     name: Option<String>,
@@ -152,7 +207,11 @@ pub struct BasketItem {
 
     sub_merchant_key: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     sub_merchant_price: Option<BigDecimal>,
+
+    #[serde(skip)]
+    currency: Option<Currency>,
 }
 
 impl BasketItem {
@@ -168,6 +227,13 @@ impl BasketItem {
         self.price = Some(price.into());
     }
 
+    /// Sets the price and keeps its currency, which the validation of the payment request checks
+    /// against the currency of the request. The currency itself is not sent.
+    pub fn set_price_money(&mut self, price: Money) {
+        self.currency = Some(price.currency().clone());
+        self.price = Some(price.into());
+    }
+
     pub fn set_name<T: Into<String>>(&mut self, name: T) {
         self.name = Some(name.into());
     }
@@ -180,7 +246,11 @@ impl BasketItem {
         self.category2 = Some(category2.into());
     }
 
-    pub fn set_item_type<T: Into<String>>(&mut self, item_type: T) {
+    pub fn set_item_type(&mut self, item_type: BasketItemType) {
+        self.item_type = Some(item_type.value().to_string());
+    }
+
+    pub fn set_item_type_raw<T: Into<String>>(&mut self, item_type: T) {
         self.item_type = Some(item_type.into());
     }
 
@@ -216,20 +286,8 @@ impl BasketItem {
     pub fn sub_merchant_price(&self) -> Option<&BigDecimal> {
         self.sub_merchant_price.as_ref()
     }
-}
-
-impl PKISerialize for BasketItem {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("id", self.id.as_ref());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("category1", self.category1.as_ref());
-        ser.append_option("category2", self.category2.as_ref());
-        ser.append_option("itemType", self.item_type.as_ref());
-        ser.append_option("subMerchantKey", self.sub_merchant_key.as_ref());
-        ser.append_price_option("subMerchantPrice", self.sub_merchant_price.as_ref());
-        Option::from(ser.build(true))
+    pub fn currency(&self) -> Option<&Currency> {
+        self.currency.as_ref()
     }
 }
 
@@ -290,31 +348,20 @@ impl Address {
     }
 }
 
-impl PKISerialize for Address {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("address", self.address.as_ref());
-        ser.append_option("zipCode", self.zip_code.as_ref());
-        ser.append_option("contactName", self.contact_name.as_ref());
-        ser.append_option("city", self.city.as_ref());
-        ser.append_option("country", self.country.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
 pub struct PaymentCard {
     card_holder_name: Option<String>,
 
-    card_number: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_card_number")]
+    card_number: Option<Secret>,
 
-    expire_year: Option<String>,
+    expire_year: Option<Secret>,
 
-    expire_month: Option<String>,
+    expire_month: Option<Secret>,
 
-    cvc: Option<String>,
+    cvc: Option<Secret>,
 
     register_card: Option<u8>,
 
@@ -323,6 +370,8 @@ pub struct PaymentCard {
     card_token: Option<String>,
 
     card_user_key: Option<String>,
+
+    reward: Option<Reward>,
 }
 
 impl PaymentCard {
@@ -334,20 +383,36 @@ impl PaymentCard {
         self.card_holder_name = Some(card_holder_name.into());
     }
 
+    /// Sets the card number without spaces and dashes.
     pub fn set_card_number<T: Into<String>>(&mut self, card_number: T) {
-        self.card_number = Some(card_number.into());
+        let card_number = Secret::new(card_number);
+        self.card_number = Some(Secret::card_number(normalize_card_number(
+            card_number.expose(),
+        )));
     }
 
+    /// Sets the year, expanding two digit years such as `30` to `2030`.
     pub fn set_expire_year<T: Into<String>>(&mut self, expire_year: T) {
-        self.expire_year = Some(expire_year.into());
+        let expire_year = Secret::new(expire_year);
+        self.expire_year = Some(
+            normalize_expire_year(expire_year.expose())
+                .map(Secret::new)
+                .unwrap_or(expire_year),
+        );
     }
 
+    /// Sets the month, padding it to two digits.
     pub fn set_expire_month<T: Into<String>>(&mut self, expire_month: T) {
-        self.expire_month = Some(expire_month.into());
+        let expire_month = Secret::new(expire_month);
+        self.expire_month = Some(
+            normalize_expire_month(expire_month.expose())
+                .map(Secret::new)
+                .unwrap_or(expire_month),
+        );
     }
 
     pub fn set_cvc<T: Into<String>>(&mut self, cvc: T) {
-        self.cvc = Some(cvc.into());
+        self.cvc = Some(Secret::new(cvc));
     }
 
     pub fn set_register_card<T: Into<u8>>(&mut self, register_card: T) {
@@ -366,19 +431,25 @@ impl PaymentCard {
         self.card_user_key = Some(card_user_key.into());
     }
 
+    /// Pays `reward_amount` of the paid price with the reward points of the card, see
+    /// `Loyalty::retrieve` for the points available.
+    pub fn set_reward(&mut self, reward: Reward) {
+        self.reward = Some(reward);
+    }
+
     pub fn card_holder_name(&self) -> Option<&String> {
         self.card_holder_name.as_ref()
     }
-    pub fn card_number(&self) -> Option<&String> {
+    pub fn card_number(&self) -> Option<&Secret> {
         self.card_number.as_ref()
     }
-    pub fn expire_year(&self) -> Option<&String> {
+    pub fn expire_year(&self) -> Option<&Secret> {
         self.expire_year.as_ref()
     }
-    pub fn expire_month(&self) -> Option<&String> {
+    pub fn expire_month(&self) -> Option<&Secret> {
         self.expire_month.as_ref()
     }
-    pub fn cvc(&self) -> Option<&String> {
+    pub fn cvc(&self) -> Option<&Secret> {
         self.cvc.as_ref()
     }
     pub fn register_card(&self) -> Option<&u8> {
@@ -393,21 +464,104 @@ impl PaymentCard {
     pub fn card_user_key(&self) -> Option<&String> {
         self.card_user_key.as_ref()
     }
+    pub fn reward(&self) -> Option<&Reward> {
+        self.reward.as_ref()
+    }
+
+    /// Returns the association detected from the card number.
+    pub fn detected_card_association(&self) -> Option<CardAssociation> {
+        self.card_number
+            .as_ref()
+            .map(Secret::expose)
+            .and_then(CardAssociation::from_card_number)
+    }
+
+    pub fn bin_number(&self) -> Option<String> {
+        self.card_number
+            .as_ref()
+            .map(Secret::expose)
+            .and_then(bin_number)
+    }
+
+    /// Returns the card number with all but the BIN and the last four digits masked.
+    pub fn masked_card_number(&self) -> Option<String> {
+        self.card_number
+            .as_ref()
+            .map(|card_number| mask_card_number(card_number.expose()))
+    }
 }
 
-impl PKISerialize for PaymentCard {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("cardHolderName", self.card_holder_name.as_ref());
-        ser.append_option("cardNumber", self.card_number.as_ref());
-        ser.append_option("expireYear", self.expire_year.as_ref());
-        ser.append_option("expireMonth", self.expire_month.as_ref());
-        ser.append_option("cvc", self.cvc.as_ref());
-        ser.append_option("registerCard", self.register_card.as_ref());
-        ser.append_option("cardAlias", self.card_alias.as_ref());
-        ser.append_option("cardToken", self.card_token.as_ref());
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        Option::from(ser.build(true))
+impl Validate for PaymentCard {
+    /// Checks the card number, expiry and CVC, unless the card is a stored card token.
+    fn validate_into(&self, validator: &mut Validator) {
+        if let Some(reward) = self.reward.as_ref() {
+            validator.nested("reward", |validator| reward.validate_into(validator));
+        }
+        if self.card_token.is_some() {
+            validator.required("cardUserKey", self.card_user_key.as_ref());
+            return;
+        }
+        validator.required("cardHolderName", self.card_holder_name.as_ref());
+        validator.max_length(
+            "cardHolderName",
+            self.card_holder_name.as_ref(),
+            MAX_NAME_LENGTH,
+        );
+        let card_association =
+            validator.card_number("cardNumber", self.card_number.as_ref().map(Secret::expose));
+        validator.expiry(
+            self.expire_month.as_ref().map(Secret::expose),
+            self.expire_year.as_ref().map(Secret::expose),
+        );
+        validator.cvc(
+            "cvc",
+            self.cvc.as_ref().map(Secret::expose),
+            card_association.as_ref(),
+        );
+    }
+}
+
+/// The part of a payment paid with the reward points of a Bonus, World or Maximum card.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Reward {
+    #[serde(serialize_with = "serialize_price")]
+    reward_amount: Option<BigDecimal>,
+
+    reward_usage: Option<u8>,
+}
+
+impl Reward {
+    pub fn new() -> Self {
+        Reward::default()
+    }
+
+    pub fn set_reward_amount<T: Into<BigDecimal>>(&mut self, reward_amount: T) {
+        self.reward_amount = Some(reward_amount.into());
+    }
+
+    /// `1` spends the points, which is what a payment request sends.
+    pub fn set_reward_usage<T: Into<u8>>(&mut self, reward_usage: T) {
+        self.reward_usage = Some(reward_usage.into());
+    }
+
+    pub fn reward_amount(&self) -> Option<&BigDecimal> {
+        self.reward_amount.as_ref()
+    }
+    pub fn reward_usage(&self) -> Option<&u8> {
+        self.reward_usage.as_ref()
+    }
+}
+
+impl Validate for Reward {
+    fn validate_into(&self, validator: &mut Validator) {
+        if let Some(reward_amount) = validator.required("rewardAmount", self.reward_amount.as_ref())
+        {
+            if reward_amount <= &BigDecimal::zero() {
+                validator.add("rewardAmount", "must be greater than 0");
+            }
+        }
     }
 }
 
@@ -427,9 +581,11 @@ pub struct Buyer {
 
     gsm_number: Option<String>,
 
-    registration_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_date_time_field")]
+    registration_date: Option<DateTimeField>,
 
-    last_login_date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_date_time_field")]
+    last_login_date: Option<DateTimeField>,
 
     registration_address: Option<String>,
 
@@ -440,6 +596,9 @@ pub struct Buyer {
     zip_code: Option<String>,
 
     ip: Option<String>,
+
+    #[serde(skip)]
+    identity_number_type: IdentityNumberType,
 }
 
 impl Buyer {
@@ -461,22 +620,34 @@ impl Buyer {
 
     pub fn set_identity_number<T: Into<String>>(&mut self, identity_number: T) {
         self.identity_number = Some(identity_number.into());
+        self.identity_number_type = IdentityNumberType::Tckn;
+    }
+
+    /// Sets a passport or other foreign identity number, which is validated as such instead of as
+    /// a TCKN.
+    pub fn set_foreign_identity_number<T: Into<String>>(&mut self, identity_number: T) {
+        self.identity_number = Some(identity_number.into());
+        self.identity_number_type = IdentityNumberType::Foreign;
     }
 
     pub fn set_email<T: Into<String>>(&mut self, email: T) {
-        self.email = Some(email.into());
+        self.email = Some(email.into().trim().to_string());
     }
 
+    /// Sets the phone number in E.164, e.g. `+905350000000`, if it can be normalized.
     pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
-        self.gsm_number = Some(gsm_number.into());
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
     }
 
-    pub fn set_registration_date<T: Into<String>>(&mut self, registration_date: T) {
-        self.registration_date = Some(registration_date.into());
+    /// Sets the registration date from a chrono date, or from a `yyyy-MM-dd HH:mm:ss` string.
+    pub fn set_registration_date<T: IntoDateTime>(&mut self, registration_date: T) {
+        self.registration_date = DateTimeField::from(registration_date);
     }
 
-    pub fn set_last_login_date<T: Into<String>>(&mut self, last_login_date: T) {
-        self.last_login_date = Some(last_login_date.into());
+    /// Sets the last login date from a chrono date, or from a `yyyy-MM-dd HH:mm:ss` string.
+    pub fn set_last_login_date<T: IntoDateTime>(&mut self, last_login_date: T) {
+        self.last_login_date = DateTimeField::from(last_login_date);
     }
 
     pub fn set_registration_address<T: Into<String>>(&mut self, registration_address: T) {
@@ -496,7 +667,7 @@ impl Buyer {
     }
 
     pub fn set_ip<T: Into<String>>(&mut self, ip: T) {
-        self.ip = Some(ip.into());
+        self.ip = Some(ip.into().trim().to_string());
     }
 
     pub fn id(&self) -> Option<&String> {
@@ -511,17 +682,24 @@ impl Buyer {
     pub fn identity_number(&self) -> Option<&String> {
         self.identity_number.as_ref()
     }
+    pub fn identity_number_type(&self) -> IdentityNumberType {
+        self.identity_number_type
+    }
     pub fn email(&self) -> Option<&String> {
         self.email.as_ref()
     }
     pub fn gsm_number(&self) -> Option<&String> {
         self.gsm_number.as_ref()
     }
-    pub fn registration_date(&self) -> Option<&String> {
-        self.registration_date.as_ref()
+    pub fn registration_date(&self) -> Option<&NaiveDateTime> {
+        self.registration_date
+            .as_ref()
+            .and_then(DateTimeField::date_time)
     }
-    pub fn last_login_date(&self) -> Option<&String> {
-        self.last_login_date.as_ref()
+    pub fn last_login_date(&self) -> Option<&NaiveDateTime> {
+        self.last_login_date
+            .as_ref()
+            .and_then(DateTimeField::date_time)
     }
     pub fn registration_address(&self) -> Option<&String> {
         self.registration_address.as_ref()
@@ -540,23 +718,18 @@ impl Buyer {
     }
 }
 
-impl PKISerialize for Buyer {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("id", self.id.as_ref());
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("surname", self.surname.as_ref());
-        ser.append_option("identityNumber", self.identity_number.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("gsmNumber", self.gsm_number.as_ref());
-        ser.append_option("registrationDate", self.registration_date.as_ref());
-        ser.append_option("lastLoginDate", self.last_login_date.as_ref());
-        ser.append_option("registrationAddress", self.registration_address.as_ref());
-        ser.append_option("city", self.city.as_ref());
-        ser.append_option("country", self.country.as_ref());
-        ser.append_option("zipCode", self.zip_code.as_ref());
-        ser.append_option("ip", self.ip.as_ref());
-        Option::from(ser.build(true))
+impl Validate for Buyer {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.max_length("name", self.name.as_ref(), MAX_NAME_LENGTH);
+        validator.max_length("surname", self.surname.as_ref(), MAX_NAME_LENGTH);
+        validator.identity_number(
+            "identityNumber",
+            self.identity_number.as_ref(),
+            self.identity_number_type,
+        );
+        validator.phone_number("gsmNumber", self.gsm_number.as_ref());
+        validator.email("email", self.email.as_ref());
+        validator.ip("ip", self.ip.as_ref());
     }
 }
 
@@ -569,12 +742,14 @@ pub struct Payment {
 
 impl Payment {
     pub fn create(req: &CreatePaymentRequest, options: &Options) -> Result<Payment> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/auth");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/auth").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -583,10 +758,11 @@ impl Payment {
     pub fn retrieve(req: &RetrievePaymentRequest, options: &Options) -> Result<Payment> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/detail");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/detail").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -617,9 +793,9 @@ pub struct PaymentResource {
 
     payment_id: Option<String>,
 
-    payment_status: Option<String>,
+    payment_status: Option<PaymentStatus>,
 
-    fraud_status: Option<u8>,
+    fraud_status: Option<FraudStatus>,
 
     merchant_commission_rate: Option<BigDecimal>,
 
@@ -629,11 +805,11 @@ pub struct PaymentResource {
 
     iyzi_commission_fee: Option<BigDecimal>,
 
-    card_type: Option<String>,
+    card_type: Option<CardType>,
 
-    card_association: Option<String>,
+    card_association: Option<CardAssociation>,
 
-    card_family: Option<String>,
+    card_family: Option<CardFamily>,
 
     card_token: Option<String>,
 
@@ -650,13 +826,21 @@ pub struct PaymentResource {
 
     auth_code: Option<String>,
 
-    phase: Option<String>,
+    phase: Option<Phase>,
 
     last_four_digits: Option<String>,
 
     pos_order_id: Option<String>,
 
     host_reference: Option<String>,
+
+    reward: Option<Reward>,
+
+    campaign_id: Option<String>,
+
+    plus_installment: Option<u8>,
+
+    deferral_months: Option<u8>,
 }
 
 impl PaymentResource {
@@ -680,11 +864,11 @@ impl PaymentResource {
         self.payment_id = Some(payment_id.into());
     }
 
-    pub fn set_payment_status<T: Into<String>>(&mut self, payment_status: T) {
+    pub fn set_payment_status<T: Into<PaymentStatus>>(&mut self, payment_status: T) {
         self.payment_status = Some(payment_status.into());
     }
 
-    pub fn set_fraud_status<T: Into<u8>>(&mut self, fraud_status: T) {
+    pub fn set_fraud_status<T: Into<FraudStatus>>(&mut self, fraud_status: T) {
         self.fraud_status = Some(fraud_status.into());
     }
 
@@ -713,15 +897,15 @@ impl PaymentResource {
         self.iyzi_commission_fee = Some(iyzi_commission_fee.into());
     }
 
-    pub fn set_card_type<T: Into<String>>(&mut self, card_type: T) {
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
         self.card_type = Some(card_type.into());
     }
 
-    pub fn set_card_association<T: Into<String>>(&mut self, card_association: T) {
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
         self.card_association = Some(card_association.into());
     }
 
-    pub fn set_card_family<T: Into<String>>(&mut self, card_family: T) {
+    pub fn set_card_family<T: Into<CardFamily>>(&mut self, card_family: T) {
         self.card_family = Some(card_family.into());
     }
 
@@ -753,7 +937,7 @@ impl PaymentResource {
         self.auth_code = Some(auth_code.into());
     }
 
-    pub fn set_phase<T: Into<String>>(&mut self, phase: T) {
+    pub fn set_phase<T: Into<Phase>>(&mut self, phase: T) {
         self.phase = Some(phase.into());
     }
 
@@ -769,6 +953,22 @@ impl PaymentResource {
         self.host_reference = Some(host_reference.into());
     }
 
+    pub fn set_reward(&mut self, reward: Reward) {
+        self.reward = Some(reward);
+    }
+
+    pub fn set_campaign_id<T: Into<String>>(&mut self, campaign_id: T) {
+        self.campaign_id = Some(campaign_id.into());
+    }
+
+    pub fn set_plus_installment<T: Into<u8>>(&mut self, plus_installment: T) {
+        self.plus_installment = Some(plus_installment.into());
+    }
+
+    pub fn set_deferral_months<T: Into<u8>>(&mut self, deferral_months: T) {
+        self.deferral_months = Some(deferral_months.into());
+    }
+
     pub fn price(&self) -> Option<&BigDecimal> {
         self.price.as_ref()
     }
@@ -784,10 +984,10 @@ impl PaymentResource {
     pub fn payment_id(&self) -> Option<&String> {
         self.payment_id.as_ref()
     }
-    pub fn payment_status(&self) -> Option<&String> {
+    pub fn payment_status(&self) -> Option<&PaymentStatus> {
         self.payment_status.as_ref()
     }
-    pub fn fraud_status(&self) -> Option<&u8> {
+    pub fn fraud_status(&self) -> Option<&FraudStatus> {
         self.fraud_status.as_ref()
     }
     pub fn merchant_commission_rate(&self) -> Option<&BigDecimal> {
@@ -802,13 +1002,13 @@ impl PaymentResource {
     pub fn iyzi_commission_fee(&self) -> Option<&BigDecimal> {
         self.iyzi_commission_fee.as_ref()
     }
-    pub fn card_type(&self) -> Option<&String> {
+    pub fn card_type(&self) -> Option<&CardType> {
         self.card_type.as_ref()
     }
-    pub fn card_association(&self) -> Option<&String> {
+    pub fn card_association(&self) -> Option<&CardAssociation> {
         self.card_association.as_ref()
     }
-    pub fn card_family(&self) -> Option<&String> {
+    pub fn card_family(&self) -> Option<&CardFamily> {
         self.card_family.as_ref()
     }
     pub fn card_token(&self) -> Option<&String> {
@@ -832,7 +1032,7 @@ impl PaymentResource {
     pub fn auth_code(&self) -> Option<&String> {
         self.auth_code.as_ref()
     }
-    pub fn phase(&self) -> Option<&String> {
+    pub fn phase(&self) -> Option<&Phase> {
         self.phase.as_ref()
     }
     pub fn last_four_digits(&self) -> Option<&String> {
@@ -844,6 +1044,20 @@ impl PaymentResource {
     pub fn host_reference(&self) -> Option<&String> {
         self.host_reference.as_ref()
     }
+    /// The part of the paid price paid with reward points, if the card had a reward.
+    pub fn reward(&self) -> Option<&Reward> {
+        self.reward.as_ref()
+    }
+    /// The bank campaign the payment was made with, see `InstallmentPrice::campaign_id`.
+    pub fn campaign_id(&self) -> Option<&String> {
+        self.campaign_id.as_ref()
+    }
+    pub fn plus_installment(&self) -> Option<&u8> {
+        self.plus_installment.as_ref()
+    }
+    pub fn deferral_months(&self) -> Option<&u8> {
+        self.deferral_months.as_ref()
+    }
 }
 
 impl std::ops::Deref for PaymentResource {
@@ -864,7 +1078,7 @@ pub struct PaymentItem {
 
     payment_transaction_id: Option<String>,
 
-    transaction_status: Option<u8>,
+    transaction_status: Option<TransactionStatus>,
 
     price: Option<BigDecimal>,
 
@@ -884,7 +1098,12 @@ pub struct PaymentItem {
 
     blockage_rate_amount_sub_merchant: Option<BigDecimal>,
 
-    blockage_resolved_date: Option<String>,
+    #[serde(
+        default,
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    blockage_resolved_date: Option<NaiveDateTime>,
 
     sub_merchant_key: Option<String>,
 
@@ -903,10 +1122,11 @@ impl PaymentItem {
     pub fn update(req: &UpdatePaymentItemRequest, options: &Options) -> Result<PaymentItem> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/item");
         let res = HttpClient::create().put(
-            format!("{}{}", options.base_url(), "/payment/item").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -920,7 +1140,7 @@ impl PaymentItem {
         self.payment_transaction_id = Some(payment_transaction_id.into());
     }
 
-    pub fn set_transaction_status<T: Into<u8>>(&mut self, transaction_status: T) {
+    pub fn set_transaction_status<T: Into<TransactionStatus>>(&mut self, transaction_status: T) {
         self.transaction_status = Some(transaction_status.into());
     }
 
@@ -975,8 +1195,8 @@ impl PaymentItem {
         self.blockage_rate_amount_sub_merchant = Some(blockage_rate_amount_sub_merchant.into());
     }
 
-    pub fn set_blockage_resolved_date<T: Into<String>>(&mut self, blockage_resolved_date: T) {
-        self.blockage_resolved_date = Some(blockage_resolved_date.into());
+    pub fn set_blockage_resolved_date<T: IntoDateTime>(&mut self, blockage_resolved_date: T) {
+        self.blockage_resolved_date = blockage_resolved_date.into_date_time();
     }
 
     pub fn set_sub_merchant_key<T: Into<String>>(&mut self, sub_merchant_key: T) {
@@ -1015,7 +1235,7 @@ impl PaymentItem {
     pub fn payment_transaction_id(&self) -> Option<&String> {
         self.payment_transaction_id.as_ref()
     }
-    pub fn transaction_status(&self) -> Option<&u8> {
+    pub fn transaction_status(&self) -> Option<&TransactionStatus> {
         self.transaction_status.as_ref()
     }
     pub fn price(&self) -> Option<&BigDecimal> {
@@ -1045,7 +1265,7 @@ impl PaymentItem {
     pub fn blockage_rate_amount_sub_merchant(&self) -> Option<&BigDecimal> {
         self.blockage_rate_amount_sub_merchant.as_ref()
     }
-    pub fn blockage_resolved_date(&self) -> Option<&String> {
+    pub fn blockage_resolved_date(&self) -> Option<&NaiveDateTime> {
         self.blockage_resolved_date.as_ref()
     }
     pub fn sub_merchant_key(&self) -> Option<&String> {
@@ -1212,10 +1432,11 @@ impl Cancel {
     pub fn create(req: &CreateCancelRequest, options: &Options) -> Result<Cancel> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/cancel");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/cancel").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1285,12 +1506,14 @@ pub struct ThreedsInitialize {
 
 impl ThreedsInitialize {
     pub fn create(req: &CreatePaymentRequest, options: &Options) -> Result<ThreedsInitialize> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/3dsecure/initialize");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/3dsecure/initialize").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1312,6 +1535,149 @@ impl std::ops::Deref for ThreedsInitialize {
     }
 }
 
+/// A payment that only holds the amount on the card until it is captured with `PaymentPostAuth`
+/// or released with `void`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentPreAuth {
+    #[serde(flatten)]
+    resource: PaymentResource,
+}
+
+impl PaymentPreAuth {
+    pub fn create(req: &CreatePaymentRequest, options: &Options) -> Result<PaymentPreAuth> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/preauth");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn retrieve(req: &RetrievePaymentRequest, options: &Options) -> Result<PaymentPreAuth> {
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/detail");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    /// Releases the held amount through `Cancel`, which is only possible before it is captured.
+    /// It fails without a request when the pre-authorization has no payment id, e.g. when it failed.
+    pub fn void(&self, options: &Options) -> Result<Cancel> {
+        let payment_id = self
+            .payment_id()
+            .ok_or_else(|| ValidationErrors::from(Violation::new("paymentId", "is required")))?;
+        let mut req = CreateCancelRequest::void(payment_id.as_str());
+        if let Some(conversation_id) = self.conversation_id() {
+            req.set_conversation_id(conversation_id.as_str());
+        }
+        Cancel::create(&req, options)
+    }
+}
+
+impl std::ops::Deref for PaymentPreAuth {
+    type Target = PaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Captures the whole or part of a pre-authorized payment.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentPostAuth {
+    #[serde(flatten)]
+    resource: PaymentResource,
+}
+
+impl PaymentPostAuth {
+    pub fn create(
+        req: &CreatePaymentPostAuthRequest,
+        options: &Options,
+    ) -> Result<PaymentPostAuth> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/postauth");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for PaymentPostAuth {
+    type Target = PaymentResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Starts a 3DS pre-authorization, which is completed with `ThreedsPayment` like a 3DS payment
+/// and captured with `PaymentPostAuth`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ThreedsInitializePreAuth {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    #[serde(rename = "threeDSHtmlContent")]
+    html_content: Option<String>,
+}
+
+impl ThreedsInitializePreAuth {
+    pub fn create(
+        req: &CreatePaymentRequest,
+        options: &Options,
+    ) -> Result<ThreedsInitializePreAuth> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/3dsecure/initialize/preauth"
+        );
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_html_content<T: Into<String>>(&mut self, html_content: T) {
+        self.html_content = Some(html_content.into());
+    }
+
+    pub fn html_content(&self) -> Option<&String> {
+        self.html_content.as_ref()
+    }
+}
+
+impl std::ops::Deref for ThreedsInitializePreAuth {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -1324,10 +1690,11 @@ impl ThreedsPayment {
     pub fn create(req: &CreateThreedsPaymentRequest, options: &Options) -> Result<ThreedsPayment> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/3dsecure/auth");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/3dsecure/auth").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1336,10 +1703,11 @@ impl ThreedsPayment {
     pub fn retrieve(req: &RetrievePaymentRequest, options: &Options) -> Result<ThreedsPayment> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/detail");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/detail").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1379,10 +1747,11 @@ impl Refund {
     pub fn create(req: &CreateRefundRequest, options: &Options) -> Result<Refund> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/refund");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/refund").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1446,6 +1815,146 @@ impl std::ops::Deref for Refund {
     }
 }
 
+/// A refund of an amount of a whole payment, see `CreateAmountBasedRefundRequest`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct AmountBasedRefund {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    payment_id: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    currency: Option<String>,
+
+    auth_code: Option<String>,
+
+    host_reference: Option<String>,
+}
+
+impl AmountBasedRefund {
+    /// Sent to iyzico's v2 refund endpoint, which is always signed with `IYZWSv2`.
+    pub fn create(
+        req: &CreateAmountBasedRefundRequest,
+        options: &Options,
+    ) -> Result<AmountBasedRefund> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/v2/payment/refund");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_v2(uri.to_owned(), request.to_owned(), options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_auth_code<T: Into<String>>(&mut self, auth_code: T) {
+        self.auth_code = Some(auth_code.into());
+    }
+
+    pub fn set_host_reference<T: Into<String>>(&mut self, host_reference: T) {
+        self.host_reference = Some(host_reference.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn auth_code(&self) -> Option<&String> {
+        self.auth_code.as_ref()
+    }
+    pub fn host_reference(&self) -> Option<&String> {
+        self.host_reference.as_ref()
+    }
+}
+
+impl std::ops::Deref for AmountBasedRefund {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// The page the buyer confirms a refund to their iyzico balance on.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct RefundToBalance {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    token: Option<String>,
+
+    url: Option<String>,
+}
+
+impl RefundToBalance {
+    pub fn create(
+        req: &CreateRefundToBalanceRequest,
+        options: &Options,
+    ) -> Result<RefundToBalance> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/refund-to-balance/init"
+        );
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_token<T: Into<String>>(&mut self, token: T) {
+        self.token = Some(token.into());
+    }
+
+    pub fn set_url<T: Into<String>>(&mut self, url: T) {
+        self.url = Some(url.into());
+    }
+
+    pub fn token(&self) -> Option<&String> {
+        self.token.as_ref()
+    }
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+}
+
+impl std::ops::Deref for RefundToBalance {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -1460,12 +1969,14 @@ pub struct BkmInitialize {
 
 impl BkmInitialize {
     pub fn create(req: &CreateBkmInitializeRequest, options: &Options) -> Result<BkmInitialize> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/bkm/initialize");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/bkm/initialize").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1510,10 +2021,11 @@ impl Bkm {
     pub fn retrieve(req: &RetrieveBkmRequest, options: &Options) -> Result<Bkm> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/bkm/auth/detail");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/bkm/auth/detail").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1563,12 +2075,14 @@ impl PeccoInitialize {
         req: &CreatePeccoInitializeRequest,
         options: &Options,
     ) -> Result<PeccoInitialize> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/pecco/initialize");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/pecco/initialize").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -1625,10 +2139,11 @@ impl PeccoPayment {
     pub fn create(req: &CreatePeccoPaymentRequest, options: &Options) -> Result<PeccoPayment> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/pecco/auth");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/pecco/auth").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -0,0 +1,899 @@
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use log::debug;
+
+use crate::client::HttpClient;
+use crate::model::date::deserialize_date_time;
+use crate::model::date::serialize_date_time;
+use crate::model::lenient::deserialize_string;
+use crate::model::CardAssociation;
+use crate::model::CardFamily;
+use crate::model::CardType;
+use crate::model::Currency;
+use crate::model::FraudStatus;
+use crate::model::IntoDateTime;
+use crate::model::Phase;
+use crate::model::Status;
+use crate::model::TransactionStatus;
+use crate::options::Options;
+use crate::requests::RequestQueryParams;
+use crate::requests::RetrievePaymentDetailsRequest;
+use crate::requests::RetrieveTransactionReportRequest;
+use crate::requests::Validate;
+use crate::resource::IyzipayResource;
+use crate::types::Result;
+
+const V2_REPORTING_PAYMENT: &str = "/v2/reporting/payment";
+
+lenient_enum! {
+    pub enum ReportingTransactionType {
+        Payment => "PAYMENT",
+        Cancel => "CANCEL",
+        Refund => "REFUND",
+    }
+}
+
+/// Payments, cancels and refunds of a single day, see `TransactionReport::pages` to fetch every
+/// page.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct TransactionReport {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    transactions: Option<Vec<ReportingTransaction>>,
+
+    current_page: Option<u32>,
+
+    total_page_count: Option<u32>,
+}
+
+impl TransactionReport {
+    pub fn retrieve(
+        req: &RetrieveTransactionReportRequest,
+        options: &Options,
+    ) -> Result<TransactionReport> {
+        TransactionReport::retrieve_page(req, req.page().cloned(), options)
+    }
+
+    /// Like `retrieve`, but for `page` instead of the page of the request.
+    pub fn retrieve_page(
+        req: &RetrieveTransactionReportRequest,
+        page: Option<u32>,
+        options: &Options,
+    ) -> Result<TransactionReport> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/transactions{}",
+            options.base_url(),
+            V2_REPORTING_PAYMENT,
+            req.get_query_params_for_page(page)
+        );
+        debug!("uri:{}", uri);
+        let res = HttpClient::create().get(
+            uri.as_str(),
+            Option::from(IyzipayResource::get_http_headers_v2(
+                uri.to_owned(),
+                String::new(),
+                options,
+            )),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    /// Iterates over the pages of the report, starting at the page of the request or the first
+    /// one. Iteration stops after the last page or the first error or failure response, which is
+    /// returned as the last item.
+    pub fn pages<'a>(
+        req: &'a RetrieveTransactionReportRequest,
+        options: &'a Options,
+    ) -> TransactionReportPages<'a> {
+        TransactionReportPages {
+            request: req,
+            options,
+            next_page: Some(req.page().cloned().unwrap_or(1)),
+        }
+    }
+
+    pub fn set_transactions<T: Into<Vec<ReportingTransaction>>>(&mut self, transactions: T) {
+        self.transactions = Some(transactions.into());
+    }
+
+    pub fn set_current_page<T: Into<u32>>(&mut self, current_page: T) {
+        self.current_page = Some(current_page.into());
+    }
+
+    pub fn set_total_page_count<T: Into<u32>>(&mut self, total_page_count: T) {
+        self.total_page_count = Some(total_page_count.into());
+    }
+
+    pub fn transactions(&self) -> Option<&Vec<ReportingTransaction>> {
+        self.transactions.as_ref()
+    }
+    pub fn current_page(&self) -> Option<&u32> {
+        self.current_page.as_ref()
+    }
+    pub fn total_page_count(&self) -> Option<&u32> {
+        self.total_page_count.as_ref()
+    }
+}
+
+impl std::ops::Deref for TransactionReport {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Iterator returned by `TransactionReport::pages`.
+pub struct TransactionReportPages<'a> {
+    request: &'a RetrieveTransactionReportRequest,
+
+    options: &'a Options,
+
+    next_page: Option<u32>,
+}
+
+impl<'a> Iterator for TransactionReportPages<'a> {
+    type Item = Result<TransactionReport>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let page = self.next_page.take()?;
+        let report = TransactionReport::retrieve_page(self.request, Some(page), self.options);
+        if let Ok(report) = &report {
+            let success = report.status().map(String::as_str) == Some(Status::Success.value());
+            let has_transactions = report
+                .transactions()
+                .is_some_and(|transactions| !transactions.is_empty());
+            let total_page_count = report.total_page_count().cloned().unwrap_or(0);
+            if success && has_transactions && page < total_page_count {
+                self.next_page = Some(page + 1);
+            }
+        }
+        Some(report)
+    }
+}
+
+/// A payment with its cancels and per basket item breakdown, see `RetrievePaymentDetailsRequest`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct PaymentDetailsReport {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    payments: Option<Vec<ReportingPayment>>,
+}
+
+impl PaymentDetailsReport {
+    pub fn retrieve(
+        req: &RetrievePaymentDetailsRequest,
+        options: &Options,
+    ) -> Result<PaymentDetailsReport> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/details{}",
+            options.base_url(),
+            V2_REPORTING_PAYMENT,
+            req.get_query_params()
+        );
+        debug!("uri:{}", uri);
+        let res = HttpClient::create().get(
+            uri.as_str(),
+            Option::from(IyzipayResource::get_http_headers_v2(
+                uri.to_owned(),
+                String::new(),
+                options,
+            )),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_payments<T: Into<Vec<ReportingPayment>>>(&mut self, payments: T) {
+        self.payments = Some(payments.into());
+    }
+
+    pub fn payments(&self) -> Option<&Vec<ReportingPayment>> {
+        self.payments.as_ref()
+    }
+}
+
+impl std::ops::Deref for PaymentDetailsReport {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReportingTransaction {
+    transaction_type: Option<ReportingTransactionType>,
+
+    #[serde(
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    transaction_date: Option<NaiveDateTime>,
+
+    #[serde(deserialize_with = "deserialize_string")]
+    transaction_id: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_id: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_tx_id: Option<String>,
+
+    conversation_id: Option<String>,
+
+    payment_phase: Option<Phase>,
+
+    price: Option<BigDecimal>,
+
+    paid_price: Option<BigDecimal>,
+
+    transaction_currency: Option<Currency>,
+
+    installment: Option<u8>,
+
+    merchant_commission_rate: Option<BigDecimal>,
+
+    merchant_commission_rate_amount: Option<BigDecimal>,
+
+    iyzi_commission_rate_amount: Option<BigDecimal>,
+
+    iyzi_commission_fee: Option<BigDecimal>,
+
+    merchant_payout_amount: Option<BigDecimal>,
+
+    sub_merchant_key: Option<String>,
+
+    sub_merchant_payout_amount: Option<BigDecimal>,
+
+    pos_order_id: Option<String>,
+}
+
+impl ReportingTransaction {
+    pub fn set_transaction_type<T: Into<ReportingTransactionType>>(&mut self, transaction_type: T) {
+        self.transaction_type = Some(transaction_type.into());
+    }
+
+    pub fn set_transaction_date<T: IntoDateTime>(&mut self, transaction_date: T) {
+        self.transaction_date = transaction_date.into_date_time();
+    }
+
+    pub fn set_transaction_id<T: Into<String>>(&mut self, transaction_id: T) {
+        self.transaction_id = Some(transaction_id.into());
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_payment_tx_id<T: Into<String>>(&mut self, payment_tx_id: T) {
+        self.payment_tx_id = Some(payment_tx_id.into());
+    }
+
+    pub fn set_conversation_id<T: Into<String>>(&mut self, conversation_id: T) {
+        self.conversation_id = Some(conversation_id.into());
+    }
+
+    pub fn set_payment_phase<T: Into<Phase>>(&mut self, payment_phase: T) {
+        self.payment_phase = Some(payment_phase.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_transaction_currency<T: Into<Currency>>(&mut self, transaction_currency: T) {
+        self.transaction_currency = Some(transaction_currency.into());
+    }
+
+    pub fn set_installment<T: Into<u8>>(&mut self, installment: T) {
+        self.installment = Some(installment.into());
+    }
+
+    pub fn set_merchant_commission_rate<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate: T,
+    ) {
+        self.merchant_commission_rate = Some(merchant_commission_rate.into());
+    }
+
+    pub fn set_merchant_commission_rate_amount<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate_amount: T,
+    ) {
+        self.merchant_commission_rate_amount = Some(merchant_commission_rate_amount.into());
+    }
+
+    pub fn set_iyzi_commission_rate_amount<T: Into<BigDecimal>>(
+        &mut self,
+        iyzi_commission_rate_amount: T,
+    ) {
+        self.iyzi_commission_rate_amount = Some(iyzi_commission_rate_amount.into());
+    }
+
+    pub fn set_iyzi_commission_fee<T: Into<BigDecimal>>(&mut self, iyzi_commission_fee: T) {
+        self.iyzi_commission_fee = Some(iyzi_commission_fee.into());
+    }
+
+    pub fn set_merchant_payout_amount<T: Into<BigDecimal>>(&mut self, merchant_payout_amount: T) {
+        self.merchant_payout_amount = Some(merchant_payout_amount.into());
+    }
+
+    pub fn set_sub_merchant_key<T: Into<String>>(&mut self, sub_merchant_key: T) {
+        self.sub_merchant_key = Some(sub_merchant_key.into());
+    }
+
+    pub fn set_sub_merchant_payout_amount<T: Into<BigDecimal>>(
+        &mut self,
+        sub_merchant_payout_amount: T,
+    ) {
+        self.sub_merchant_payout_amount = Some(sub_merchant_payout_amount.into());
+    }
+
+    pub fn set_pos_order_id<T: Into<String>>(&mut self, pos_order_id: T) {
+        self.pos_order_id = Some(pos_order_id.into());
+    }
+
+    pub fn transaction_type(&self) -> Option<&ReportingTransactionType> {
+        self.transaction_type.as_ref()
+    }
+    pub fn transaction_date(&self) -> Option<&NaiveDateTime> {
+        self.transaction_date.as_ref()
+    }
+    pub fn transaction_id(&self) -> Option<&String> {
+        self.transaction_id.as_ref()
+    }
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn payment_tx_id(&self) -> Option<&String> {
+        self.payment_tx_id.as_ref()
+    }
+    pub fn conversation_id(&self) -> Option<&String> {
+        self.conversation_id.as_ref()
+    }
+    pub fn payment_phase(&self) -> Option<&Phase> {
+        self.payment_phase.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn transaction_currency(&self) -> Option<&Currency> {
+        self.transaction_currency.as_ref()
+    }
+    pub fn installment(&self) -> Option<&u8> {
+        self.installment.as_ref()
+    }
+    pub fn merchant_commission_rate(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate.as_ref()
+    }
+    pub fn merchant_commission_rate_amount(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate_amount.as_ref()
+    }
+    pub fn iyzi_commission_rate_amount(&self) -> Option<&BigDecimal> {
+        self.iyzi_commission_rate_amount.as_ref()
+    }
+    pub fn iyzi_commission_fee(&self) -> Option<&BigDecimal> {
+        self.iyzi_commission_fee.as_ref()
+    }
+    pub fn merchant_payout_amount(&self) -> Option<&BigDecimal> {
+        self.merchant_payout_amount.as_ref()
+    }
+    pub fn sub_merchant_key(&self) -> Option<&String> {
+        self.sub_merchant_key.as_ref()
+    }
+    pub fn sub_merchant_payout_amount(&self) -> Option<&BigDecimal> {
+        self.sub_merchant_payout_amount.as_ref()
+    }
+    pub fn pos_order_id(&self) -> Option<&String> {
+        self.pos_order_id.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReportingPayment {
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_id: Option<String>,
+
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_status: Option<String>,
+
+    payment_refund_status: Option<String>,
+
+    payment_conversation_id: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    paid_price: Option<BigDecimal>,
+
+    currency: Option<Currency>,
+
+    installment: Option<u8>,
+
+    merchant_commission_rate: Option<BigDecimal>,
+
+    merchant_commission_rate_amount: Option<BigDecimal>,
+
+    iyzi_commission_rate_amount: Option<BigDecimal>,
+
+    iyzi_commission_fee: Option<BigDecimal>,
+
+    fraud_status: Option<FraudStatus>,
+
+    phase: Option<Phase>,
+
+    card_type: Option<CardType>,
+
+    card_association: Option<CardAssociation>,
+
+    card_family: Option<CardFamily>,
+
+    bin_number: Option<String>,
+
+    last_four_digits: Option<String>,
+
+    basket_id: Option<String>,
+
+    connector_name: Option<String>,
+
+    auth_code: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    created_date: Option<NaiveDateTime>,
+
+    #[serde(
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    updated_date: Option<NaiveDateTime>,
+
+    cancels: Option<Vec<ReportingCancel>>,
+
+    item_transactions: Option<Vec<ReportingItemTransaction>>,
+}
+
+impl ReportingPayment {
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_payment_status<T: Into<String>>(&mut self, payment_status: T) {
+        self.payment_status = Some(payment_status.into());
+    }
+
+    pub fn set_payment_refund_status<T: Into<String>>(&mut self, payment_refund_status: T) {
+        self.payment_refund_status = Some(payment_refund_status.into());
+    }
+
+    pub fn set_payment_conversation_id<T: Into<String>>(&mut self, payment_conversation_id: T) {
+        self.payment_conversation_id = Some(payment_conversation_id.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_currency<T: Into<Currency>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_installment<T: Into<u8>>(&mut self, installment: T) {
+        self.installment = Some(installment.into());
+    }
+
+    pub fn set_merchant_commission_rate<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate: T,
+    ) {
+        self.merchant_commission_rate = Some(merchant_commission_rate.into());
+    }
+
+    pub fn set_merchant_commission_rate_amount<T: Into<BigDecimal>>(
+        &mut self,
+        merchant_commission_rate_amount: T,
+    ) {
+        self.merchant_commission_rate_amount = Some(merchant_commission_rate_amount.into());
+    }
+
+    pub fn set_iyzi_commission_rate_amount<T: Into<BigDecimal>>(
+        &mut self,
+        iyzi_commission_rate_amount: T,
+    ) {
+        self.iyzi_commission_rate_amount = Some(iyzi_commission_rate_amount.into());
+    }
+
+    pub fn set_iyzi_commission_fee<T: Into<BigDecimal>>(&mut self, iyzi_commission_fee: T) {
+        self.iyzi_commission_fee = Some(iyzi_commission_fee.into());
+    }
+
+    pub fn set_fraud_status<T: Into<FraudStatus>>(&mut self, fraud_status: T) {
+        self.fraud_status = Some(fraud_status.into());
+    }
+
+    pub fn set_phase<T: Into<Phase>>(&mut self, phase: T) {
+        self.phase = Some(phase.into());
+    }
+
+    pub fn set_card_type<T: Into<CardType>>(&mut self, card_type: T) {
+        self.card_type = Some(card_type.into());
+    }
+
+    pub fn set_card_association<T: Into<CardAssociation>>(&mut self, card_association: T) {
+        self.card_association = Some(card_association.into());
+    }
+
+    pub fn set_card_family<T: Into<CardFamily>>(&mut self, card_family: T) {
+        self.card_family = Some(card_family.into());
+    }
+
+    pub fn set_bin_number<T: Into<String>>(&mut self, bin_number: T) {
+        self.bin_number = Some(bin_number.into());
+    }
+
+    pub fn set_last_four_digits<T: Into<String>>(&mut self, last_four_digits: T) {
+        self.last_four_digits = Some(last_four_digits.into());
+    }
+
+    pub fn set_basket_id<T: Into<String>>(&mut self, basket_id: T) {
+        self.basket_id = Some(basket_id.into());
+    }
+
+    pub fn set_connector_name<T: Into<String>>(&mut self, connector_name: T) {
+        self.connector_name = Some(connector_name.into());
+    }
+
+    pub fn set_auth_code<T: Into<String>>(&mut self, auth_code: T) {
+        self.auth_code = Some(auth_code.into());
+    }
+
+    pub fn set_created_date<T: IntoDateTime>(&mut self, created_date: T) {
+        self.created_date = created_date.into_date_time();
+    }
+
+    pub fn set_updated_date<T: IntoDateTime>(&mut self, updated_date: T) {
+        self.updated_date = updated_date.into_date_time();
+    }
+
+    pub fn set_cancels<T: Into<Vec<ReportingCancel>>>(&mut self, cancels: T) {
+        self.cancels = Some(cancels.into());
+    }
+
+    pub fn set_item_transactions<T: Into<Vec<ReportingItemTransaction>>>(
+        &mut self,
+        item_transactions: T,
+    ) {
+        self.item_transactions = Some(item_transactions.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn payment_status(&self) -> Option<&String> {
+        self.payment_status.as_ref()
+    }
+    pub fn payment_refund_status(&self) -> Option<&String> {
+        self.payment_refund_status.as_ref()
+    }
+    pub fn payment_conversation_id(&self) -> Option<&String> {
+        self.payment_conversation_id.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&Currency> {
+        self.currency.as_ref()
+    }
+    pub fn installment(&self) -> Option<&u8> {
+        self.installment.as_ref()
+    }
+    pub fn merchant_commission_rate(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate.as_ref()
+    }
+    pub fn merchant_commission_rate_amount(&self) -> Option<&BigDecimal> {
+        self.merchant_commission_rate_amount.as_ref()
+    }
+    pub fn iyzi_commission_rate_amount(&self) -> Option<&BigDecimal> {
+        self.iyzi_commission_rate_amount.as_ref()
+    }
+    pub fn iyzi_commission_fee(&self) -> Option<&BigDecimal> {
+        self.iyzi_commission_fee.as_ref()
+    }
+    pub fn fraud_status(&self) -> Option<&FraudStatus> {
+        self.fraud_status.as_ref()
+    }
+    pub fn phase(&self) -> Option<&Phase> {
+        self.phase.as_ref()
+    }
+    pub fn card_type(&self) -> Option<&CardType> {
+        self.card_type.as_ref()
+    }
+    pub fn card_association(&self) -> Option<&CardAssociation> {
+        self.card_association.as_ref()
+    }
+    pub fn card_family(&self) -> Option<&CardFamily> {
+        self.card_family.as_ref()
+    }
+    pub fn bin_number(&self) -> Option<&String> {
+        self.bin_number.as_ref()
+    }
+    pub fn last_four_digits(&self) -> Option<&String> {
+        self.last_four_digits.as_ref()
+    }
+    pub fn basket_id(&self) -> Option<&String> {
+        self.basket_id.as_ref()
+    }
+    pub fn connector_name(&self) -> Option<&String> {
+        self.connector_name.as_ref()
+    }
+    pub fn auth_code(&self) -> Option<&String> {
+        self.auth_code.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&NaiveDateTime> {
+        self.created_date.as_ref()
+    }
+    pub fn updated_date(&self) -> Option<&NaiveDateTime> {
+        self.updated_date.as_ref()
+    }
+    pub fn cancels(&self) -> Option<&Vec<ReportingCancel>> {
+        self.cancels.as_ref()
+    }
+    pub fn item_transactions(&self) -> Option<&Vec<ReportingItemTransaction>> {
+        self.item_transactions.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReportingCancel {
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_id: Option<String>,
+
+    amount: Option<BigDecimal>,
+
+    currency: Option<Currency>,
+
+    reason: Option<String>,
+
+    description: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    created_date: Option<NaiveDateTime>,
+}
+
+impl ReportingCancel {
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_amount<T: Into<BigDecimal>>(&mut self, amount: T) {
+        self.amount = Some(amount.into());
+    }
+
+    pub fn set_currency<T: Into<Currency>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_reason<T: Into<String>>(&mut self, reason: T) {
+        self.reason = Some(reason.into());
+    }
+
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = Some(description.into());
+    }
+
+    pub fn set_created_date<T: IntoDateTime>(&mut self, created_date: T) {
+        self.created_date = created_date.into_date_time();
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn amount(&self) -> Option<&BigDecimal> {
+        self.amount.as_ref()
+    }
+    pub fn currency(&self) -> Option<&Currency> {
+        self.currency.as_ref()
+    }
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&NaiveDateTime> {
+        self.created_date.as_ref()
+    }
+}
+
+/// A basket item of a reported payment, with what the merchant and the sub merchant are paid.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReportingItemTransaction {
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_transaction_id: Option<String>,
+
+    transaction_status: Option<TransactionStatus>,
+
+    item_id: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    paid_price: Option<BigDecimal>,
+
+    merchant_payout_amount: Option<BigDecimal>,
+
+    sub_merchant_key: Option<String>,
+
+    sub_merchant_price: Option<BigDecimal>,
+
+    sub_merchant_payout_amount: Option<BigDecimal>,
+
+    refunds: Option<Vec<ReportingRefund>>,
+}
+
+impl ReportingItemTransaction {
+    pub fn set_payment_transaction_id<T: Into<String>>(&mut self, payment_transaction_id: T) {
+        self.payment_transaction_id = Some(payment_transaction_id.into());
+    }
+
+    pub fn set_transaction_status<T: Into<TransactionStatus>>(&mut self, transaction_status: T) {
+        self.transaction_status = Some(transaction_status.into());
+    }
+
+    pub fn set_item_id<T: Into<String>>(&mut self, item_id: T) {
+        self.item_id = Some(item_id.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_merchant_payout_amount<T: Into<BigDecimal>>(&mut self, merchant_payout_amount: T) {
+        self.merchant_payout_amount = Some(merchant_payout_amount.into());
+    }
+
+    pub fn set_sub_merchant_key<T: Into<String>>(&mut self, sub_merchant_key: T) {
+        self.sub_merchant_key = Some(sub_merchant_key.into());
+    }
+
+    pub fn set_sub_merchant_price<T: Into<BigDecimal>>(&mut self, sub_merchant_price: T) {
+        self.sub_merchant_price = Some(sub_merchant_price.into());
+    }
+
+    pub fn set_sub_merchant_payout_amount<T: Into<BigDecimal>>(
+        &mut self,
+        sub_merchant_payout_amount: T,
+    ) {
+        self.sub_merchant_payout_amount = Some(sub_merchant_payout_amount.into());
+    }
+
+    pub fn set_refunds<T: Into<Vec<ReportingRefund>>>(&mut self, refunds: T) {
+        self.refunds = Some(refunds.into());
+    }
+
+    pub fn payment_transaction_id(&self) -> Option<&String> {
+        self.payment_transaction_id.as_ref()
+    }
+    pub fn transaction_status(&self) -> Option<&TransactionStatus> {
+        self.transaction_status.as_ref()
+    }
+    pub fn item_id(&self) -> Option<&String> {
+        self.item_id.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn merchant_payout_amount(&self) -> Option<&BigDecimal> {
+        self.merchant_payout_amount.as_ref()
+    }
+    pub fn sub_merchant_key(&self) -> Option<&String> {
+        self.sub_merchant_key.as_ref()
+    }
+    pub fn sub_merchant_price(&self) -> Option<&BigDecimal> {
+        self.sub_merchant_price.as_ref()
+    }
+    pub fn sub_merchant_payout_amount(&self) -> Option<&BigDecimal> {
+        self.sub_merchant_payout_amount.as_ref()
+    }
+    pub fn refunds(&self) -> Option<&Vec<ReportingRefund>> {
+        self.refunds.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct ReportingRefund {
+    #[serde(deserialize_with = "deserialize_string")]
+    payment_transaction_id: Option<String>,
+
+    refund_price: Option<BigDecimal>,
+
+    currency: Option<Currency>,
+
+    conversation_id: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_date_time",
+        deserialize_with = "deserialize_date_time"
+    )]
+    created_date: Option<NaiveDateTime>,
+}
+
+impl ReportingRefund {
+    pub fn set_payment_transaction_id<T: Into<String>>(&mut self, payment_transaction_id: T) {
+        self.payment_transaction_id = Some(payment_transaction_id.into());
+    }
+
+    pub fn set_refund_price<T: Into<BigDecimal>>(&mut self, refund_price: T) {
+        self.refund_price = Some(refund_price.into());
+    }
+
+    pub fn set_currency<T: Into<Currency>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_conversation_id<T: Into<String>>(&mut self, conversation_id: T) {
+        self.conversation_id = Some(conversation_id.into());
+    }
+
+    pub fn set_created_date<T: IntoDateTime>(&mut self, created_date: T) {
+        self.created_date = created_date.into_date_time();
+    }
+
+    pub fn payment_transaction_id(&self) -> Option<&String> {
+        self.payment_transaction_id.as_ref()
+    }
+    pub fn refund_price(&self) -> Option<&BigDecimal> {
+        self.refund_price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&Currency> {
+        self.currency.as_ref()
+    }
+    pub fn conversation_id(&self) -> Option<&String> {
+        self.conversation_id.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&NaiveDateTime> {
+        self.created_date.as_ref()
+    }
+}
@@ -8,12 +8,15 @@ use crate::model::PaymentItem;
 use crate::options::Options;
 use crate::requests::CreateApmInitializeRequest;
 use crate::requests::CreateApprovalRequest;
+use crate::requests::CreateCrossBookingRequest;
+use crate::requests::CreateSettlementToBalanceRequest;
 use crate::requests::CreateSubMerchantRequest;
-use crate::requests::PKISerialize;
 use crate::requests::RetrieveApmRequest;
 use crate::requests::RetrieveSubMerchantRequest;
 use crate::requests::RetrieveTransactionsRequest;
 use crate::requests::UpdateSubMerchantRequest;
+use crate::requests::Validate;
+use crate::requests::Validator;
 use crate::resource::IyzipayResource;
 use crate::types::Result;
 
@@ -55,6 +58,8 @@ pub struct SubMerchant {
     sub_merchant_type: Option<String>,
 
     sub_merchant_key: Option<String>,
+
+    settlement_to_balance: Option<bool>,
 }
 
 impl std::ops::Deref for SubMerchant {
@@ -66,24 +71,28 @@ impl std::ops::Deref for SubMerchant {
 
 impl SubMerchant {
     pub fn create(req: &CreateSubMerchantRequest, options: &Options) -> Result<SubMerchant> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/onboarding/submerchant");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/onboarding/submerchant").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
     }
 
     pub fn update(req: &UpdateSubMerchantRequest, options: &Options) -> Result<SubMerchant> {
+        req.validate_for(options)?;
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/onboarding/submerchant");
         let res = HttpClient::create().put(
-            format!("{}{}", options.base_url(), "/onboarding/submerchant").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -92,15 +101,34 @@ impl SubMerchant {
     pub fn retrieve(req: &RetrieveSubMerchantRequest, options: &Options) -> Result<SubMerchant> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/onboarding/submerchant/detail");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/onboarding/submerchant/detail").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
     }
 
+    /// Starts switching the payouts of this sub merchant to iyzico balance, see
+    /// `CreateSettlementToBalanceRequest`. It fails without a request when this sub merchant has
+    /// no key, as the request would switch the settlement of the merchant itself. Build the
+    /// request to also send a locale or conversation id.
+    pub fn init_settlement_to_balance<T: Into<String>>(
+        &self,
+        callback_url: T,
+        options: &Options,
+    ) -> Result<SettlementToBalance> {
+        let mut validator = Validator::new();
+        let sub_merchant_key = validator.required("subMerchantKey", self.sub_merchant_key());
+        validator.finish()?;
+        let mut req = CreateSettlementToBalanceRequest::new();
+        req.set_sub_merchant_key(sub_merchant_key.cloned().unwrap_or_default());
+        req.set_callback_url(callback_url);
+        SettlementToBalance::create(&req, options)
+    }
+
     pub fn set_name<T: Into<String>>(&mut self, name: T) {
         self.name = Some(name.into());
     }
@@ -165,6 +193,10 @@ impl SubMerchant {
         self.sub_merchant_key = Some(sub_merchant_key.into());
     }
 
+    pub fn set_settlement_to_balance<T: Into<bool>>(&mut self, settlement_to_balance: T) {
+        self.settlement_to_balance = Some(settlement_to_balance.into());
+    }
+
     pub fn name(&self) -> Option<&String> {
         self.name.as_ref()
     }
@@ -213,6 +245,10 @@ impl SubMerchant {
     pub fn sub_merchant_key(&self) -> Option<&String> {
         self.sub_merchant_key.as_ref()
     }
+    /// Whether the payouts are kept as iyzico balance instead of sent to the iban.
+    pub fn settlement_to_balance(&self) -> Option<&bool> {
+        self.settlement_to_balance.as_ref()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq)]
@@ -252,10 +288,11 @@ impl Approval {
     pub fn create(req: &CreateApprovalRequest, options: &Options) -> Result<Approval> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/iyzipos/item/approve");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/iyzipos/item/approve").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -290,15 +327,15 @@ impl Disapproval {
     pub fn create(req: &CreateApprovalRequest, options: &Options) -> Result<Disapproval> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/iyzipos/item/disapprove"
+        );
         let res = HttpClient::create().post(
-            format!(
-                "{}{}",
-                options.base_url(),
-                "/payment/iyzipos/item/disapprove"
-            )
-            .as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -320,6 +357,134 @@ impl std::ops::Deref for Disapproval {
     }
 }
 
+/// Sends an amount from the merchant to one of its sub-merchants.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CrossBookingToSubMerchant {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl CrossBookingToSubMerchant {
+    pub fn create(
+        req: &CreateCrossBookingRequest,
+        options: &Options,
+    ) -> Result<CrossBookingToSubMerchant> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/crossbooking/send");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for CrossBookingToSubMerchant {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Takes an amount back from one of the sub-merchants to the merchant.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct CrossBookingFromSubMerchant {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl CrossBookingFromSubMerchant {
+    pub fn create(
+        req: &CreateCrossBookingRequest,
+        options: &Options,
+    ) -> Result<CrossBookingFromSubMerchant> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/crossbooking/receive");
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+}
+
+impl std::ops::Deref for CrossBookingFromSubMerchant {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// The page the settlement to balance of a sub merchant is confirmed on.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SettlementToBalance {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    token: Option<String>,
+
+    url: Option<String>,
+}
+
+impl SettlementToBalance {
+    pub fn create(
+        req: &CreateSettlementToBalanceRequest,
+        options: &Options,
+    ) -> Result<SettlementToBalance> {
+        req.validate_for(options)?;
+        let request = serde_json::to_string(req)?;
+        debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/payment/settlement-to-balance/init"
+        );
+        let res = HttpClient::create().post(
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
+        )?;
+        let response = res.json()?;
+        Ok(response)
+    }
+
+    pub fn set_token<T: Into<String>>(&mut self, token: T) {
+        self.token = Some(token.into());
+    }
+
+    pub fn set_url<T: Into<String>>(&mut self, url: T) {
+        self.url = Some(url.into());
+    }
+
+    pub fn token(&self) -> Option<&String> {
+        self.token.as_ref()
+    }
+    pub fn url(&self) -> Option<&String> {
+        self.url.as_ref()
+    }
+}
+
+impl std::ops::Deref for SettlementToBalance {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[serde(default)]
@@ -337,15 +502,15 @@ impl PayoutCompletedTransactionList {
     ) -> Result<PayoutCompletedTransactionList> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!(
+            "{}{}",
+            options.base_url(),
+            "/reporting/settlement/payoutcompleted"
+        );
         let res = HttpClient::create().post(
-            format!(
-                "{}{}",
-                options.base_url(),
-                "/reporting/settlement/payoutcompleted"
-            )
-            .as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -440,10 +605,11 @@ impl BouncedBankTransferList {
     ) -> Result<BouncedBankTransferList> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/reporting/settlement/bounced");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/reporting/settlement/bounced").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -544,10 +710,11 @@ impl Apm {
     pub fn create(req: &CreateApmInitializeRequest, options: &Options) -> Result<Apm> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/apm/initialize");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/apm/initialize").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
@@ -556,10 +723,11 @@ impl Apm {
     pub fn retrieve(req: &RetrieveApmRequest, options: &Options) -> Result<Apm> {
         let request = serde_json::to_string(req)?;
         debug!("RequestBody:{}", request);
+        let uri = format!("{}{}", options.base_url(), "/payment/apm/retrieve");
         let res = HttpClient::create().post(
-            format!("{}{}", options.base_url(), "/payment/apm/retrieve").as_str(),
-            request,
-            IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), &options),
+            uri.as_str(),
+            request.to_owned(),
+            IyzipayResource::get_http_headers_for(uri.as_str(), request.as_str(), req, options),
         )?;
         let response = res.json()?;
         Ok(response)
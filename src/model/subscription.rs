@@ -0,0 +1,1218 @@
+use bigdecimal::BigDecimal;
+use chrono::DateTime;
+use chrono::Utc;
+use log::debug;
+use serde::de::DeserializeOwned;
+
+use crate::client::HttpClient;
+use crate::model::date::deserialize_timestamp;
+use crate::model::date::serialize_timestamp;
+use crate::model::Address;
+use crate::options::Options;
+use crate::requests::CreatePricingPlanRequest;
+use crate::requests::CreateSubscriptionCardUpdateRequest;
+use crate::requests::CreateSubscriptionInitializeRequest;
+use crate::requests::CreateSubscriptionProductRequest;
+use crate::requests::PagingRequest;
+use crate::requests::Request;
+use crate::requests::RequestQueryParams;
+use crate::requests::RetrySubscriptionPaymentRequest;
+use crate::requests::SearchSubscriptionsRequest;
+use crate::requests::SubscriptionCustomerRequest;
+use crate::requests::UpdatePricingPlanRequest;
+use crate::requests::UpdateSubscriptionProductRequest;
+use crate::requests::UpgradeSubscriptionRequest;
+use crate::requests::Validate;
+use crate::resource::IyzipayResource;
+use crate::types::Result;
+
+const V2_SUBSCRIPTION: &str = "/v2/subscription";
+
+lenient_enum! {
+    pub enum SubscriptionStatus {
+        Active => "ACTIVE",
+        Pending => "PENDING",
+        Unpaid => "UNPAID",
+        Upgraded => "UPGRADED",
+        Canceled => "CANCELED",
+        Expired => "EXPIRED",
+    }
+}
+
+lenient_enum! {
+    pub enum PaymentInterval {
+        Daily => "DAILY",
+        Weekly => "WEEKLY",
+        Monthly => "MONTHLY",
+        Yearly => "YEARLY",
+    }
+}
+
+lenient_enum! {
+    pub enum PlanPaymentType {
+        Recurring => "RECURRING",
+    }
+}
+
+lenient_enum! {
+    /// When an upgraded subscription switches to its new pricing plan.
+    pub enum SubscriptionUpgradePeriod {
+        Now => "NOW",
+        NextPeriod => "NEXT_PERIOD",
+    }
+}
+
+lenient_enum! {
+    pub enum SubscriptionOrderStatus {
+        Success => "SUCCESS",
+        Failed => "FAILED",
+        Waiting => "WAITING",
+    }
+}
+
+lenient_enum! {
+    pub enum SubscriptionItemStatus {
+        Active => "ACTIVE",
+        Passive => "PASSIVE",
+    }
+}
+
+fn post<T: DeserializeOwned>(uri: String, request: String, options: &Options) -> Result<T> {
+    debug!("RequestBody:{}", request);
+    debug!("uri:{}", uri);
+    let res = HttpClient::create().post(
+        uri.as_str(),
+        request.to_owned(),
+        IyzipayResource::get_http_headers_v2(uri.to_owned(), request, options),
+    )?;
+    let response = res.json()?;
+    Ok(response)
+}
+
+fn put<T: DeserializeOwned>(uri: String, request: String, options: &Options) -> Result<T> {
+    debug!("RequestBody:{}", request);
+    debug!("uri:{}", uri);
+    let res = HttpClient::create().put(
+        uri.as_str(),
+        request.to_owned(),
+        IyzipayResource::get_http_headers_v2(uri.to_owned(), request, options),
+    )?;
+    let response = res.json()?;
+    Ok(response)
+}
+
+fn get<T: DeserializeOwned>(uri: String, options: &Options) -> Result<T> {
+    debug!("uri:{}", uri);
+    let res = HttpClient::create().get(
+        uri.as_str(),
+        Option::from(IyzipayResource::get_http_headers_v2(
+            uri.to_owned(),
+            String::new(),
+            options,
+        )),
+    )?;
+    let response = res.json()?;
+    Ok(response)
+}
+
+fn delete<T: DeserializeOwned>(uri: String, options: &Options) -> Result<T> {
+    debug!("uri:{}", uri);
+    let res = HttpClient::create().delete(
+        uri.as_str(),
+        String::new(),
+        IyzipayResource::get_http_headers_v2(uri.to_owned(), String::new(), options),
+    )?;
+    let response = res.json()?;
+    Ok(response)
+}
+
+/// Products of the subscription API, which group the pricing plans customers subscribe to.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionProduct {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl SubscriptionProduct {
+    pub fn create(
+        req: &CreateSubscriptionProductRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionProductItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/products{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn update<S: Into<String>>(
+        reference_code: S,
+        req: &UpdateSubscriptionProductRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionProductItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/products/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        put(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn retrieve<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionProductItem>> {
+        let uri = format!(
+            "{}{}/products/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn retrieve_all(
+        req: &PagingRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionPaging<SubscriptionProductItem>>> {
+        let uri = format!(
+            "{}{}/products{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn delete<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<IyzipayResource> {
+        let uri = format!(
+            "{}{}/products/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        delete(uri, options)
+    }
+}
+
+impl std::ops::Deref for SubscriptionProduct {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Pricing plans of a subscription product: the price and how often it is charged.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct PricingPlan {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl PricingPlan {
+    pub fn create<S: Into<String>>(
+        product_reference_code: S,
+        req: &CreatePricingPlanRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<PricingPlanItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/products/{}/pricing-plans{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            product_reference_code.into(),
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn update<S: Into<String>>(
+        reference_code: S,
+        req: &UpdatePricingPlanRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<PricingPlanItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/pricing-plans/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        put(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn retrieve<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<SubscriptionResource<PricingPlanItem>> {
+        let uri = format!(
+            "{}{}/pricing-plans/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn retrieve_all<S: Into<String>>(
+        product_reference_code: S,
+        req: &PagingRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionPaging<PricingPlanItem>>> {
+        let uri = format!(
+            "{}{}/products/{}/pricing-plans{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            product_reference_code.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn delete<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<IyzipayResource> {
+        let uri = format!(
+            "{}{}/pricing-plans/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        delete(uri, options)
+    }
+}
+
+impl std::ops::Deref for PricingPlan {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionCustomer {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl SubscriptionCustomer {
+    pub fn create(
+        req: &SubscriptionCustomerRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionCustomerItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/customers{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn update<S: Into<String>>(
+        reference_code: S,
+        req: &SubscriptionCustomerRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionCustomerItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/customers/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        put(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn retrieve<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionCustomerItem>> {
+        let uri = format!(
+            "{}{}/customers/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn retrieve_all(
+        req: &PagingRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionPaging<SubscriptionCustomerItem>>> {
+        let uri = format!(
+            "{}{}/customers{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+}
+
+impl std::ops::Deref for SubscriptionCustomer {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Subscriptions of customers to pricing plans. Their payments are charged with
+/// `PaymentGroup::Subscription`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct Subscription {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl Subscription {
+    /// Starts a subscription for a new customer, charging the payment card of the request.
+    pub fn initialize(
+        req: &CreateSubscriptionInitializeRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/initialize{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    /// Activates a subscription initialized with `SubscriptionStatus::Pending`.
+    pub fn activate<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<IyzipayResource> {
+        let uri = format!(
+            "{}{}/subscriptions/{}/activate{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn cancel<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<IyzipayResource> {
+        let uri = format!(
+            "{}{}/subscriptions/{}/cancel{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    /// Moves a subscription to another pricing plan of the same product.
+    pub fn upgrade<S: Into<String>>(
+        reference_code: S,
+        req: &UpgradeSubscriptionRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionItem>> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/subscriptions/{}/upgrade{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    /// Retries the payment of a failed order.
+    pub fn retry(
+        req: &RetrySubscriptionPaymentRequest,
+        options: &Options,
+    ) -> Result<IyzipayResource> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/operation/retry{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    pub fn retrieve<S: Into<String>>(
+        reference_code: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionItem>> {
+        let uri = format!(
+            "{}{}/subscriptions/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            reference_code.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+
+    pub fn search(
+        req: &SearchSubscriptionsRequest,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionPaging<SubscriptionItem>>> {
+        let uri = format!(
+            "{}{}/subscriptions{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+}
+
+impl std::ops::Deref for Subscription {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// The iyzico hosted form a new customer subscribes through.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionCheckoutForm {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl SubscriptionCheckoutForm {
+    pub fn initialize(
+        req: &CreateSubscriptionInitializeRequest,
+        options: &Options,
+    ) -> Result<SubscriptionCheckoutFormInitialize> {
+        req.validate_for(options)?;
+        let uri = format!(
+            "{}{}/checkoutform/initialize{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+
+    /// Retrieves the subscription started by the form of `token`, once its callback is called.
+    pub fn retrieve<S: Into<String>>(
+        token: S,
+        req: &Request,
+        options: &Options,
+    ) -> Result<SubscriptionResource<SubscriptionItem>> {
+        let uri = format!(
+            "{}{}/checkoutform/{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            token.into(),
+            req.get_query_params()
+        );
+        get(uri, options)
+    }
+}
+
+impl std::ops::Deref for SubscriptionCheckoutForm {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// The iyzico hosted form a customer updates the card of their subscriptions through.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionCardUpdate {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+}
+
+impl SubscriptionCardUpdate {
+    pub fn initialize(
+        req: &CreateSubscriptionCardUpdateRequest,
+        options: &Options,
+    ) -> Result<SubscriptionCheckoutFormInitialize> {
+        req.validate_for(options)?;
+        let path = if req.subscription_reference_code().is_some() {
+            "/card-update/checkoutform/initialize/with-subscription"
+        } else {
+            "/card-update/checkoutform/initialize"
+        };
+        let uri = format!(
+            "{}{}{}{}",
+            options.base_url(),
+            V2_SUBSCRIPTION,
+            path,
+            req.get_query_params()
+        );
+        post(uri, serde_json::to_string(req)?, options)
+    }
+}
+
+impl std::ops::Deref for SubscriptionCardUpdate {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+/// Response of the subscription API, with the returned object in `data`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionResource<T> {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    data: Option<T>,
+}
+
+impl<T> SubscriptionResource<T> {
+    pub fn set_data<D: Into<T>>(&mut self, data: D) {
+        self.data = Some(data.into());
+    }
+
+    pub fn data(&self) -> Option<&T> {
+        self.data.as_ref()
+    }
+}
+
+impl<T> std::ops::Deref for SubscriptionResource<T> {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionPaging<T> {
+    items: Option<Vec<T>>,
+
+    total_count: Option<i64>,
+
+    current_page: Option<i32>,
+
+    page_count: Option<i32>,
+}
+
+impl<T> SubscriptionPaging<T> {
+    pub fn set_items<I: Into<Vec<T>>>(&mut self, items: I) {
+        self.items = Some(items.into());
+    }
+
+    pub fn set_total_count<I: Into<i64>>(&mut self, total_count: I) {
+        self.total_count = Some(total_count.into());
+    }
+
+    pub fn set_current_page<I: Into<i32>>(&mut self, current_page: I) {
+        self.current_page = Some(current_page.into());
+    }
+
+    pub fn set_page_count<I: Into<i32>>(&mut self, page_count: I) {
+        self.page_count = Some(page_count.into());
+    }
+
+    pub fn items(&self) -> Option<&Vec<T>> {
+        self.items.as_ref()
+    }
+    pub fn total_count(&self) -> Option<&i64> {
+        self.total_count.as_ref()
+    }
+    pub fn current_page(&self) -> Option<&i32> {
+        self.current_page.as_ref()
+    }
+    pub fn page_count(&self) -> Option<&i32> {
+        self.page_count.as_ref()
+    }
+}
+
+/// Checkout form started by `SubscriptionCheckoutForm::initialize` or
+/// `SubscriptionCardUpdate::initialize`. iyzico returns it next to `status` instead of in `data`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionCheckoutFormInitialize {
+    #[serde(flatten)]
+    resource: IyzipayResource,
+
+    token: Option<String>,
+
+    checkout_form_content: Option<String>,
+
+    token_expire_time: Option<i64>,
+}
+
+impl SubscriptionCheckoutFormInitialize {
+    pub fn set_token<T: Into<String>>(&mut self, token: T) {
+        self.token = Some(token.into());
+    }
+
+    pub fn set_checkout_form_content<T: Into<String>>(&mut self, checkout_form_content: T) {
+        self.checkout_form_content = Some(checkout_form_content.into());
+    }
+
+    pub fn set_token_expire_time<T: Into<i64>>(&mut self, token_expire_time: T) {
+        self.token_expire_time = Some(token_expire_time.into());
+    }
+
+    pub fn token(&self) -> Option<&String> {
+        self.token.as_ref()
+    }
+    pub fn checkout_form_content(&self) -> Option<&String> {
+        self.checkout_form_content.as_ref()
+    }
+    pub fn token_expire_time(&self) -> Option<&i64> {
+        self.token_expire_time.as_ref()
+    }
+}
+
+impl std::ops::Deref for SubscriptionCheckoutFormInitialize {
+    type Target = IyzipayResource;
+    fn deref(&self) -> &Self::Target {
+        &self.resource
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionProductItem {
+    reference_code: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    created_date: Option<DateTime<Utc>>,
+
+    name: Option<String>,
+
+    description: Option<String>,
+
+    status: Option<SubscriptionItemStatus>,
+
+    pricing_plans: Option<Vec<PricingPlanItem>>,
+}
+
+impl SubscriptionProductItem {
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn set_created_date<T: Into<DateTime<Utc>>>(&mut self, created_date: T) {
+        self.created_date = Some(created_date.into());
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = Some(description.into());
+    }
+
+    pub fn set_status<T: Into<SubscriptionItemStatus>>(&mut self, status: T) {
+        self.status = Some(status.into());
+    }
+
+    pub fn set_pricing_plans<T: Into<Vec<PricingPlanItem>>>(&mut self, pricing_plans: T) {
+        self.pricing_plans = Some(pricing_plans.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&DateTime<Utc>> {
+        self.created_date.as_ref()
+    }
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+    pub fn status(&self) -> Option<&SubscriptionItemStatus> {
+        self.status.as_ref()
+    }
+    pub fn pricing_plans(&self) -> Option<&Vec<PricingPlanItem>> {
+        self.pricing_plans.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct PricingPlanItem {
+    reference_code: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    created_date: Option<DateTime<Utc>>,
+
+    name: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    #[serde(rename = "currencyCode")]
+    currency: Option<String>,
+
+    payment_interval: Option<PaymentInterval>,
+
+    payment_interval_count: Option<u32>,
+
+    trial_period_days: Option<u32>,
+
+    recurrence_count: Option<u32>,
+
+    product_reference_code: Option<String>,
+
+    plan_payment_type: Option<PlanPaymentType>,
+
+    status: Option<SubscriptionItemStatus>,
+}
+
+impl PricingPlanItem {
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn set_created_date<T: Into<DateTime<Utc>>>(&mut self, created_date: T) {
+        self.created_date = Some(created_date.into());
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_payment_interval<T: Into<PaymentInterval>>(&mut self, payment_interval: T) {
+        self.payment_interval = Some(payment_interval.into());
+    }
+
+    pub fn set_payment_interval_count<T: Into<u32>>(&mut self, payment_interval_count: T) {
+        self.payment_interval_count = Some(payment_interval_count.into());
+    }
+
+    pub fn set_trial_period_days<T: Into<u32>>(&mut self, trial_period_days: T) {
+        self.trial_period_days = Some(trial_period_days.into());
+    }
+
+    pub fn set_recurrence_count<T: Into<u32>>(&mut self, recurrence_count: T) {
+        self.recurrence_count = Some(recurrence_count.into());
+    }
+
+    pub fn set_product_reference_code<T: Into<String>>(&mut self, product_reference_code: T) {
+        self.product_reference_code = Some(product_reference_code.into());
+    }
+
+    pub fn set_plan_payment_type<T: Into<PlanPaymentType>>(&mut self, plan_payment_type: T) {
+        self.plan_payment_type = Some(plan_payment_type.into());
+    }
+
+    pub fn set_status<T: Into<SubscriptionItemStatus>>(&mut self, status: T) {
+        self.status = Some(status.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&DateTime<Utc>> {
+        self.created_date.as_ref()
+    }
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn payment_interval(&self) -> Option<&PaymentInterval> {
+        self.payment_interval.as_ref()
+    }
+    pub fn payment_interval_count(&self) -> Option<&u32> {
+        self.payment_interval_count.as_ref()
+    }
+    pub fn trial_period_days(&self) -> Option<&u32> {
+        self.trial_period_days.as_ref()
+    }
+    pub fn recurrence_count(&self) -> Option<&u32> {
+        self.recurrence_count.as_ref()
+    }
+    pub fn product_reference_code(&self) -> Option<&String> {
+        self.product_reference_code.as_ref()
+    }
+    pub fn plan_payment_type(&self) -> Option<&PlanPaymentType> {
+        self.plan_payment_type.as_ref()
+    }
+    pub fn status(&self) -> Option<&SubscriptionItemStatus> {
+        self.status.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionCustomerItem {
+    reference_code: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    created_date: Option<DateTime<Utc>>,
+
+    status: Option<SubscriptionItemStatus>,
+
+    name: Option<String>,
+
+    surname: Option<String>,
+
+    identity_number: Option<String>,
+
+    email: Option<String>,
+
+    gsm_number: Option<String>,
+
+    billing_address: Option<Address>,
+
+    shipping_address: Option<Address>,
+}
+
+impl SubscriptionCustomerItem {
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn set_created_date<T: Into<DateTime<Utc>>>(&mut self, created_date: T) {
+        self.created_date = Some(created_date.into());
+    }
+
+    pub fn set_status<T: Into<SubscriptionItemStatus>>(&mut self, status: T) {
+        self.status = Some(status.into());
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_surname<T: Into<String>>(&mut self, surname: T) {
+        self.surname = Some(surname.into());
+    }
+
+    pub fn set_identity_number<T: Into<String>>(&mut self, identity_number: T) {
+        self.identity_number = Some(identity_number.into());
+    }
+
+    pub fn set_email<T: Into<String>>(&mut self, email: T) {
+        self.email = Some(email.into());
+    }
+
+    pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
+        self.gsm_number = Some(gsm_number.into());
+    }
+
+    pub fn set_billing_address<T: Into<Address>>(&mut self, billing_address: T) {
+        self.billing_address = Some(billing_address.into());
+    }
+
+    pub fn set_shipping_address<T: Into<Address>>(&mut self, shipping_address: T) {
+        self.shipping_address = Some(shipping_address.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&DateTime<Utc>> {
+        self.created_date.as_ref()
+    }
+    pub fn status(&self) -> Option<&SubscriptionItemStatus> {
+        self.status.as_ref()
+    }
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn surname(&self) -> Option<&String> {
+        self.surname.as_ref()
+    }
+    pub fn identity_number(&self) -> Option<&String> {
+        self.identity_number.as_ref()
+    }
+    pub fn email(&self) -> Option<&String> {
+        self.email.as_ref()
+    }
+    pub fn gsm_number(&self) -> Option<&String> {
+        self.gsm_number.as_ref()
+    }
+    pub fn billing_address(&self) -> Option<&Address> {
+        self.billing_address.as_ref()
+    }
+    pub fn shipping_address(&self) -> Option<&Address> {
+        self.shipping_address.as_ref()
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionItem {
+    reference_code: Option<String>,
+
+    parent_reference_code: Option<String>,
+
+    pricing_plan_reference_code: Option<String>,
+
+    customer_reference_code: Option<String>,
+
+    subscription_status: Option<SubscriptionStatus>,
+
+    trial_days: Option<u32>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    trial_start_date: Option<DateTime<Utc>>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    trial_end_date: Option<DateTime<Utc>>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    created_date: Option<DateTime<Utc>>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    start_date: Option<DateTime<Utc>>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    end_date: Option<DateTime<Utc>>,
+
+    orders: Option<Vec<SubscriptionOrder>>,
+}
+
+impl SubscriptionItem {
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn set_parent_reference_code<T: Into<String>>(&mut self, parent_reference_code: T) {
+        self.parent_reference_code = Some(parent_reference_code.into());
+    }
+
+    pub fn set_pricing_plan_reference_code<T: Into<String>>(
+        &mut self,
+        pricing_plan_reference_code: T,
+    ) {
+        self.pricing_plan_reference_code = Some(pricing_plan_reference_code.into());
+    }
+
+    pub fn set_customer_reference_code<T: Into<String>>(&mut self, customer_reference_code: T) {
+        self.customer_reference_code = Some(customer_reference_code.into());
+    }
+
+    pub fn set_subscription_status<T: Into<SubscriptionStatus>>(&mut self, subscription_status: T) {
+        self.subscription_status = Some(subscription_status.into());
+    }
+
+    pub fn set_trial_days<T: Into<u32>>(&mut self, trial_days: T) {
+        self.trial_days = Some(trial_days.into());
+    }
+
+    pub fn set_trial_start_date<T: Into<DateTime<Utc>>>(&mut self, trial_start_date: T) {
+        self.trial_start_date = Some(trial_start_date.into());
+    }
+
+    pub fn set_trial_end_date<T: Into<DateTime<Utc>>>(&mut self, trial_end_date: T) {
+        self.trial_end_date = Some(trial_end_date.into());
+    }
+
+    pub fn set_created_date<T: Into<DateTime<Utc>>>(&mut self, created_date: T) {
+        self.created_date = Some(created_date.into());
+    }
+
+    pub fn set_start_date<T: Into<DateTime<Utc>>>(&mut self, start_date: T) {
+        self.start_date = Some(start_date.into());
+    }
+
+    pub fn set_end_date<T: Into<DateTime<Utc>>>(&mut self, end_date: T) {
+        self.end_date = Some(end_date.into());
+    }
+
+    pub fn set_orders<T: Into<Vec<SubscriptionOrder>>>(&mut self, orders: T) {
+        self.orders = Some(orders.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+    pub fn parent_reference_code(&self) -> Option<&String> {
+        self.parent_reference_code.as_ref()
+    }
+    pub fn pricing_plan_reference_code(&self) -> Option<&String> {
+        self.pricing_plan_reference_code.as_ref()
+    }
+    pub fn customer_reference_code(&self) -> Option<&String> {
+        self.customer_reference_code.as_ref()
+    }
+    pub fn subscription_status(&self) -> Option<&SubscriptionStatus> {
+        self.subscription_status.as_ref()
+    }
+    pub fn trial_days(&self) -> Option<&u32> {
+        self.trial_days.as_ref()
+    }
+    pub fn trial_start_date(&self) -> Option<&DateTime<Utc>> {
+        self.trial_start_date.as_ref()
+    }
+    pub fn trial_end_date(&self) -> Option<&DateTime<Utc>> {
+        self.trial_end_date.as_ref()
+    }
+    pub fn created_date(&self) -> Option<&DateTime<Utc>> {
+        self.created_date.as_ref()
+    }
+    pub fn start_date(&self) -> Option<&DateTime<Utc>> {
+        self.start_date.as_ref()
+    }
+    pub fn end_date(&self) -> Option<&DateTime<Utc>> {
+        self.end_date.as_ref()
+    }
+    pub fn orders(&self) -> Option<&Vec<SubscriptionOrder>> {
+        self.orders.as_ref()
+    }
+}
+
+/// A recurring charge of a subscription.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub struct SubscriptionOrder {
+    reference_code: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    #[serde(rename = "currencyCode")]
+    currency: Option<String>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    start_period: Option<DateTime<Utc>>,
+
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    end_period: Option<DateTime<Utc>>,
+
+    order_status: Option<SubscriptionOrderStatus>,
+}
+
+impl SubscriptionOrder {
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_start_period<T: Into<DateTime<Utc>>>(&mut self, start_period: T) {
+        self.start_period = Some(start_period.into());
+    }
+
+    pub fn set_end_period<T: Into<DateTime<Utc>>>(&mut self, end_period: T) {
+        self.end_period = Some(end_period.into());
+    }
+
+    pub fn set_order_status<T: Into<SubscriptionOrderStatus>>(&mut self, order_status: T) {
+        self.order_status = Some(order_status.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn start_period(&self) -> Option<&DateTime<Utc>> {
+        self.start_period.as_ref()
+    }
+    pub fn end_period(&self) -> Option<&DateTime<Utc>> {
+        self.end_period.as_ref()
+    }
+    pub fn order_status(&self) -> Option<&SubscriptionOrderStatus> {
+        self.order_status.as_ref()
+    }
+}
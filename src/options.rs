@@ -1,8 +1,38 @@
+use std::collections::HashMap;
+
+use crate::secret::Secret;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AuthScheme {
+    #[default]
+    V1,
+    V2,
+}
+
+impl AuthScheme {
+    pub fn value(&self) -> &'static str {
+        match self {
+            AuthScheme::V1 => "IYZWS",
+            AuthScheme::V2 => "IYZWSv2",
+        }
+    }
+}
+
+impl std::fmt::Display for AuthScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct Options {
     api_key: &'static str,
-    secret_key: &'static str,
+    secret_key: Secret,
     base_url: &'static str,
+    auth_scheme: AuthScheme,
+    endpoint_auth_schemes: HashMap<String, AuthScheme>,
+    validate_requests: bool,
+    marketplace: bool,
 }
 
 impl Options {
@@ -14,23 +44,73 @@ impl Options {
         &self.api_key
     }
 
-    pub fn secret_key(&self) -> &'static str {
-        &self.secret_key
+    pub fn secret_key(&self) -> &str {
+        self.secret_key.expose()
     }
 
     pub fn base_url(&self) -> &'static str {
         &self.base_url
     }
 
+    pub fn auth_scheme(&self) -> AuthScheme {
+        self.auth_scheme
+    }
+
+    pub fn validate_requests(&self) -> bool {
+        self.validate_requests
+    }
+
+    pub fn marketplace(&self) -> bool {
+        self.marketplace
+    }
+
     pub fn set_api_key(&mut self, api_key: &'static str) {
         self.api_key = api_key;
     }
 
-    pub fn set_secret_key(&mut self, secret_key: &'static str) {
-        self.secret_key = secret_key;
+    /// Sets the secret key, which is zeroed when the options are dropped. Pass an owned `String`
+    /// for this to cover the only copy of the key.
+    pub fn set_secret_key<T: Into<String>>(&mut self, secret_key: T) {
+        self.secret_key = Secret::new(secret_key);
     }
 
     pub fn set_base_url(&mut self, base_url: &'static str) {
         self.base_url = base_url;
     }
+
+    /// Sets the scheme used for every endpoint without an override.
+    pub fn set_auth_scheme(&mut self, auth_scheme: AuthScheme) {
+        self.auth_scheme = auth_scheme;
+    }
+
+    /// Overrides the scheme for endpoints whose path starts with `path`, e.g. `/payment/auth`.
+    pub fn set_endpoint_auth_scheme<T: Into<String>>(&mut self, path: T, auth_scheme: AuthScheme) {
+        self.endpoint_auth_schemes.insert(path.into(), auth_scheme);
+    }
+
+    pub fn remove_endpoint_auth_scheme(&mut self, path: &str) {
+        self.endpoint_auth_schemes.remove(path);
+    }
+
+    /// Validates payment requests before they are sent and fails with `ValidationErrors` instead
+    /// of calling iyzico when they are invalid.
+    pub fn set_validate_requests(&mut self, validate_requests: bool) {
+        self.validate_requests = validate_requests;
+    }
+
+    /// Marks the merchant as a marketplace, so validation requires a sub merchant on every basket
+    /// item.
+    pub fn set_marketplace(&mut self, marketplace: bool) {
+        self.marketplace = marketplace;
+    }
+
+    /// Resolves the scheme for `path`, preferring the longest matching override.
+    pub fn endpoint_auth_scheme(&self, path: &str) -> AuthScheme {
+        self.endpoint_auth_schemes
+            .iter()
+            .filter(|(prefix, _)| path.starts_with(prefix.as_str()))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, auth_scheme)| *auth_scheme)
+            .unwrap_or(self.auth_scheme)
+    }
 }
@@ -0,0 +1,342 @@
+use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+
+use crate::model::BkmInstallment;
+use crate::model::Currency;
+use crate::model::PaymentCard;
+use crate::requests::serialize_price;
+use crate::requests::Request;
+use crate::requests::Set;
+use crate::requests::Validate;
+use crate::requests::Validator;
+
+/// A payment without basket items, buyer or addresses, for `BasicPayment`,
+/// `BasicPaymentPreAuth`, `BasicThreedsInitialize` and `BasicThreedsInitializePreAuth`.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBasicPaymentRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    #[serde(serialize_with = "serialize_price")]
+    price: Option<BigDecimal>,
+
+    #[serde(serialize_with = "serialize_price")]
+    paid_price: Option<BigDecimal>,
+
+    installment: Option<u8>,
+
+    buyer_email: Option<String>,
+
+    buyer_id: Option<String>,
+
+    buyer_ip: Option<String>,
+
+    pos_order_id: Option<String>,
+
+    payment_card: Option<PaymentCard>,
+
+    currency: Option<String>,
+
+    connector_name: Option<String>,
+
+    callback_url: Option<String>,
+}
+
+impl CreateBasicPaymentRequest {
+    pub fn new() -> Self {
+        CreateBasicPaymentRequest::default()
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_installment<T: Into<u8>>(&mut self, installment: T) {
+        self.installment = Some(installment.into());
+    }
+
+    pub fn set_buyer_email<T: Into<String>>(&mut self, buyer_email: T) {
+        self.buyer_email = Some(buyer_email.into().trim().to_string());
+    }
+
+    pub fn set_buyer_id<T: Into<String>>(&mut self, buyer_id: T) {
+        self.buyer_id = Some(buyer_id.into());
+    }
+
+    pub fn set_buyer_ip<T: Into<String>>(&mut self, buyer_ip: T) {
+        self.buyer_ip = Some(buyer_ip.into().trim().to_string());
+    }
+
+    pub fn set_pos_order_id<T: Into<String>>(&mut self, pos_order_id: T) {
+        self.pos_order_id = Some(pos_order_id.into());
+    }
+
+    pub fn set_payment_card<T: Into<PaymentCard>>(&mut self, payment_card: T) {
+        self.payment_card = Some(payment_card.into());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_connector_name<T: Into<String>>(&mut self, connector_name: T) {
+        self.connector_name = Some(connector_name.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn installment(&self) -> Option<&u8> {
+        self.installment.as_ref()
+    }
+    pub fn buyer_email(&self) -> Option<&String> {
+        self.buyer_email.as_ref()
+    }
+    pub fn buyer_id(&self) -> Option<&String> {
+        self.buyer_id.as_ref()
+    }
+    pub fn buyer_ip(&self) -> Option<&String> {
+        self.buyer_ip.as_ref()
+    }
+    pub fn pos_order_id(&self) -> Option<&String> {
+        self.pos_order_id.as_ref()
+    }
+    pub fn payment_card(&self) -> Option<&PaymentCard> {
+        self.payment_card.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn connector_name(&self) -> Option<&String> {
+        self.connector_name.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateBasicPaymentRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateBasicPaymentRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateBasicPaymentRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if let Some(price) = validator.required("price", self.price.as_ref()) {
+            if price <= &BigDecimal::zero() {
+                validator.add("price", "must be greater than 0");
+            }
+        }
+        validator.paid_price(self.price.as_ref(), self.paid_price.as_ref());
+        validator.installment("installment", self.installment.as_ref());
+        if let Some(payment_card) = validator.required("paymentCard", self.payment_card.as_ref()) {
+            validator.nested("paymentCard", |validator| {
+                payment_card.validate_into(validator)
+            });
+        }
+        validator.email("buyerEmail", self.buyer_email.as_ref());
+        validator.ip("buyerIp", self.buyer_ip.as_ref());
+    }
+}
+
+request_builder! {
+    /// Builds a `CreateBasicPaymentRequest` that has the fields iyzico requires for a basic
+    /// payment.
+    pub struct CreateBasicPaymentRequestBuilder for CreateBasicPaymentRequest {
+        required {
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+            },
+            HasPaidPrice {
+                paid_price(impl Into<BigDecimal>) => set_paid_price,
+            },
+            HasPaymentCard {
+                payment_card(impl Into<PaymentCard>) => set_payment_card,
+            },
+            HasCallbackUrl {
+                callback_url(impl Into<String>) => set_callback_url,
+            },
+        }
+        optional {
+            installment(impl Into<u8>) => set_installment,
+            buyer_email(impl Into<String>) => set_buyer_email,
+            buyer_id(impl Into<String>) => set_buyer_id,
+            buyer_ip(impl Into<String>) => set_buyer_ip,
+            pos_order_id(impl Into<String>) => set_pos_order_id,
+            currency(Currency) => set_currency,
+            connector_name(impl Into<String>) => set_connector_name,
+        }
+    }
+}
+
+impl<HasCallbackUrl> CreateBasicPaymentRequestBuilder<Set, Set, Set, HasCallbackUrl> {
+    /// Builds a request for `BasicPayment::create` and `BasicPaymentPreAuth::create`.
+    pub fn build(self) -> CreateBasicPaymentRequest {
+        self.request
+    }
+}
+
+impl CreateBasicPaymentRequestBuilder<Set, Set, Set, Set> {
+    /// Builds a request for `BasicThreedsInitialize::create` and
+    /// `BasicThreedsInitializePreAuth::create`, which also need a callback url.
+    pub fn build_threeds(self) -> CreateBasicPaymentRequest {
+        self.request
+    }
+}
+
+/// Starts a basic BKM Express payment, with the installment prices offered per bank.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBasicBkmInitializeRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    connector_name: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    price: Option<BigDecimal>,
+
+    callback_url: Option<String>,
+
+    buyer_email: Option<String>,
+
+    buyer_id: Option<String>,
+
+    buyer_ip: Option<String>,
+
+    pos_order_id: Option<String>,
+
+    installment_details: Option<Vec<BkmInstallment>>,
+}
+
+impl CreateBasicBkmInitializeRequest {
+    pub fn new() -> Self {
+        CreateBasicBkmInitializeRequest::default()
+    }
+
+    pub fn set_connector_name<T: Into<String>>(&mut self, connector_name: T) {
+        self.connector_name = Some(connector_name.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn set_buyer_email<T: Into<String>>(&mut self, buyer_email: T) {
+        self.buyer_email = Some(buyer_email.into().trim().to_string());
+    }
+
+    pub fn set_buyer_id<T: Into<String>>(&mut self, buyer_id: T) {
+        self.buyer_id = Some(buyer_id.into());
+    }
+
+    pub fn set_buyer_ip<T: Into<String>>(&mut self, buyer_ip: T) {
+        self.buyer_ip = Some(buyer_ip.into().trim().to_string());
+    }
+
+    pub fn set_pos_order_id<T: Into<String>>(&mut self, pos_order_id: T) {
+        self.pos_order_id = Some(pos_order_id.into());
+    }
+
+    pub fn set_installment_details<T: Into<Vec<BkmInstallment>>>(
+        &mut self,
+        installment_details: T,
+    ) {
+        self.installment_details = Some(installment_details.into());
+    }
+
+    pub fn connector_name(&self) -> Option<&String> {
+        self.connector_name.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+    pub fn buyer_email(&self) -> Option<&String> {
+        self.buyer_email.as_ref()
+    }
+    pub fn buyer_id(&self) -> Option<&String> {
+        self.buyer_id.as_ref()
+    }
+    pub fn buyer_ip(&self) -> Option<&String> {
+        self.buyer_ip.as_ref()
+    }
+    pub fn pos_order_id(&self) -> Option<&String> {
+        self.pos_order_id.as_ref()
+    }
+    pub fn installment_details(&self) -> Option<&Vec<BkmInstallment>> {
+        self.installment_details.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateBasicBkmInitializeRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateBasicBkmInitializeRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateBasicBkmInitializeRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if let Some(price) = validator.required("price", self.price.as_ref()) {
+            if price <= &BigDecimal::zero() {
+                validator.add("price", "must be greater than 0");
+            }
+        }
+        validator.required("callbackUrl", self.callback_url.as_ref());
+        validator.email("buyerEmail", self.buyer_email.as_ref());
+        validator.ip("buyerIp", self.buyer_ip.as_ref());
+        for (index, installment) in self.installment_details.iter().flatten().enumerate() {
+            for (price_index, installment_price) in installment
+                .installment_prices()
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                validator.installment(
+                    &format!(
+                        "installmentDetails[{}].installmentPrices[{}].installmentNumber",
+                        index, price_index
+                    ),
+                    installment_price.installment_number(),
+                );
+            }
+        }
+    }
+}
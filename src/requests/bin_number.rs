@@ -1,6 +1,3 @@
-use crate::requests::RequestStringBuilder;
-
-use self::super::PKISerialize;
 use self::super::Request;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -26,15 +23,6 @@ impl RetrieveBinNumberRequest {
     }
 }
 
-impl PKISerialize for RetrieveBinNumberRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("binNumber", self.bin_number.as_ref());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveBinNumberRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
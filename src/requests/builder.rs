@@ -0,0 +1,125 @@
+/// Marks a required field of a request builder that has not been set yet.
+#[derive(Debug)]
+pub struct Unset;
+
+/// Marks a required field of a request builder that has been set.
+///
+/// `build` is only available once every required field is `Set`, so a missing field is a compile
+/// error instead of an iyzico error at runtime:
+///
+/// ```compile_fail
+/// use iyzipay_rust::requests::CreateRefundRequest;
+///
+/// // price is missing
+/// let request = CreateRefundRequest::builder()
+///     .payment_transaction_id("1")
+///     .build();
+/// ```
+#[derive(Debug)]
+pub struct Set;
+
+/// Declares a builder for a request with one type parameter per required field. Each required
+/// field is listed with the setters that satisfy it and moves its parameter from `Unset` to `Set`;
+/// optional setters keep the state as it is. The `build` methods are written next to the request,
+/// as they decide which combinations of fields are complete.
+macro_rules! request_builder {
+    (
+        $(#[$meta:meta])*
+        pub struct $builder:ident for $request:ident {
+            required {
+                $($state:ident {
+                    $($field:ident($arg:ty) => $setter:ident,)+
+                },)+
+            }
+            optional {
+                $($optional_field:ident($optional_arg:ty) => $optional_setter:ident,)*
+            }
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        pub struct $builder<$($state = crate::requests::Unset),+> {
+            request: $request,
+
+            state: std::marker::PhantomData<($($state,)+)>,
+        }
+
+        impl $request {
+            pub fn builder() -> $builder {
+                $builder::new()
+            }
+        }
+
+        impl $builder {
+            pub fn new() -> Self {
+                $builder {
+                    request: $request::new(),
+                    state: std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl Default for $builder {
+            fn default() -> Self {
+                $builder::new()
+            }
+        }
+
+        impl<$($state),+> $builder<$($state),+> {
+            pub fn locale(mut self, locale: crate::model::Locale) -> Self {
+                self.request.set_locale(locale);
+                self
+            }
+
+            pub fn conversation_id(mut self, conversation_id: impl Into<String>) -> Self {
+                self.request.set_conversation_id(conversation_id);
+                self
+            }
+
+            $(
+                pub fn $optional_field(mut self, $optional_field: $optional_arg) -> Self {
+                    self.request.$optional_setter($optional_field);
+                    self
+                }
+            )*
+        }
+
+        request_builder!(@required $builder [] [$($state { $($field($arg) => $setter,)+ })+]);
+    };
+
+    (@required $builder:ident [$($before:ident)*] []) => {};
+
+    (
+        @required $builder:ident [$($before:ident)*] [
+            $state:ident { $($field:ident($arg:ty) => $setter:ident,)+ }
+            $($after:ident { $($after_body:tt)* })*
+        ]
+    ) => {
+        request_builder!(
+            @setters $builder
+            [$($before,)* $state, $($after),*]
+            [$builder<$($before,)* $state, $($after),*>]
+            [$builder<$($before,)* crate::requests::Set, $($after),*>]
+            $($field($arg) => $setter,)+
+        );
+
+        request_builder!(@required $builder [$($before)* $state] [$($after { $($after_body)* })*]);
+    };
+
+    (
+        @setters $builder:ident [$($generic:ident),+ $(,)?] [$from:ty] [$to:ty]
+        $($field:ident($arg:ty) => $setter:ident,)+
+    ) => {
+        impl<$($generic),+> $from {
+            $(
+                pub fn $field(mut self, $field: $arg) -> $to {
+                    self.request.$setter($field);
+                    $builder {
+                        request: self.request,
+                        state: std::marker::PhantomData,
+                    }
+                }
+            )+
+        }
+    };
+}
@@ -1,7 +1,6 @@
 use crate::model::CardInformation;
-use crate::requests::PKISerialize;
 use crate::requests::Request;
-use crate::requests::RequestStringBuilder;
+use crate::requests::Set;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -53,18 +52,6 @@ impl CreateCardRequest {
     }
 }
 
-impl PKISerialize for CreateCardRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("externalId", self.external_id.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        ser.append_option("card", self.card.serialize());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateCardRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -78,6 +65,31 @@ impl std::ops::DerefMut for CreateCardRequest {
     }
 }
 
+request_builder! {
+    /// Builds a `CreateCardRequest`. The card is stored for an existing card user when a card user
+    /// key is given, otherwise a new card user is created for the email.
+    pub struct CreateCardRequestBuilder for CreateCardRequest {
+        required {
+            HasCardUser {
+                card_user_key(impl Into<String>) => set_card_user_key,
+                email(impl Into<String>) => set_email,
+            },
+            HasCard {
+                card(impl Into<CardInformation>) => set_card,
+            },
+        }
+        optional {
+            external_id(impl Into<String>) => set_external_id,
+        }
+    }
+}
+
+impl CreateCardRequestBuilder<Set, Set> {
+    pub fn build(self) -> CreateCardRequest {
+        self.request
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteCardRequest {
@@ -123,16 +135,6 @@ impl std::ops::DerefMut for DeleteCardRequest {
     }
 }
 
-impl PKISerialize for DeleteCardRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        ser.append_option("cardToken", self.card_token.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetrieveCardListRequest {
@@ -169,15 +171,6 @@ impl std::ops::DerefMut for RetrieveCardListRequest {
     }
 }
 
-impl PKISerialize for RetrieveCardListRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateCardManagementPageInitializeRequest {
@@ -255,21 +248,6 @@ impl CreateCardManagementPageInitializeRequest {
     }
 }
 
-impl PKISerialize for CreateCardManagementPageInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("addNewCardEnabled", self.add_new_card_enabled);
-        ser.append_option("validateNewCard", self.validate_new_card);
-        ser.append_option("externalId", self.external_id.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        ser.append_option("debitCardAllowed", self.debit_card_allowed);
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateCardManagementPageInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -289,6 +267,7 @@ pub struct RetrieveCardManagementPageCardRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(rename(serialize = "token"))]
     page_token: Option<String>,
 }
 
@@ -306,15 +285,6 @@ impl RetrieveCardManagementPageCardRequest {
     }
 }
 
-impl PKISerialize for RetrieveCardManagementPageCardRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("token", self.page_token.as_ref());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveCardManagementPageCardRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
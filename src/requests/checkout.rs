@@ -3,9 +3,13 @@ use bigdecimal::BigDecimal;
 use crate::model::Address;
 use crate::model::BasketItem;
 use crate::model::Buyer;
-use crate::requests::PKISerialize;
+use crate::model::Currency;
+use crate::model::PaymentGroup;
+use crate::requests::serialize_price;
 use crate::requests::Request;
-use crate::requests::RequestStringBuilder;
+use crate::requests::Set;
+use crate::requests::Validate;
+use crate::requests::Validator;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -13,18 +17,13 @@ pub struct CreateCheckoutFormInitializeRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
-    paid_price: Option<BigDecimal>,
-
     basket_id: Option<String>,
 
     payment_group: Option<String>,
 
-    payment_source: Option<String>,
-
-    currency: Option<String>,
-
     buyer: Option<Buyer>,
 
     shipping_address: Option<Address>,
@@ -35,13 +34,20 @@ pub struct CreateCheckoutFormInitializeRequest {
 
     callback_url: Option<String>,
 
+    payment_source: Option<String>,
+
+    currency: Option<String>,
+
+    pos_order_id: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    paid_price: Option<BigDecimal>,
+
     #[serde(rename = "forceThreeDS")]
     force_three_ds: Option<u8>,
 
     card_user_key: Option<String>,
 
-    pos_order_id: Option<String>,
-
     enabled_installments: Option<Vec<u8>>,
 
     payment_with_new_card_enabled: Option<bool>,
@@ -66,7 +72,11 @@ impl CreateCheckoutFormInitializeRequest {
         self.basket_id = Some(basket_id.into());
     }
 
-    pub fn set_payment_group<T: Into<String>>(&mut self, payment_group: T) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<T: Into<String>>(&mut self, payment_group: T) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -74,7 +84,11 @@ impl CreateCheckoutFormInitializeRequest {
         self.payment_source = Some(payment_source.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -178,34 +192,6 @@ impl CreateCheckoutFormInitializeRequest {
     }
 }
 
-impl PKISerialize for CreateCheckoutFormInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("basketId", self.basket_id.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("buyer", self.buyer.serialize());
-        ser.append_option("shippingAddress", self.shipping_address.serialize());
-        ser.append_option("billingAddress", self.billing_address.serialize());
-        ser.append_option("basketItems", self.basket_items.serialize());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option("posOrderId", self.pos_order_id.as_ref());
-        ser.append_price_option("paidPrice", self.paid_price.as_ref());
-        ser.append_option("forceThreeDS", self.force_three_ds);
-        ser.append_option("cardUserKey", self.card_user_key.as_ref());
-        ser.append_option("enabledInstallments", self.enabled_installments.serialize());
-        ser.append_option(
-            "paymentWithNewCardEnabled",
-            self.payment_with_new_card_enabled,
-        );
-        ser.append_option("debitCardAllowed", self.debit_card_allowed);
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateCheckoutFormInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -219,6 +205,70 @@ impl std::ops::DerefMut for CreateCheckoutFormInitializeRequest {
     }
 }
 
+impl Validate for CreateCheckoutFormInitializeRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.basket(self.price.as_ref(), self.basket_items.as_ref());
+        validator.paid_price(self.price.as_ref(), self.paid_price.as_ref());
+        if let Some(enabled_installments) = &self.enabled_installments {
+            for (index, installment) in enabled_installments.iter().enumerate() {
+                validator.installment(
+                    &format!("enabledInstallments[{}]", index),
+                    Some(installment),
+                );
+            }
+        }
+        validator.buyer(self.buyer.as_ref());
+        validator.address("shippingAddress", self.shipping_address.as_ref());
+        validator.address("billingAddress", self.billing_address.as_ref());
+    }
+}
+
+request_builder! {
+    /// Builds a `CreateCheckoutFormInitializeRequest` that has the fields iyzico requires to
+    /// initialize a checkout form.
+    pub struct CreateCheckoutFormInitializeRequestBuilder for CreateCheckoutFormInitializeRequest {
+        required {
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+            },
+            HasPaidPrice {
+                paid_price(impl Into<BigDecimal>) => set_paid_price,
+            },
+            HasCallbackUrl {
+                callback_url(impl Into<String>) => set_callback_url,
+            },
+            HasBuyer {
+                buyer(impl Into<Buyer>) => set_buyer,
+            },
+            HasBillingAddress {
+                billing_address(impl Into<Address>) => set_billing_address,
+            },
+            HasBasketItems {
+                basket_items(impl Into<Vec<BasketItem>>) => set_basket_items,
+            },
+        }
+        optional {
+            basket_id(impl Into<String>) => set_basket_id,
+            payment_group(PaymentGroup) => set_payment_group,
+            payment_source(impl Into<String>) => set_payment_source,
+            currency(Currency) => set_currency,
+            shipping_address(impl Into<Address>) => set_shipping_address,
+            force_three_ds(impl Into<u8>) => set_force_three_ds,
+            card_user_key(impl Into<String>) => set_card_user_key,
+            pos_order_id(impl Into<String>) => set_pos_order_id,
+            enabled_installments(impl Into<Vec<u8>>) => set_enabled_installments,
+            payment_with_new_card_enabled(impl Into<bool>) => set_payment_with_new_card_enabled,
+            debit_card_allowed(impl Into<bool>) => set_debit_card_allowed,
+        }
+    }
+}
+
+impl CreateCheckoutFormInitializeRequestBuilder<Set, Set, Set, Set, Set, Set> {
+    pub fn build(self) -> CreateCheckoutFormInitializeRequest {
+        self.request
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetrieveCheckoutFormRequest {
@@ -242,15 +292,6 @@ impl RetrieveCheckoutFormRequest {
     }
 }
 
-impl PKISerialize for RetrieveCheckoutFormRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("token", self.token.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveCheckoutFormRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -1,8 +1,8 @@
 use bigdecimal::BigDecimal;
 
-use crate::requests::RequestStringBuilder;
+use crate::model::Currency;
+use crate::requests::serialize_price;
 
-use self::super::PKISerialize;
 use self::super::Request;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -13,6 +13,7 @@ pub struct RetrieveInstallmentInfoRequest {
 
     bin_number: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
     currency: Option<String>,
@@ -31,7 +32,11 @@ impl RetrieveInstallmentInfoRequest {
         self.price = Some(price.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -58,14 +63,3 @@ impl std::ops::DerefMut for RetrieveInstallmentInfoRequest {
         &mut self.request
     }
 }
-
-impl PKISerialize for RetrieveInstallmentInfoRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("binNumber", self.bin_number.as_ref());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        Option::from(ser.build(true))
-    }
-}
@@ -1,8 +1,10 @@
 use bigdecimal::BigDecimal;
 
-use crate::requests::PKISerialize;
+use crate::model::Currency;
+use crate::model::Money;
+use crate::requests::skip_pki;
 use crate::requests::Request;
-use crate::requests::RequestStringBuilder;
+use crate::requests::Set;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -10,22 +12,28 @@ pub struct IyziLinkSaveRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "skip_pki")]
     name: Option<String>,
 
+    #[serde(serialize_with = "skip_pki")]
     description: Option<String>,
 
-    #[serde(rename = "encodedImageFile")]
+    #[serde(rename = "encodedImageFile", serialize_with = "skip_pki")]
     base64_encoded_image: Option<String>,
 
+    #[serde(serialize_with = "skip_pki")]
     price: Option<BigDecimal>,
 
-    #[serde(rename = "currencyCode")]
+    #[serde(rename = "currencyCode", serialize_with = "skip_pki")]
     currency: Option<String>,
 
+    #[serde(serialize_with = "skip_pki")]
     address_ignorable: Option<bool>,
 
+    #[serde(serialize_with = "skip_pki")]
     sold_limit: Option<u8>,
 
+    #[serde(serialize_with = "skip_pki")]
     installment_requested: Option<bool>,
 }
 
@@ -50,7 +58,16 @@ impl IyziLinkSaveRequest {
         self.price = Some(price.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_price_money(&mut self, price: Money) {
+        self.set_currency(price.currency().clone());
+        self.price = Some(price.into());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -92,14 +109,6 @@ impl IyziLinkSaveRequest {
     }
 }
 
-impl PKISerialize for IyziLinkSaveRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for IyziLinkSaveRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -112,3 +121,38 @@ impl std::ops::DerefMut for IyziLinkSaveRequest {
         &mut self.request
     }
 }
+
+request_builder! {
+    /// Builds an `IyziLinkSaveRequest` that has the fields iyzico requires to create or update a
+    /// link.
+    pub struct IyziLinkSaveRequestBuilder for IyziLinkSaveRequest {
+        required {
+            HasName {
+                name(impl Into<String>) => set_name,
+            },
+            HasDescription {
+                description(impl Into<String>) => set_description,
+            },
+            HasImage {
+                base64_encoded_image(impl Into<String>) => set_base64_encoded_image,
+            },
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+            },
+            HasCurrency {
+                currency(Currency) => set_currency,
+            },
+        }
+        optional {
+            address_ignorable(impl Into<bool>) => set_address_ignorable,
+            sold_limit(impl Into<u8>) => set_sold_limit,
+            installment_requested(impl Into<bool>) => set_installment_requested,
+        }
+    }
+}
+
+impl IyziLinkSaveRequestBuilder<Set, Set, Set, Set, Set> {
+    pub fn build(self) -> IyziLinkSaveRequest {
+        self.request
+    }
+}
@@ -1,10 +1,11 @@
 use bigdecimal::BigDecimal;
 
+use crate::model::Currency;
 use crate::model::InitialConsumer;
 use crate::model::OrderItem;
-use crate::requests::PKISerialize;
+use crate::model::PaymentGroup;
+use crate::requests::serialize_price;
 use crate::requests::Request;
-use crate::requests::RequestStringBuilder;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -27,10 +28,13 @@ pub struct CreateIyziupFormInitializeRequest {
 
     currency: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
+    #[serde(serialize_with = "serialize_price")]
     paid_price: Option<BigDecimal>,
 
+    #[serde(serialize_with = "serialize_price")]
     shipping_price: Option<BigDecimal>,
 
     callback_url: Option<String>,
@@ -53,7 +57,11 @@ impl CreateIyziupFormInitializeRequest {
         self.merchant_order_id = Some(merchant_order_id.into());
     }
 
-    pub fn set_payment_group<T: Into<String>>(&mut self, payment_group: T) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<T: Into<String>>(&mut self, payment_group: T) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -73,7 +81,11 @@ impl CreateIyziupFormInitializeRequest {
         self.enabled_card_family = Some(enabled_card_family.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -156,29 +168,6 @@ impl CreateIyziupFormInitializeRequest {
     }
 }
 
-impl PKISerialize for CreateIyziupFormInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("merchantOrderId", self.merchant_order_id.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_option("forceThreeDS", self.force_three_ds.as_ref());
-        ser.append_option("enabledInstallments", self.enabled_installments.serialize());
-        ser.append_option("enabledCardFamily", self.enabled_card_family.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_price_option("paidPrice", self.paid_price.as_ref());
-        ser.append_price_option("shippingPrice", self.shipping_price.as_ref());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        ser.append_option("termsUrl", self.terms_url.as_ref());
-        ser.append_option("preSalesContractUrl", self.pre_sales_contract_url.as_ref());
-        ser.append_option("orderItems", self.order_items.serialize());
-        ser.append_option("initialConsumer", self.initial_consumer.serialize());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateIyziupFormInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -215,15 +204,6 @@ impl RetrieveIyziupFormRequest {
     }
 }
 
-impl PKISerialize for RetrieveIyziupFormRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("token", self.token.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveIyziupFormRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
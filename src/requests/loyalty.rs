@@ -0,0 +1,67 @@
+use crate::model::Currency;
+use crate::model::PaymentCard;
+use crate::requests::Validate;
+use crate::requests::Validator;
+
+use self::super::Request;
+
+/// The reward points available on a card, before paying part of a payment with them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrieveLoyaltyRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    payment_card: Option<PaymentCard>,
+
+    currency: Option<String>,
+}
+
+impl RetrieveLoyaltyRequest {
+    pub fn new() -> Self {
+        RetrieveLoyaltyRequest::default()
+    }
+
+    pub fn set_payment_card(&mut self, payment_card: PaymentCard) {
+        self.payment_card = Some(payment_card);
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn payment_card(&self) -> Option<&PaymentCard> {
+        self.payment_card.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+}
+
+impl std::ops::Deref for RetrieveLoyaltyRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for RetrieveLoyaltyRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for RetrieveLoyaltyRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if let Some(payment_card) = validator.required("paymentCard", self.payment_card.as_ref()) {
+            validator.nested("paymentCard", |validator| {
+                payment_card.validate_into(validator)
+            });
+        }
+        validator.required("currency", self.currency.as_ref());
+    }
+}
@@ -1,26 +1,54 @@
+pub use self::basic::CreateBasicBkmInitializeRequest;
+pub use self::basic::CreateBasicPaymentRequest;
+pub use self::basic::CreateBasicPaymentRequestBuilder;
 pub use self::bin_number::RetrieveBinNumberRequest;
+pub use self::builder::Set;
+pub use self::builder::Unset;
 pub use self::card::CreateCardManagementPageInitializeRequest;
 pub use self::card::CreateCardRequest;
+pub use self::card::CreateCardRequestBuilder;
 pub use self::card::DeleteCardRequest;
 pub use self::card::RetrieveCardListRequest;
 pub use self::card::RetrieveCardManagementPageCardRequest;
 pub use self::checkout::CreateCheckoutFormInitializeRequest;
+pub use self::checkout::CreateCheckoutFormInitializeRequestBuilder;
 pub use self::checkout::RetrieveCheckoutFormRequest;
 pub use self::installment::RetrieveInstallmentInfoRequest;
 pub use self::iyzilink::IyziLinkSaveRequest;
+pub use self::iyzilink::IyziLinkSaveRequestBuilder;
 pub use self::iyziup::CreateIyziupFormInitializeRequest;
 pub use self::iyziup::RetrieveIyziupFormRequest;
+pub use self::loyalty::RetrieveLoyaltyRequest;
+pub use self::payment::CreateAmountBasedRefundRequest;
+pub use self::payment::CreateAmountBasedRefundRequestBuilder;
 pub use self::payment::CreateBkmInitializeRequest;
 pub use self::payment::CreateCancelRequest;
+pub use self::payment::CreatePaymentPostAuthRequest;
+pub use self::payment::CreatePaymentPostAuthRequestBuilder;
 pub use self::payment::CreatePaymentRequest;
+pub use self::payment::CreatePaymentRequestBuilder;
 pub use self::payment::CreatePeccoInitializeRequest;
 pub use self::payment::CreatePeccoPaymentRequest;
 pub use self::payment::CreateRefundRequest;
+pub use self::payment::CreateRefundRequestBuilder;
+pub use self::payment::CreateRefundToBalanceRequest;
 pub use self::payment::CreateThreedsPaymentRequest;
 pub use self::payment::RetrieveBkmRequest;
 pub use self::payment::RetrievePaymentRequest;
 pub use self::payment::RetrieveTransactionsRequest;
 pub use self::payment::UpdatePaymentItemRequest;
+pub use self::pki::parse_pki_string;
+pub use self::pki::serialize_price;
+pub use self::pki::skip_pki;
+pub use self::pki::to_pki_string;
+pub use self::pki::PKIError;
+pub use self::pki::PKISerializer;
+pub use self::pki::PKIValue;
+pub use self::pki_diagnostics::PKIDiagnostics;
+pub use self::pki_diagnostics::PKIFieldDiagnostic;
+pub use self::pki_diagnostics::PKIFieldStatus;
+pub use self::reporting::RetrievePaymentDetailsRequest;
+pub use self::reporting::RetrieveTransactionReportRequest;
 pub use self::request::PKISerialize;
 pub use self::request::PagingRequest;
 pub use self::request::Request;
@@ -29,17 +57,46 @@ pub use self::request::RequestQueryParams;
 pub use self::request::RequestStringBuilder;
 pub use self::sub_merchant::CreateApmInitializeRequest;
 pub use self::sub_merchant::CreateApprovalRequest;
+pub use self::sub_merchant::CreateCrossBookingRequest;
+pub use self::sub_merchant::CreateSettlementToBalanceRequest;
 pub use self::sub_merchant::CreateSubMerchantRequest;
+pub use self::sub_merchant::CreateSubMerchantRequestBuilder;
 pub use self::sub_merchant::RetrieveApmRequest;
 pub use self::sub_merchant::RetrieveSubMerchantRequest;
 pub use self::sub_merchant::UpdateSubMerchantRequest;
+pub use self::subscription::CreatePricingPlanRequest;
+pub use self::subscription::CreateSubscriptionCardUpdateRequest;
+pub use self::subscription::CreateSubscriptionInitializeRequest;
+pub use self::subscription::CreateSubscriptionProductRequest;
+pub use self::subscription::RetrySubscriptionPaymentRequest;
+pub use self::subscription::SearchSubscriptionsRequest;
+pub use self::subscription::SubscriptionCustomerDetails;
+pub use self::subscription::SubscriptionCustomerRequest;
+pub use self::subscription::UpdatePricingPlanRequest;
+pub use self::subscription::UpdateSubscriptionProductRequest;
+pub use self::subscription::UpgradeSubscriptionRequest;
+pub use self::validation::Validate;
+pub use self::validation::ValidationErrors;
+pub use self::validation::Validator;
+pub use self::validation::Violation;
+pub use self::validation::MAX_NAME_LENGTH;
 
+#[macro_use]
+mod builder;
+
+mod basic;
 mod bin_number;
 mod card;
 mod checkout;
 mod installment;
 mod iyzilink;
 mod iyziup;
+mod loyalty;
 mod payment;
+mod pki;
+mod pki_diagnostics;
+mod reporting;
 mod request;
 mod sub_merchant;
+mod subscription;
+mod validation;
@@ -1,13 +1,27 @@
 use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+use chrono::NaiveDateTime;
 
+use crate::model::date::{deserialize_date_time_field, DateTimeField};
 use crate::model::Address;
 use crate::model::BasketItem;
 use crate::model::Buyer;
+use crate::model::Currency;
+use crate::model::InstallmentPrice;
+use crate::model::IntoDateTime;
+use crate::model::Money;
+use crate::model::MoneyError;
 use crate::model::PaymentCard;
+use crate::model::PaymentChannel;
+use crate::model::PaymentGroup;
 use crate::model::RefundReason;
-use crate::requests::PKISerialize;
+use crate::model::Reward;
+use crate::requests::serialize_price;
+use crate::requests::skip_pki;
 use crate::requests::Request;
-use crate::requests::RequestStringBuilder;
+use crate::requests::Set;
+use crate::requests::Validate;
+use crate::requests::Validator;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -15,8 +29,10 @@ pub struct CreatePaymentRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
+    #[serde(serialize_with = "serialize_price")]
     paid_price: Option<BigDecimal>,
 
     installment: Option<u8>,
@@ -39,13 +55,15 @@ pub struct CreatePaymentRequest {
 
     payment_source: Option<String>,
 
-    currency: Option<String>,
-
     pos_order_id: Option<String>,
 
+    currency: Option<String>,
+
     connector_name: Option<String>,
 
     callback_url: Option<String>,
+
+    campaign_id: Option<String>,
 }
 
 impl CreatePaymentRequest {
@@ -61,11 +79,31 @@ impl CreatePaymentRequest {
         self.paid_price = Some(paid_price.into());
     }
 
+    /// Sets price, paid price and currency at once. Both amounts must be in the same currency.
+    pub fn set_money(&mut self, price: Money, paid_price: Money) -> Result<(), MoneyError> {
+        price.same_currency(&paid_price)?;
+        self.set_currency(price.currency().clone());
+        self.price = Some(price.into());
+        self.paid_price = Some(paid_price.into());
+        Ok(())
+    }
+
     pub fn set_installment<T: Into<u8>>(&mut self, installment: T) {
         self.installment = Some(installment.into());
     }
 
-    pub fn set_payment_channel<T: Into<String>>(&mut self, payment_channel: T) {
+    /// Pays with an option of `InstallmentInfo`, setting its installment number and, for a bank
+    /// campaign, its campaign id.
+    pub fn select_installment_price(&mut self, installment_price: &InstallmentPrice) {
+        self.installment = installment_price.installment_number().cloned();
+        self.campaign_id = installment_price.campaign_id().cloned();
+    }
+
+    pub fn set_payment_channel(&mut self, payment_channel: PaymentChannel) {
+        self.payment_channel = Some(payment_channel.value().to_string());
+    }
+
+    pub fn set_payment_channel_raw<T: Into<String>>(&mut self, payment_channel: T) {
         self.payment_channel = Some(payment_channel.into());
     }
 
@@ -73,7 +111,11 @@ impl CreatePaymentRequest {
         self.basket_id = Some(basket_id.into());
     }
 
-    pub fn set_payment_group<T: Into<String>>(&mut self, payment_group: T) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<T: Into<String>>(&mut self, payment_group: T) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -101,7 +143,11 @@ impl CreatePaymentRequest {
         self.payment_source = Some(payment_source.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -117,6 +163,10 @@ impl CreatePaymentRequest {
         self.callback_url = Some(callback_url.into());
     }
 
+    pub fn set_campaign_id<T: Into<String>>(&mut self, campaign_id: T) {
+        self.campaign_id = Some(campaign_id.into());
+    }
+
     pub fn price(&self) -> Option<&BigDecimal> {
         self.price.as_ref()
     }
@@ -165,6 +215,9 @@ impl CreatePaymentRequest {
     pub fn callback_url(&self) -> Option<&String> {
         self.callback_url.as_ref()
     }
+    pub fn campaign_id(&self) -> Option<&String> {
+        self.campaign_id.as_ref()
+    }
 }
 
 impl std::ops::Deref for CreatePaymentRequest {
@@ -180,27 +233,94 @@ impl std::ops::DerefMut for CreatePaymentRequest {
     }
 }
 
-impl PKISerialize for CreatePaymentRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_price_option("paidPrice", self.paid_price.as_ref());
-        ser.append_option("installment", self.installment);
-        ser.append_option("paymentChannel", self.payment_channel.as_ref());
-        ser.append_option("basketId", self.basket_id.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("paymentCard", self.payment_card.serialize());
-        ser.append_option("buyer", self.buyer.serialize());
-        ser.append_option("shippingAddress", self.shipping_address.serialize());
-        ser.append_option("billingAddress", self.billing_address.serialize());
-        ser.append_option("basketItems", self.basket_items.serialize());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_option("posOrderId", self.pos_order_id.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option("connectorName", self.connector_name.as_ref());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        Option::from(ser.build(true))
+impl Validate for CreatePaymentRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.basket(self.price.as_ref(), self.basket_items.as_ref());
+        validator.basket_currency(self.currency.as_ref(), self.basket_items.as_ref());
+        validator.paid_price(self.price.as_ref(), self.paid_price.as_ref());
+        validator.installment("installment", self.installment.as_ref());
+        if self.campaign_id.is_some() {
+            validator.required("installment", self.installment.as_ref());
+        }
+        if let Some(payment_card) = validator.required("paymentCard", self.payment_card.as_ref()) {
+            validator.nested("paymentCard", |validator| {
+                payment_card.validate_into(validator)
+            });
+            let reward_amount = payment_card.reward().and_then(Reward::reward_amount);
+            if let (Some(reward_amount), Some(paid_price)) =
+                (reward_amount, self.paid_price.as_ref())
+            {
+                if reward_amount > paid_price {
+                    validator.add(
+                        "paymentCard.reward.rewardAmount",
+                        "must not exceed paidPrice",
+                    );
+                }
+            }
+        }
+        validator.buyer(self.buyer.as_ref());
+        validator.address("shippingAddress", self.shipping_address.as_ref());
+        validator.address("billingAddress", self.billing_address.as_ref());
+    }
+}
+
+request_builder! {
+    /// Builds a `CreatePaymentRequest` that has the fields iyzico requires for a payment. A
+    /// shipping address is still needed when the basket has physical items.
+    pub struct CreatePaymentRequestBuilder for CreatePaymentRequest {
+        required {
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+            },
+            HasPaidPrice {
+                paid_price(impl Into<BigDecimal>) => set_paid_price,
+            },
+            HasInstallment {
+                installment(impl Into<u8>) => set_installment,
+            },
+            HasPaymentCard {
+                payment_card(impl Into<PaymentCard>) => set_payment_card,
+            },
+            HasBuyer {
+                buyer(impl Into<Buyer>) => set_buyer,
+            },
+            HasBillingAddress {
+                billing_address(impl Into<Address>) => set_billing_address,
+            },
+            HasBasketItems {
+                basket_items(impl Into<Vec<BasketItem>>) => set_basket_items,
+            },
+            HasCallbackUrl {
+                callback_url(impl Into<String>) => set_callback_url,
+            },
+        }
+        optional {
+            payment_channel(PaymentChannel) => set_payment_channel,
+            basket_id(impl Into<String>) => set_basket_id,
+            payment_group(PaymentGroup) => set_payment_group,
+            shipping_address(impl Into<Address>) => set_shipping_address,
+            payment_source(impl Into<String>) => set_payment_source,
+            pos_order_id(impl Into<String>) => set_pos_order_id,
+            currency(Currency) => set_currency,
+            connector_name(impl Into<String>) => set_connector_name,
+            campaign_id(impl Into<String>) => set_campaign_id,
+        }
+    }
+}
+
+impl<HasCallbackUrl>
+    CreatePaymentRequestBuilder<Set, Set, Set, Set, Set, Set, Set, HasCallbackUrl>
+{
+    /// Builds a request for `Payment::create`.
+    pub fn build(self) -> CreatePaymentRequest {
+        self.request
+    }
+}
+
+impl CreatePaymentRequestBuilder<Set, Set, Set, Set, Set, Set, Set, Set> {
+    /// Builds a request for `ThreedsInitialize::create`, which also needs a callback url.
+    pub fn build_threeds(self) -> CreatePaymentRequest {
+        self.request
     }
 }
 
@@ -229,19 +349,6 @@ impl RetrievePaymentRequest {
     }
 }
 
-impl PKISerialize for RetrievePaymentRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentId", self.payment_id.as_ref());
-        ser.append_option(
-            "paymentConversationId",
-            self.payment_conversation_id.as_ref(),
-        );
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrievePaymentRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -292,31 +399,141 @@ impl CreateCancelRequest {
     }
 }
 
-impl PKISerialize for CreateCancelRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentId", self.payment_id.as_ref());
-        ser.append_option("ip", self.ip.as_ref());
-        ser.append_option("reason", self.reason.serialize());
-        ser.append_option("description", self.description.as_ref());
-        Option::from(ser.build(true))
+impl std::ops::Deref for CreateCancelRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
     }
 }
 
-impl std::ops::Deref for CreateCancelRequest {
+impl std::ops::DerefMut for CreateCancelRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl CreateCancelRequest {
+    /// Cancels a pre-authorized payment that has not been captured yet, releasing the held funds.
+    pub fn void<T: Into<String>>(payment_id: T) -> Self {
+        let mut request = CreateCancelRequest::new();
+        request.set_payment_id(payment_id);
+        request
+    }
+}
+
+/// Captures a pre-authorized payment. A `paid_price` lower than the pre-authorized amount
+/// captures part of it and releases the rest.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePaymentPostAuthRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    payment_id: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    paid_price: Option<BigDecimal>,
+
+    ip: Option<String>,
+
+    currency: Option<String>,
+}
+
+impl CreatePaymentPostAuthRequest {
+    pub fn new() -> Self {
+        CreatePaymentPostAuthRequest::default()
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_paid_price<T: Into<BigDecimal>>(&mut self, paid_price: T) {
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_paid_price_money(&mut self, paid_price: Money) {
+        self.set_currency(paid_price.currency().clone());
+        self.paid_price = Some(paid_price.into());
+    }
+
+    pub fn set_ip<T: Into<String>>(&mut self, ip: T) {
+        self.ip = Some(ip.into().trim().to_string());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn paid_price(&self) -> Option<&BigDecimal> {
+        self.paid_price.as_ref()
+    }
+    pub fn ip(&self) -> Option<&String> {
+        self.ip.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreatePaymentPostAuthRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
         &self.request
     }
 }
 
-impl std::ops::DerefMut for CreateCancelRequest {
+impl std::ops::DerefMut for CreatePaymentPostAuthRequest {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.request
     }
 }
 
+impl Validate for CreatePaymentPostAuthRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("paymentId", self.payment_id.as_ref());
+        if let Some(paid_price) = validator.required("paidPrice", self.paid_price.as_ref()) {
+            if paid_price <= &BigDecimal::zero() {
+                validator.add("paidPrice", "must be greater than 0");
+            }
+        }
+        validator.ip("ip", self.ip.as_ref());
+    }
+}
+
+request_builder! {
+    /// Builds a `CreatePaymentPostAuthRequest` for a pre-authorized payment and the amount to
+    /// capture.
+    pub struct CreatePaymentPostAuthRequestBuilder for CreatePaymentPostAuthRequest {
+        required {
+            HasPaymentId {
+                payment_id(impl Into<String>) => set_payment_id,
+            },
+            HasPaidPrice {
+                paid_price(impl Into<BigDecimal>) => set_paid_price,
+                paid_price_money(Money) => set_paid_price_money,
+            },
+        }
+        optional {
+            ip(impl Into<String>) => set_ip,
+            currency(Currency) => set_currency,
+        }
+    }
+}
+
+impl CreatePaymentPostAuthRequestBuilder<Set, Set> {
+    pub fn build(self) -> CreatePaymentPostAuthRequest {
+        self.request
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateThreedsPaymentRequest {
@@ -349,16 +566,6 @@ impl CreateThreedsPaymentRequest {
     }
 }
 
-impl PKISerialize for CreateThreedsPaymentRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentId", self.payment_id.as_ref());
-        ser.append_option("conversationData", self.conversation_data.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateThreedsPaymentRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -380,6 +587,7 @@ pub struct CreateRefundRequest {
 
     payment_transaction_id: Option<String>,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
     ip: Option<String>,
@@ -404,11 +612,20 @@ impl CreateRefundRequest {
         self.price = Some(price.into());
     }
 
+    pub fn set_price_money(&mut self, price: Money) {
+        self.set_currency(price.currency().clone());
+        self.price = Some(price.into());
+    }
+
     pub fn set_ip<T: Into<String>>(&mut self, ip: T) {
         self.ip = Some(ip.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -440,39 +657,240 @@ impl CreateRefundRequest {
     }
 }
 
-impl PKISerialize for CreateRefundRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentTransactionId", self.payment_transaction_id.as_ref());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("ip", self.ip.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option("reason", self.reason.serialize());
-        ser.append_option("description", self.description.as_ref());
-        Some(ser.build(true))
+impl std::ops::Deref for CreateRefundRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
     }
 }
 
-impl std::ops::Deref for CreateRefundRequest {
+impl std::ops::DerefMut for CreateRefundRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+request_builder! {
+    /// Builds a `CreateRefundRequest` for a payment transaction and an amount.
+    pub struct CreateRefundRequestBuilder for CreateRefundRequest {
+        required {
+            HasPaymentTransactionId {
+                payment_transaction_id(impl Into<String>) => set_payment_transaction_id,
+            },
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+                price_money(Money) => set_price_money,
+            },
+        }
+        optional {
+            ip(impl Into<String>) => set_ip,
+            currency(Currency) => set_currency,
+            reason(impl Into<RefundReason>) => set_reason,
+            description(impl Into<String>) => set_description,
+        }
+    }
+}
+
+impl CreateRefundRequestBuilder<Set, Set> {
+    pub fn build(self) -> CreateRefundRequest {
+        self.request
+    }
+}
+
+/// Refunds an amount of a payment by its payment id. iyzico distributes the amount over the basket
+/// items of the payment, unlike `CreateRefundRequest` which refunds a single item.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAmountBasedRefundRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    payment_id: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    price: Option<BigDecimal>,
+
+    ip: Option<String>,
+
+    currency: Option<String>,
+
+    reason: Option<RefundReason>,
+
+    description: Option<String>,
+}
+
+impl CreateAmountBasedRefundRequest {
+    pub fn new() -> Self {
+        CreateAmountBasedRefundRequest::default()
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_price_money(&mut self, price: Money) {
+        self.set_currency(price.currency().clone());
+        self.price = Some(price.into());
+    }
+
+    pub fn set_ip<T: Into<String>>(&mut self, ip: T) {
+        self.ip = Some(ip.into());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_reason<T: Into<RefundReason>>(&mut self, reason: T) {
+        self.reason = Some(reason.into());
+    }
+
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = Some(description.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn ip(&self) -> Option<&String> {
+        self.ip.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn reason(&self) -> Option<&RefundReason> {
+        self.reason.as_ref()
+    }
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateAmountBasedRefundRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
         &self.request
     }
 }
 
-impl std::ops::DerefMut for CreateRefundRequest {
+impl std::ops::DerefMut for CreateAmountBasedRefundRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateAmountBasedRefundRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("paymentId", self.payment_id.as_ref());
+        if let Some(price) = validator.required("price", self.price.as_ref()) {
+            if price <= &BigDecimal::zero() {
+                validator.add("price", "must be greater than 0");
+            }
+        }
+        validator.ip("ip", self.ip.as_ref());
+    }
+}
+
+request_builder! {
+    /// Builds a `CreateAmountBasedRefundRequest` for a payment and an amount.
+    pub struct CreateAmountBasedRefundRequestBuilder for CreateAmountBasedRefundRequest {
+        required {
+            HasPaymentId {
+                payment_id(impl Into<String>) => set_payment_id,
+            },
+            HasPrice {
+                price(impl Into<BigDecimal>) => set_price,
+                price_money(Money) => set_price_money,
+            },
+        }
+        optional {
+            ip(impl Into<String>) => set_ip,
+            currency(Currency) => set_currency,
+            reason(impl Into<RefundReason>) => set_reason,
+            description(impl Into<String>) => set_description,
+        }
+    }
+}
+
+impl CreateAmountBasedRefundRequestBuilder<Set, Set> {
+    pub fn build(self) -> CreateAmountBasedRefundRequest {
+        self.request
+    }
+}
+
+/// Refunds a payment to the iyzico balance of the buyer instead of their card. The buyer
+/// confirms it on the page returned by `RefundToBalance::create` and is sent back to the
+/// callback url.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRefundToBalanceRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    payment_id: Option<String>,
+
+    callback_url: Option<String>,
+}
+
+impl CreateRefundToBalanceRequest {
+    pub fn new() -> Self {
+        CreateRefundToBalanceRequest::default()
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateRefundToBalanceRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateRefundToBalanceRequest {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.request
     }
 }
 
+impl Validate for CreateRefundToBalanceRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("paymentId", self.payment_id.as_ref());
+        validator.required("callbackUrl", self.callback_url.as_ref());
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateBkmInitializeRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
     basket_id: Option<String>,
@@ -491,6 +909,7 @@ pub struct CreateBkmInitializeRequest {
 
     payment_source: Option<String>,
 
+    #[serde(serialize_with = "skip_pki")]
     currency: Option<String>,
 
     enabled_installments: Option<Vec<u8>>,
@@ -509,7 +928,11 @@ impl CreateBkmInitializeRequest {
         self.basket_id = Some(basket_id.into());
     }
 
-    pub fn set_payment_group<S: Into<String>>(&mut self, payment_group: S) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<S: Into<String>>(&mut self, payment_group: S) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -537,7 +960,11 @@ impl CreateBkmInitializeRequest {
         self.payment_source = Some(payment_source.into());
     }
 
-    pub fn set_currency<S: Into<String>>(&mut self, currency: S) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<S: Into<String>>(&mut self, currency: S) {
         self.currency = Some(currency.into());
     }
 
@@ -546,24 +973,6 @@ impl CreateBkmInitializeRequest {
     }
 }
 
-impl PKISerialize for CreateBkmInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("basketId", self.basket_id.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("buyer", self.buyer.serialize());
-        ser.append_option("shippingAddress", self.shipping_address.serialize());
-        ser.append_option("billingAddress", self.billing_address.serialize());
-        ser.append_option("basketItems", self.basket_items.serialize());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_option("enabledInstallments", self.enabled_installments.serialize());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateBkmInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -577,6 +986,23 @@ impl std::ops::DerefMut for CreateBkmInitializeRequest {
     }
 }
 
+impl Validate for CreateBkmInitializeRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.basket(self.price.as_ref(), self.basket_items.as_ref());
+        if let Some(enabled_installments) = &self.enabled_installments {
+            for (index, installment) in enabled_installments.iter().enumerate() {
+                validator.installment(
+                    &format!("enabledInstallments[{}]", index),
+                    Some(installment),
+                );
+            }
+        }
+        validator.buyer(self.buyer.as_ref());
+        validator.address("shippingAddress", self.shipping_address.as_ref());
+        validator.address("billingAddress", self.billing_address.as_ref());
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetrieveBkmRequest {
@@ -596,15 +1022,6 @@ impl RetrieveBkmRequest {
     }
 }
 
-impl PKISerialize for RetrieveBkmRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("token", self.token.as_ref());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveBkmRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -624,7 +1041,8 @@ pub struct RetrieveTransactionsRequest {
     #[serde(flatten)]
     request: Request,
 
-    date: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_date_time_field")]
+    date: Option<DateTimeField>,
 }
 
 impl RetrieveTransactionsRequest {
@@ -632,17 +1050,13 @@ impl RetrieveTransactionsRequest {
         RetrieveTransactionsRequest::default()
     }
 
-    pub fn set_date<S: Into<String>>(&mut self, date: S) {
-        self.date = Some(date.into());
+    /// Sets the date from a chrono date, or from a `yyyy-MM-dd HH:mm:ss` string.
+    pub fn set_date<T: IntoDateTime>(&mut self, date: T) {
+        self.date = DateTimeField::from(date);
     }
-}
 
-impl PKISerialize for RetrieveTransactionsRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("date", self.date.as_ref());
-        Some(ser.build(true))
+    pub fn date(&self) -> Option<&NaiveDateTime> {
+        self.date.as_ref().and_then(DateTimeField::date_time)
     }
 }
 
@@ -665,18 +1079,13 @@ pub struct CreatePeccoInitializeRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
-    paid_price: Option<BigDecimal>,
-
     basket_id: Option<String>,
 
     payment_group: Option<String>,
 
-    payment_source: Option<String>,
-
-    currency: Option<String>,
-
     buyer: Option<Buyer>,
 
     shipping_address: Option<Address>,
@@ -686,6 +1095,14 @@ pub struct CreatePeccoInitializeRequest {
     basket_items: Option<Vec<BasketItem>>,
 
     callback_url: Option<String>,
+
+    payment_source: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    paid_price: Option<BigDecimal>,
+
+    #[serde(serialize_with = "skip_pki")]
+    currency: Option<String>,
 }
 
 impl CreatePeccoInitializeRequest {
@@ -705,7 +1122,11 @@ impl CreatePeccoInitializeRequest {
         self.basket_id = Some(basket_id.into());
     }
 
-    pub fn set_payment_group<S: Into<String>>(&mut self, payment_group: S) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<S: Into<String>>(&mut self, payment_group: S) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -713,7 +1134,11 @@ impl CreatePeccoInitializeRequest {
         self.payment_source = Some(payment_source.into());
     }
 
-    pub fn set_currency<S: Into<String>>(&mut self, currency: S) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<S: Into<String>>(&mut self, currency: S) {
         self.currency = Some(currency.into());
     }
 
@@ -738,24 +1163,6 @@ impl CreatePeccoInitializeRequest {
     }
 }
 
-impl PKISerialize for CreatePeccoInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_option("basketId", self.basket_id.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("buyer", self.buyer.serialize());
-        ser.append_option("shippingAddress", self.shipping_address.serialize());
-        ser.append_option("billingAddress", self.billing_address.serialize());
-        ser.append_option("basketItems", self.basket_items.serialize());
-        ser.append_option("callbackUrl", self.callback_url.as_ref());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_price_option("paidPrice", self.paid_price.as_ref());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreatePeccoInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -769,6 +1176,16 @@ impl std::ops::DerefMut for CreatePeccoInitializeRequest {
     }
 }
 
+impl Validate for CreatePeccoInitializeRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.basket(self.price.as_ref(), self.basket_items.as_ref());
+        validator.paid_price(self.price.as_ref(), self.paid_price.as_ref());
+        validator.buyer(self.buyer.as_ref());
+        validator.address("shippingAddress", self.shipping_address.as_ref());
+        validator.address("billingAddress", self.billing_address.as_ref());
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdatePaymentItemRequest {
@@ -779,6 +1196,7 @@ pub struct UpdatePaymentItemRequest {
 
     payment_transaction_id: Option<i32>,
 
+    #[serde(serialize_with = "serialize_price")]
     sub_merchant_price: Option<BigDecimal>,
 }
 
@@ -800,17 +1218,6 @@ impl UpdatePaymentItemRequest {
     }
 }
 
-impl PKISerialize for UpdatePaymentItemRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("subMerchantKey", self.sub_merchant_key.as_ref());
-        ser.append_option("paymentTransactionId", self.payment_transaction_id);
-        ser.append_price_option("subMerchantPrice", self.sub_merchant_price.as_ref());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for UpdatePaymentItemRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -843,15 +1250,6 @@ impl CreatePeccoPaymentRequest {
     }
 }
 
-impl PKISerialize for CreatePeccoPaymentRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option_val(self.token.to_owned());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreatePeccoPaymentRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -0,0 +1,471 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use serde::ser;
+use serde::ser::Serialize;
+
+use crate::requests::RequestFormatter;
+
+const PRICE: &str = "$iyzipay::pki::price";
+const SKIP: &str = "$iyzipay::pki::skip";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PKIError(pub(crate) String);
+
+impl fmt::Display for PKIError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for PKIError {}
+
+impl ser::Error for PKIError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        PKIError(msg.to_string())
+    }
+}
+
+/// Serializes `value` to iyzico's PKI string, e.g. `[locale=tr,price=1.0,buyer=[id=BY789]]`.
+/// Returns `None` when the value itself is `None`.
+pub fn to_pki_string<T: Serialize + ?Sized>(value: &T) -> Result<Option<String>, PKIError> {
+    value.serialize(PKISerializer)
+}
+
+/// `serialize_with` helper for prices, formatted with `RequestFormatter::format_price` in the
+/// PKI string and left untouched in JSON.
+pub fn serialize_price<S: ser::Serializer>(
+    price: &Option<BigDecimal>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match price {
+        Some(price) => serializer.serialize_some(&PKIMarker(PRICE, price)),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// `serialize_with` helper for fields sent in JSON but left out of the PKI string.
+pub fn skip_pki<T: Serialize, S: ser::Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_newtype_struct(SKIP, value)
+}
+
+struct PKIMarker<'a, T>(&'static str, &'a T);
+
+impl<'a, T: Serialize> Serialize for PKIMarker<'a, T> {
+    fn serialize<S: ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_newtype_struct(self.0, self.1)
+    }
+}
+
+pub struct PKISerializer;
+
+impl PKISerializer {
+    fn display<T: fmt::Display>(value: T) -> Result<Option<String>, PKIError> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn unsupported(kind: &str) -> PKIError {
+        PKIError(format!("{} can not be serialized to a PKI string", kind))
+    }
+}
+
+impl ser::Serializer for PKISerializer {
+    type Ok = Option<String>;
+    type Error = PKIError;
+    type SerializeSeq = PKISeq;
+    type SerializeTuple = PKISeq;
+    type SerializeTupleStruct = PKISeq;
+    type SerializeTupleVariant = ser::Impossible<Option<String>, PKIError>;
+    type SerializeMap = PKIStruct;
+    type SerializeStruct = PKIStruct;
+    type SerializeStructVariant = ser::Impossible<Option<String>, PKIError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_char(self, v: char) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(v)
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(PKISerializer::unsupported("bytes"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        PKISerializer::display(variant)
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        match name {
+            SKIP => Ok(None),
+            PRICE => match value.serialize(self)? {
+                Some(price) => {
+                    let price = BigDecimal::from_str(price.as_str())
+                        .map_err(|e| PKIError(format!("invalid price {}: {}", price, e)))?;
+                    Ok(Some(RequestFormatter::format_price(&price)))
+                }
+                None => Ok(None),
+            },
+            _ => value.serialize(self),
+        }
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(PKISerializer::unsupported("newtype variant"))
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(PKISeq(Vec::new()))
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(PKISerializer::unsupported("tuple variant"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Ok(PKIStruct::default())
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(PKIStruct::default())
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        _variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(PKISerializer::unsupported("struct variant"))
+    }
+}
+
+/// Sequence items are joined with `, ` and `None` items are dropped, e.g. `[[id=1], [id=2]]`.
+pub struct PKISeq(Vec<String>);
+
+impl PKISeq {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), PKIError> {
+        if let Some(value) = value.serialize(PKISerializer)? {
+            self.0.push(value);
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<Option<String>, PKIError> {
+        Ok(Some(format!("[{}]", self.0.join(", "))))
+    }
+}
+
+impl ser::SerializeSeq for PKISeq {
+    type Ok = Option<String>;
+    type Error = PKIError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.build()
+    }
+}
+
+impl ser::SerializeTuple for PKISeq {
+    type Ok = Option<String>;
+    type Error = PKIError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.build()
+    }
+}
+
+impl ser::SerializeTupleStruct for PKISeq {
+    type Ok = Option<String>;
+    type Error = PKIError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.build()
+    }
+}
+
+/// Struct fields and map entries become `key=value` pairs joined with `,`; `None` values are
+/// dropped. Flattened fields arrive as map entries and therefore end up inline.
+#[derive(Default)]
+pub struct PKIStruct {
+    fields: Vec<String>,
+
+    key: Option<String>,
+}
+
+impl PKIStruct {
+    fn push<T: Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), PKIError> {
+        if let Some(value) = value.serialize(PKISerializer)? {
+            self.fields.push(format!("{}={}", key, value));
+        }
+        Ok(())
+    }
+
+    fn build(self) -> Result<Option<String>, PKIError> {
+        Ok(Some(format!("[{}]", self.fields.join(","))))
+    }
+}
+
+impl ser::SerializeStruct for PKIStruct {
+    type Ok = Option<String>;
+    type Error = PKIError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Self::Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.build()
+    }
+}
+
+impl ser::SerializeMap for PKIStruct {
+    type Ok = Option<String>;
+    type Error = PKIError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Self::Error> {
+        self.key = key.serialize(PKISerializer)?;
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Self::Error> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| PKIError(String::from("map value without a key")))?;
+        self.push(key.as_str(), value)
+    }
+
+    fn end(self) -> Result<Self::Ok, Self::Error> {
+        self.build()
+    }
+}
+
+/// A parsed PKI string. Scalars are kept as the raw text that was signed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PKIValue {
+    Value(String),
+    Object(Vec<(String, PKIValue)>),
+    Array(Vec<PKIValue>),
+}
+
+/// Parses a PKI string such as `[locale=tr,basketItems=[[id=BI101], [id=BI102]]]`.
+///
+/// The format has no escaping, so fields are split on top level commas followed by `key=` and
+/// array items on top level `, `. Values containing `,key=` or unbalanced brackets can not be
+/// told apart from the structure around them.
+pub fn parse_pki_string(pki: &str) -> Result<PKIValue, PKIError> {
+    let pki = pki.trim();
+    match bracketed(pki) {
+        Some(_) => Ok(parse_value(pki)),
+        None => Err(PKIError(format!(
+            "PKI string must be enclosed in []: {}",
+            pki
+        ))),
+    }
+}
+
+fn parse_value(value: &str) -> PKIValue {
+    let inner = match bracketed(value) {
+        Some(inner) => inner,
+        None => return PKIValue::Value(value.to_string()),
+    };
+    if inner.is_empty() {
+        return PKIValue::Array(Vec::new());
+    }
+    if key_at(inner, 0).is_some() {
+        let mut fields = Vec::new();
+        for field in split_top_level(inner, |rest| {
+            rest.starts_with(',') && key_at(rest, 1).is_some()
+        }) {
+            let field = field.trim_start_matches(',');
+            let mut parts = field.splitn(2, '=');
+            let key = parts.next().unwrap_or_default().to_string();
+            fields.push((key, parse_value(parts.next().unwrap_or_default())));
+        }
+        PKIValue::Object(fields)
+    } else {
+        let items = split_top_level(inner, |rest| rest.starts_with(", "))
+            .into_iter()
+            .map(|item| parse_value(item.trim_start_matches(", ")))
+            .collect();
+        PKIValue::Array(items)
+    }
+}
+
+/// Returns the content of `value` when its first `[` is closed by its last character.
+fn bracketed(value: &str) -> Option<&str> {
+    if !value.starts_with('[') || !value.ends_with(']') {
+        return None;
+    }
+    let mut depth = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 && i != value.len() - 1 {
+                    return None;
+                }
+            }
+            _ => {}
+        }
+    }
+    if depth == 0 {
+        Some(&value[1..value.len() - 1])
+    } else {
+        None
+    }
+}
+
+/// Returns the key when `value[start..]` starts with `key=`.
+fn key_at(value: &str, start: usize) -> Option<&str> {
+    let rest = value.get(start..)?;
+    let end = rest.find('=')?;
+    let key = &rest[..end];
+    let mut chars = key.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() && chars.all(|c| c.is_ascii_alphanumeric()) => Some(key),
+        _ => None,
+    }
+}
+
+/// Splits `value` before every top level position accepted by `is_separator`, keeping the
+/// separator at the start of the following part.
+fn split_top_level<F: Fn(&str) -> bool>(value: &str, is_separator: F) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            _ if depth == 0 && i > start && is_separator(&value[i..]) => {
+                parts.push(&value[start..i]);
+                start = i;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
@@ -0,0 +1,377 @@
+use std::fmt;
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use serde::de;
+use serde::de::Deserialize;
+use serde::de::Deserializer;
+use serde::ser::Serialize;
+
+use crate::requests::parse_pki_string;
+use crate::requests::to_pki_string;
+use crate::requests::PKIError;
+use crate::requests::PKIValue;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PKIFieldStatus {
+    Match,
+    /// Same amount written differently, e.g. `1.20` in JSON and `1.2` in PKI.
+    PriceFormatted,
+    Mismatch,
+    MissingInPki,
+    MissingInJson,
+}
+
+impl PKIFieldStatus {
+    pub fn value(&self) -> &'static str {
+        match self {
+            PKIFieldStatus::Match => "ok",
+            PKIFieldStatus::PriceFormatted => "price formatted",
+            PKIFieldStatus::Mismatch => "mismatch",
+            PKIFieldStatus::MissingInPki => "missing in PKI",
+            PKIFieldStatus::MissingInJson => "missing in JSON",
+        }
+    }
+}
+
+impl fmt::Display for PKIFieldStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value())
+    }
+}
+
+/// A leaf field such as `buyer.name` or `basketItems[1].price` on both sides of a request.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PKIFieldDiagnostic {
+    path: String,
+
+    json_value: Option<String>,
+
+    pki_value: Option<String>,
+
+    json_position: Option<usize>,
+
+    pki_position: Option<usize>,
+
+    status: PKIFieldStatus,
+
+    out_of_order: bool,
+}
+
+impl PKIFieldDiagnostic {
+    pub fn path(&self) -> &String {
+        &self.path
+    }
+    pub fn json_value(&self) -> Option<&String> {
+        self.json_value.as_ref()
+    }
+    pub fn pki_value(&self) -> Option<&String> {
+        self.pki_value.as_ref()
+    }
+    pub fn json_position(&self) -> Option<usize> {
+        self.json_position
+    }
+    pub fn pki_position(&self) -> Option<usize> {
+        self.pki_position
+    }
+    pub fn status(&self) -> PKIFieldStatus {
+        self.status
+    }
+    /// Whether the field comes in a different order than its siblings in JSON and PKI.
+    pub fn out_of_order(&self) -> bool {
+        self.out_of_order
+    }
+}
+
+/// Compares the JSON body of a request with its PKI string to find out why iyzico rejected an
+/// `IYZWS` signature. Fields are listed in PKI order followed by JSON-only fields; `null` JSON
+/// values are ignored since they are never part of the PKI string.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PKIDiagnostics {
+    fields: Vec<PKIFieldDiagnostic>,
+}
+
+impl PKIDiagnostics {
+    pub fn of<T: Serialize + ?Sized>(req: &T) -> Result<Self, PKIError> {
+        let json = serde_json::to_string(req).map_err(|e| PKIError(e.to_string()))?;
+        let pki = to_pki_string(req)?.unwrap_or_else(|| String::from("[]"));
+        PKIDiagnostics::compare(json.as_str(), pki.as_str())
+    }
+
+    /// Compares a captured JSON body with a captured PKI string.
+    pub fn compare(json: &str, pki: &str) -> Result<Self, PKIError> {
+        let json = serde_json::from_str::<JsonTree>(json)
+            .map_err(|e| PKIError(e.to_string()))?
+            .0
+            .unwrap_or_else(|| PKIValue::Object(Vec::new()));
+        let pki = parse_pki_string(pki)?;
+
+        let mut json_fields = Vec::new();
+        flatten(&json, String::new(), &mut json_fields);
+        let mut pki_fields = Vec::new();
+        flatten(&pki, String::new(), &mut pki_fields);
+
+        let mut fields: Vec<PKIFieldDiagnostic> = Vec::new();
+        for (pki_position, (path, pki_value)) in pki_fields.iter().enumerate() {
+            let json_field = json_fields.iter().position(|(p, _)| p == path);
+            let json_value = json_field.map(|i| json_fields[i].1.clone());
+            fields.push(PKIFieldDiagnostic {
+                path: path.clone(),
+                status: status_of(json_value.as_ref(), Some(pki_value)),
+                json_value,
+                pki_value: Some(pki_value.clone()),
+                json_position: json_field,
+                pki_position: Some(pki_position),
+                out_of_order: false,
+            });
+        }
+        for (json_position, (path, json_value)) in json_fields.iter().enumerate() {
+            if !pki_fields.iter().any(|(p, _)| p == path) {
+                fields.push(PKIFieldDiagnostic {
+                    path: path.clone(),
+                    json_value: Some(json_value.clone()),
+                    pki_value: None,
+                    json_position: Some(json_position),
+                    pki_position: None,
+                    status: PKIFieldStatus::MissingInPki,
+                    out_of_order: false,
+                });
+            }
+        }
+        mark_out_of_order(&mut fields);
+
+        Ok(PKIDiagnostics { fields })
+    }
+
+    pub fn fields(&self) -> &Vec<PKIFieldDiagnostic> {
+        &self.fields
+    }
+
+    /// Fields sent in the JSON body but not signed.
+    pub fn missing_in_pki(&self) -> Vec<&PKIFieldDiagnostic> {
+        self.with_status(PKIFieldStatus::MissingInPki)
+    }
+
+    pub fn with_status(&self, status: PKIFieldStatus) -> Vec<&PKIFieldDiagnostic> {
+        self.fields.iter().filter(|f| f.status == status).collect()
+    }
+
+    pub fn has_issues(&self) -> bool {
+        self.fields
+            .iter()
+            .any(|f| f.status != PKIFieldStatus::Match || f.out_of_order)
+    }
+}
+
+impl fmt::Display for PKIDiagnostics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let width = |column: &dyn Fn(&PKIFieldDiagnostic) -> usize, title: &str| {
+            self.fields
+                .iter()
+                .map(column)
+                .max()
+                .unwrap_or(0)
+                .max(title.chars().count())
+        };
+        let path_width = width(&|d| d.path.chars().count(), "field");
+        let json_width = width(&|d| cell(d.json_value.as_ref()).chars().count(), "json");
+        let pki_width = width(&|d| cell(d.pki_value.as_ref()).chars().count(), "pki");
+
+        writeln!(
+            f,
+            "{:<pw$} | {:<jw$} | {:<kw$} | status",
+            "field",
+            "json",
+            "pki",
+            pw = path_width,
+            jw = json_width,
+            kw = pki_width
+        )?;
+        for d in &self.fields {
+            let mut status = d.status.to_string();
+            if d.out_of_order {
+                status.push_str(", out of order");
+            }
+            writeln!(
+                f,
+                "{:<pw$} | {:<jw$} | {:<kw$} | {}",
+                d.path,
+                cell(d.json_value.as_ref()),
+                cell(d.pki_value.as_ref()),
+                status,
+                pw = path_width,
+                jw = json_width,
+                kw = pki_width
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn cell(value: Option<&String>) -> &str {
+    value.map(String::as_str).unwrap_or("-")
+}
+
+fn status_of(json: Option<&String>, pki: Option<&String>) -> PKIFieldStatus {
+    match (json, pki) {
+        (Some(json), Some(pki)) if json == pki => PKIFieldStatus::Match,
+        (Some(json), Some(pki)) => match (BigDecimal::from_str(json), BigDecimal::from_str(pki)) {
+            (Ok(json), Ok(pki)) if json == pki => PKIFieldStatus::PriceFormatted,
+            _ => PKIFieldStatus::Mismatch,
+        },
+        (None, Some(_)) => PKIFieldStatus::MissingInJson,
+        _ => PKIFieldStatus::MissingInPki,
+    }
+}
+
+fn flatten(value: &PKIValue, path: String, fields: &mut Vec<(String, String)>) {
+    match value {
+        PKIValue::Value(value) => fields.push((path, value.clone())),
+        PKIValue::Object(entries) => {
+            for (key, value) in entries {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten(value, path, fields);
+            }
+        }
+        PKIValue::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(item, format!("{}[{}]", path, i), fields);
+            }
+        }
+    }
+}
+
+/// Flags fields whose rank among the siblings present on both sides differs between JSON and
+/// PKI, at any level of their path.
+fn mark_out_of_order(fields: &mut [PKIFieldDiagnostic]) {
+    let mut common: Vec<(&PKIFieldDiagnostic, usize, usize)> = fields
+        .iter()
+        .filter_map(|f| match (f.json_position, f.pki_position) {
+            (Some(json), Some(pki)) => Some((f, json, pki)),
+            _ => None,
+        })
+        .collect();
+
+    common.sort_by_key(|c| c.1);
+    let json_order = sibling_order(common.iter().map(|c| c.0.path.as_str()));
+    common.sort_by_key(|c| c.2);
+    let pki_order = sibling_order(common.iter().map(|c| c.0.path.as_str()));
+
+    let out_of_order: Vec<String> = common
+        .iter()
+        .filter(|c| {
+            segments(c.0.path.as_str()).iter().any(|segment| {
+                let rank = |order: &Vec<(String, String)>| {
+                    order
+                        .iter()
+                        .filter(|s| s.0 == segment.0)
+                        .position(|s| s.1 == segment.1)
+                };
+                rank(&json_order) != rank(&pki_order)
+            })
+        })
+        .map(|c| c.0.path.clone())
+        .collect();
+    for field in fields.iter_mut() {
+        field.out_of_order = out_of_order.contains(&field.path);
+    }
+}
+
+/// Distinct (parent, key) segments in order of first appearance.
+fn sibling_order<'a, I: Iterator<Item = &'a str>>(paths: I) -> Vec<(String, String)> {
+    let mut order = Vec::new();
+    for path in paths {
+        for segment in segments(path) {
+            if !order.contains(&segment) {
+                order.push(segment);
+            }
+        }
+    }
+    order
+}
+
+/// Splits `basketItems[0].price` into (``, `basketItems`) and (`basketItems[0]`, `price`).
+fn segments(path: &str) -> Vec<(String, String)> {
+    let mut segments = Vec::new();
+    let mut parent = String::new();
+    for part in path.split('.') {
+        let key = part.split('[').next().unwrap_or_default();
+        segments.push((parent.clone(), key.to_string()));
+        if !parent.is_empty() {
+            parent.push('.');
+        }
+        parent.push_str(part);
+    }
+    segments
+}
+
+/// Order preserving JSON tree; `null` values are dropped like in the PKI string.
+struct JsonTree(Option<PKIValue>);
+
+impl<'de> Deserialize<'de> for JsonTree {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(JsonTreeVisitor)
+    }
+}
+
+struct JsonTreeVisitor;
+
+impl JsonTreeVisitor {
+    fn value<T: fmt::Display, E>(value: T) -> Result<JsonTree, E> {
+        Ok(JsonTree(Some(PKIValue::Value(value.to_string()))))
+    }
+}
+
+impl<'de> de::Visitor<'de> for JsonTreeVisitor {
+    type Value = JsonTree;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a JSON value")
+    }
+
+    fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+        JsonTreeVisitor::value(v)
+    }
+
+    fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+        JsonTreeVisitor::value(v)
+    }
+
+    fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E> {
+        JsonTreeVisitor::value(v)
+    }
+
+    fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+        JsonTreeVisitor::value(v)
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+        JsonTreeVisitor::value(v)
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(JsonTree(None))
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(JsonTree(item)) = seq.next_element()? {
+            items.extend(item);
+        }
+        Ok(JsonTree(Some(PKIValue::Array(items))))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+        while let Some((key, JsonTree(value))) = map.next_entry::<String, JsonTree>()? {
+            if let Some(value) = value {
+                entries.push((key, value));
+            }
+        }
+        Ok(JsonTree(Some(PKIValue::Object(entries))))
+    }
+}
@@ -0,0 +1,154 @@
+use chrono::NaiveDate;
+
+use crate::model::IntoDateTime;
+use crate::requests::request::to_query_string;
+use crate::requests::Request;
+use crate::requests::RequestQueryParams;
+use crate::requests::Validate;
+use crate::requests::Validator;
+
+/// Transactions of a single day, one page at a time, sent as query parameters.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrieveTransactionReportRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    transaction_date: Option<NaiveDate>,
+
+    page: Option<u32>,
+}
+
+impl RetrieveTransactionReportRequest {
+    pub fn new() -> Self {
+        RetrieveTransactionReportRequest::default()
+    }
+
+    /// Only the date is used, the time of the day is ignored.
+    pub fn set_transaction_date<T: IntoDateTime>(&mut self, transaction_date: T) {
+        self.transaction_date = transaction_date
+            .into_date_time()
+            .map(|date_time| date_time.date());
+    }
+
+    pub fn set_page<T: Into<u32>>(&mut self, page: T) {
+        self.page = Some(page.into());
+    }
+
+    pub fn transaction_date(&self) -> Option<&NaiveDate> {
+        self.transaction_date.as_ref()
+    }
+    pub fn page(&self) -> Option<&u32> {
+        self.page.as_ref()
+    }
+
+    /// Query parameters of `page` instead of the page of the request.
+    pub fn get_query_params_for_page(&self, page: Option<u32>) -> String {
+        to_query_string(&[
+            ("conversationId", self.conversation_id().cloned()),
+            ("locale", self.locale().cloned()),
+            (
+                "transactionDate",
+                self.transaction_date
+                    .map(|date| date.format("%Y-%m-%d").to_string()),
+            ),
+            ("page", page.map(|page| page.to_string())),
+        ])
+    }
+}
+
+impl std::ops::Deref for RetrieveTransactionReportRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for RetrieveTransactionReportRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl RequestQueryParams for RetrieveTransactionReportRequest {
+    fn get_query_params(&self) -> String {
+        self.get_query_params_for_page(self.page)
+    }
+}
+
+impl Validate for RetrieveTransactionReportRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("transactionDate", self.transaction_date.as_ref());
+        if self.page == Some(0) {
+            validator.add("page", "must be greater than 0");
+        }
+    }
+}
+
+/// Details of a payment, found by its payment id or by the conversation id it was created with.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrievePaymentDetailsRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    payment_id: Option<String>,
+
+    payment_conversation_id: Option<String>,
+}
+
+impl RetrievePaymentDetailsRequest {
+    pub fn new() -> Self {
+        RetrievePaymentDetailsRequest::default()
+    }
+
+    pub fn set_payment_id<T: Into<String>>(&mut self, payment_id: T) {
+        self.payment_id = Some(payment_id.into());
+    }
+
+    pub fn set_payment_conversation_id<T: Into<String>>(&mut self, payment_conversation_id: T) {
+        self.payment_conversation_id = Some(payment_conversation_id.into());
+    }
+
+    pub fn payment_id(&self) -> Option<&String> {
+        self.payment_id.as_ref()
+    }
+    pub fn payment_conversation_id(&self) -> Option<&String> {
+        self.payment_conversation_id.as_ref()
+    }
+}
+
+impl std::ops::Deref for RetrievePaymentDetailsRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for RetrievePaymentDetailsRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl RequestQueryParams for RetrievePaymentDetailsRequest {
+    fn get_query_params(&self) -> String {
+        to_query_string(&[
+            ("conversationId", self.conversation_id().cloned()),
+            ("locale", self.locale().cloned()),
+            ("paymentId", self.payment_id.clone()),
+            (
+                "paymentConversationId",
+                self.payment_conversation_id.clone(),
+            ),
+        ])
+    }
+}
+
+impl Validate for RetrievePaymentDetailsRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if self.payment_id.is_none() && self.payment_conversation_id.is_none() {
+            validator.add("paymentId", "or paymentConversationId is required");
+        }
+    }
+}
@@ -1,56 +1,61 @@
-use std::ops::Deref;
 use std::str;
 
 use bigdecimal::BigDecimal;
+use serde::Serialize;
+
+use crate::model::Locale;
+use crate::requests::to_pki_string;
 
 const DOT: &'static str = ".";
 const ZERO: &'static str = "0";
 const COMMA: &'static str = ",";
 
+/// iyzico's PKI string of a request, used to sign `IYZWS` requests. Implemented for every
+/// `Serialize` type through `to_pki_string`; see `serialize_price` and `skip_pki` for the field
+/// level customizations.
 pub trait PKISerialize {
     fn serialize(&self) -> Option<String>;
 }
 
-impl<T> PKISerialize for Vec<T>
-where
-    T: PKISerialize,
-{
+impl<T: Serialize + ?Sized> PKISerialize for T {
     fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        for val in self {
-            let serialized: Option<String> = val.serialize();
-            if serialized.is_some() {
-                ser.append_raw(format!("{}, ", serialized.unwrap()));
-            }
-        }
-        Option::from(ser.build(true))
+        to_pki_string(self).ok().flatten()
     }
 }
 
-impl PKISerialize for Vec<u8> {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        for val in self {
-            ser.append_raw(format!("{}, ", val.to_string()));
-        }
-        Option::from(ser.build(true))
-    }
+pub trait RequestQueryParams {
+    fn get_query_params(&self) -> String;
 }
 
-impl<T> PKISerialize for Option<T>
-where
-    T: PKISerialize,
-{
-    fn serialize(&self) -> Option<String> {
-        match self.as_ref() {
-            Some(val) => val.serialize(),
-            None => None,
-        }
+/// Builds a query string starting with `?` from the parameters that are set and not empty, with
+/// everything but the unreserved characters of RFC 3986 percent-encoded.
+pub(crate) fn to_query_string(params: &[(&str, Option<String>)]) -> String {
+    let query: Vec<String> = params
+        .iter()
+        .filter_map(|(name, value)| {
+            value
+                .as_ref()
+                .filter(|value| !value.is_empty())
+                .map(|value| format!("{}={}", name, encode_query_value(value)))
+        })
+        .collect();
+    if query.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", query.join("&"))
     }
 }
 
-pub trait RequestQueryParams {
-    fn get_query_params(&self) -> String;
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -77,7 +82,11 @@ impl Request {
         self.conversation_id.as_ref()
     }
 
-    pub fn set_locale<T: Into<String>>(&mut self, locale: T) {
+    pub fn set_locale(&mut self, locale: Locale) {
+        self.locale = Some(locale.value().to_string());
+    }
+
+    pub fn set_locale_raw<T: Into<String>>(&mut self, locale: T) {
         self.locale = Some(locale.into());
     }
 
@@ -86,45 +95,24 @@ impl Request {
     }
 }
 
-impl PKISerialize for Request {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option("locale", self.locale.as_ref());
-        ser.append_option("conversationId", self.conversation_id.as_ref());
-        Option::from(ser.build(false))
-    }
-}
-
 impl RequestQueryParams for Request {
     fn get_query_params(&self) -> String {
-        let mut str = String::new();
-
-        if self.conversation_id().is_some() {
-            let val = self.conversation_id().unwrap();
-            if !val.is_empty() {
-                str.push_str(format!("{}={}", "?conversationId", val.as_str()).as_str());
-            }
-        }
-
-        if self.conversation_id().is_some() {
-            let val = self.locale().unwrap();
-            if !val.is_empty() {
-                str.push_str(format!("{}={}", "&locale", val.as_str()).as_str());
-            }
-        }
-        str
+        to_query_string(&[
+            ("conversationId", self.conversation_id.clone()),
+            ("locale", self.locale.clone()),
+        ])
     }
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PagingRequest {
-    page: Option<u8>,
-
-    count: Option<u8>,
-
     #[serde(flatten)]
     request: Request,
+
+    page: Option<u32>,
+
+    count: Option<u32>,
 }
 
 impl PagingRequest {
@@ -132,57 +120,31 @@ impl PagingRequest {
         PagingRequest::default()
     }
 
-    pub fn set_page<S: Into<Option<u8>>>(&mut self, page: S) {
+    pub fn set_page<S: Into<Option<u32>>>(&mut self, page: S) {
         self.page = page.into();
     }
 
-    pub fn page(&self) -> &Option<u8> {
+    pub fn page(&self) -> &Option<u32> {
         &self.page
     }
 
-    pub fn set_count<S: Into<Option<u8>>>(&mut self, count: S) {
+    pub fn set_count<S: Into<Option<u32>>>(&mut self, count: S) {
         self.count = count.into();
     }
 
-    pub fn count(&self) -> &Option<u8> {
+    pub fn count(&self) -> &Option<u32> {
         &self.count
     }
 }
 
-impl PKISerialize for PagingRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append(
-            "page",
-            str::from_utf8(&[self.page.unwrap_or_default()]).unwrap(),
-        );
-        ser.append(
-            "count",
-            str::from_utf8(&[self.count.unwrap_or_default()]).unwrap(),
-        );
-        Option::from(ser.build(true))
-    }
-}
-
 impl RequestQueryParams for PagingRequest {
     fn get_query_params(&self) -> String {
-        let parent_query_params = &self.deref().get_query_params();
-        let mut str = String::new();
-
-        if !parent_query_params.is_empty() {
-            str.push_str(parent_query_params);
-        }
-
-        if self.page().is_some() {
-            str.push_str(format!("{}={}", "&page", format!("{}", self.page.unwrap())).as_str());
-        }
-
-        if self.count().is_some() {
-            str.push_str(format!("{}={}", "&count", format!("{}", self.count.unwrap())).as_str());
-        }
-
-        str
+        to_query_string(&[
+            ("conversationId", self.conversation_id().cloned()),
+            ("locale", self.locale().cloned()),
+            ("page", self.page.map(|page| page.to_string())),
+            ("count", self.count.map(|count| count.to_string())),
+        ])
     }
 }
 
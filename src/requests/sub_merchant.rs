@@ -1,11 +1,22 @@
 use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
 
+use crate::model::normalize_phone_number;
 use crate::model::Address;
 use crate::model::BasketItem;
 use crate::model::Buyer;
-use crate::requests::RequestStringBuilder;
+use crate::model::Currency;
+use crate::model::Iban;
+use crate::model::IdentityNumberType;
+use crate::model::PaymentChannel;
+use crate::model::PaymentGroup;
+use crate::model::SubMerchantType;
+use crate::requests::serialize_price;
+use crate::requests::Set;
+use crate::requests::Validate;
+use crate::requests::Validator;
+use crate::requests::MAX_NAME_LENGTH;
 
-use self::super::PKISerialize;
 use self::super::Request;
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -36,13 +47,16 @@ pub struct CreateSubMerchantRequest {
 
     currency: Option<String>,
 
+    sub_merchant_external_id: Option<String>,
+
     identity_number: Option<String>,
 
     tax_number: Option<String>,
 
-    sub_merchant_external_id: Option<String>,
-
     sub_merchant_type: Option<String>,
+
+    #[serde(skip)]
+    identity_number_type: IdentityNumberType,
 }
 
 impl CreateSubMerchantRequest {
@@ -55,19 +69,22 @@ impl CreateSubMerchantRequest {
     }
 
     pub fn set_email<T: Into<String>>(&mut self, email: T) {
-        self.email = Some(email.into());
+        self.email = Some(email.into().trim().to_string());
     }
 
+    /// Sets the phone number in E.164, e.g. `+905350000000`, if it can be normalized.
     pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
-        self.gsm_number = Some(gsm_number.into());
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
     }
 
     pub fn set_address<T: Into<String>>(&mut self, address: T) {
         self.address = Some(address.into());
     }
 
+    /// Sets the IBAN without whitespace and in upper case.
     pub fn set_iban<T: Into<String>>(&mut self, iban: T) {
-        self.iban = Some(iban.into());
+        self.iban = Some(Iban::normalize(&iban.into()));
     }
 
     pub fn set_tax_office<T: Into<String>>(&mut self, tax_office: T) {
@@ -90,12 +107,24 @@ impl CreateSubMerchantRequest {
         self.swift_code = Some(swift_code.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
     pub fn set_identity_number<T: Into<String>>(&mut self, identity_number: T) {
         self.identity_number = Some(identity_number.into());
+        self.identity_number_type = IdentityNumberType::Tckn;
+    }
+
+    /// Sets a passport or other foreign identity number, which is validated as such instead of as
+    /// a TCKN.
+    pub fn set_foreign_identity_number<T: Into<String>>(&mut self, identity_number: T) {
+        self.identity_number = Some(identity_number.into());
+        self.identity_number_type = IdentityNumberType::Foreign;
     }
 
     pub fn set_tax_number<T: Into<String>>(&mut self, tax_number: T) {
@@ -106,7 +135,11 @@ impl CreateSubMerchantRequest {
         self.sub_merchant_external_id = Some(sub_merchant_external_id.into());
     }
 
-    pub fn set_sub_merchant_type<T: Into<String>>(&mut self, sub_merchant_type: T) {
+    pub fn set_sub_merchant_type(&mut self, sub_merchant_type: SubMerchantType) {
+        self.sub_merchant_type = Some(sub_merchant_type.value().to_string());
+    }
+
+    pub fn set_sub_merchant_type_raw<T: Into<String>>(&mut self, sub_merchant_type: T) {
         self.sub_merchant_type = Some(sub_merchant_type.into());
     }
 
@@ -157,32 +190,6 @@ impl CreateSubMerchantRequest {
     }
 }
 
-impl PKISerialize for CreateSubMerchantRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("gsmNumber", self.gsm_number.as_ref());
-        ser.append_option("address", self.address.as_ref());
-        ser.append_option("iban", self.iban.as_ref());
-        ser.append_option("taxOffice", self.tax_office.as_ref());
-        ser.append_option("contactName", self.contact_name.as_ref());
-        ser.append_option("contactSurname", self.contact_surname.as_ref());
-        ser.append_option("legalCompanyTitle", self.legal_company_title.as_ref());
-        ser.append_option("swiftCode", self.swift_code.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option(
-            "subMerchantExternalId",
-            self.sub_merchant_external_id.as_ref(),
-        );
-        ser.append_option("identityNumber", self.identity_number.as_ref());
-        ser.append_option("taxNumber", self.tax_number.as_ref());
-        ser.append_option("subMerchantType", self.sub_merchant_type.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateSubMerchantRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -196,6 +203,166 @@ impl std::ops::DerefMut for CreateSubMerchantRequest {
     }
 }
 
+impl Validate for CreateSubMerchantRequest {
+    /// Checks the IBAN, contact details and the identity and tax numbers required for the sub
+    /// merchant type.
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.iban("iban", self.iban.as_ref());
+        validator.phone_number("gsmNumber", self.gsm_number.as_ref());
+        validator.email("email", self.email.as_ref());
+        validator.max_length("name", self.name.as_ref(), MAX_NAME_LENGTH);
+        validator.max_length("contactName", self.contact_name.as_ref(), MAX_NAME_LENGTH);
+        validator.max_length(
+            "contactSurname",
+            self.contact_surname.as_ref(),
+            MAX_NAME_LENGTH,
+        );
+        let sub_merchant_type =
+            validator.required("subMerchantType", self.sub_merchant_type.as_ref());
+        let company = sub_merchant_type.is_some_and(|t| t != SubMerchantType::Personal.value());
+        let limited_company = sub_merchant_type
+            .is_some_and(|t| t == SubMerchantType::LimitedOrJointStockCompany.value());
+        if !limited_company || self.identity_number.is_some() {
+            validator.identity_number(
+                "identityNumber",
+                self.identity_number.as_ref(),
+                self.identity_number_type,
+            );
+        }
+        if company {
+            validator.required("taxOffice", self.tax_office.as_ref());
+            validator.required("legalCompanyTitle", self.legal_company_title.as_ref());
+        }
+        if limited_company || self.tax_number.is_some() {
+            validator.tax_number("taxNumber", self.tax_number.as_ref());
+        }
+    }
+}
+
+request_builder! {
+    /// Builds a `CreateSubMerchantRequest`. The fields iyzico requires depend on the sub merchant
+    /// type, so there is one `build` method per type which also sets the type.
+    pub struct CreateSubMerchantRequestBuilder for CreateSubMerchantRequest {
+        required {
+            HasName {
+                name(impl Into<String>) => set_name,
+            },
+            HasEmail {
+                email(impl Into<String>) => set_email,
+            },
+            HasGsmNumber {
+                gsm_number(impl Into<String>) => set_gsm_number,
+            },
+            HasAddress {
+                address(impl Into<String>) => set_address,
+            },
+            HasIban {
+                iban(impl Into<String>) => set_iban,
+            },
+            HasExternalId {
+                sub_merchant_external_id(impl Into<String>) => set_sub_merchant_external_id,
+            },
+            HasContactName {
+                contact_name(impl Into<String>) => set_contact_name,
+            },
+            HasContactSurname {
+                contact_surname(impl Into<String>) => set_contact_surname,
+            },
+            HasIdentityNumber {
+                identity_number(impl Into<String>) => set_identity_number,
+            },
+            HasTaxOffice {
+                tax_office(impl Into<String>) => set_tax_office,
+            },
+            HasLegalCompanyTitle {
+                legal_company_title(impl Into<String>) => set_legal_company_title,
+            },
+            HasTaxNumber {
+                tax_number(impl Into<String>) => set_tax_number,
+            },
+        }
+        optional {
+            swift_code(impl Into<String>) => set_swift_code,
+            currency(Currency) => set_currency,
+        }
+    }
+}
+
+impl<HasTaxOffice, HasLegalCompanyTitle, HasTaxNumber>
+    CreateSubMerchantRequestBuilder<
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        HasTaxOffice,
+        HasLegalCompanyTitle,
+        HasTaxNumber,
+    >
+{
+    /// Builds a request for a `SubMerchantType::Personal` sub merchant, which needs a contact
+    /// name, surname and identity number.
+    pub fn build_personal(mut self) -> CreateSubMerchantRequest {
+        self.request
+            .set_sub_merchant_type(SubMerchantType::Personal);
+        self.request
+    }
+}
+
+impl<HasContactName, HasContactSurname, HasTaxNumber>
+    CreateSubMerchantRequestBuilder<
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        HasContactName,
+        HasContactSurname,
+        Set,
+        Set,
+        Set,
+        HasTaxNumber,
+    >
+{
+    /// Builds a request for a `SubMerchantType::PrivateCompany` sub merchant, which needs a tax
+    /// office, legal company title and identity number.
+    pub fn build_private_company(mut self) -> CreateSubMerchantRequest {
+        self.request
+            .set_sub_merchant_type(SubMerchantType::PrivateCompany);
+        self.request
+    }
+}
+
+impl<HasContactName, HasContactSurname, HasIdentityNumber>
+    CreateSubMerchantRequestBuilder<
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        Set,
+        HasContactName,
+        HasContactSurname,
+        HasIdentityNumber,
+        Set,
+        Set,
+        Set,
+    >
+{
+    /// Builds a request for a `SubMerchantType::LimitedOrJointStockCompany` sub merchant, which
+    /// needs a tax office, tax number and legal company title.
+    pub fn build_limited_or_joint_stock_company(mut self) -> CreateSubMerchantRequest {
+        self.request
+            .set_sub_merchant_type(SubMerchantType::LimitedOrJointStockCompany);
+        self.request
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UpdateSubMerchantRequest {
@@ -224,11 +391,11 @@ pub struct UpdateSubMerchantRequest {
 
     currency: Option<String>,
 
+    sub_merchant_key: Option<String>,
+
     identity_number: Option<String>,
 
     tax_number: Option<String>,
-
-    sub_merchant_key: Option<String>,
 }
 
 impl UpdateSubMerchantRequest {
@@ -241,19 +408,22 @@ impl UpdateSubMerchantRequest {
     }
 
     pub fn set_email<T: Into<String>>(&mut self, email: T) {
-        self.email = Some(email.into());
+        self.email = Some(email.into().trim().to_string());
     }
 
+    /// Sets the phone number in E.164, e.g. `+905350000000`, if it can be normalized.
     pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
-        self.gsm_number = Some(gsm_number.into());
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
     }
 
     pub fn set_address<T: Into<String>>(&mut self, address: T) {
         self.address = Some(address.into());
     }
 
+    /// Sets the IBAN without whitespace and in upper case.
     pub fn set_iban<T: Into<String>>(&mut self, iban: T) {
-        self.iban = Some(iban.into());
+        self.iban = Some(Iban::normalize(&iban.into()));
     }
 
     pub fn set_tax_office<T: Into<String>>(&mut self, tax_office: T) {
@@ -276,7 +446,11 @@ impl UpdateSubMerchantRequest {
         self.swift_code = Some(swift_code.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -336,28 +510,6 @@ impl UpdateSubMerchantRequest {
     }
 }
 
-impl PKISerialize for UpdateSubMerchantRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("name", self.name.as_ref());
-        ser.append_option("email", self.email.as_ref());
-        ser.append_option("gsmNumber", self.gsm_number.as_ref());
-        ser.append_option("address", self.address.as_ref());
-        ser.append_option("iban", self.iban.as_ref());
-        ser.append_option("taxOffice", self.tax_office.as_ref());
-        ser.append_option("contactName", self.contact_name.as_ref());
-        ser.append_option("contactSurname", self.contact_surname.as_ref());
-        ser.append_option("legalCompanyTitle", self.legal_company_title.as_ref());
-        ser.append_option("swiftCode", self.swift_code.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option("subMerchantKey", self.sub_merchant_key.as_ref());
-        ser.append_option("identityNumber", self.identity_number.as_ref());
-        ser.append_option("taxNumber", self.tax_number.as_ref());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for UpdateSubMerchantRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -371,6 +523,20 @@ impl std::ops::DerefMut for UpdateSubMerchantRequest {
     }
 }
 
+impl Validate for UpdateSubMerchantRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("subMerchantKey", self.sub_merchant_key.as_ref());
+        if self.iban.is_some() {
+            validator.iban("iban", self.iban.as_ref());
+        }
+        if self.tax_number.is_some() {
+            validator.tax_number("taxNumber", self.tax_number.as_ref());
+        }
+        validator.phone_number("gsmNumber", self.gsm_number.as_ref());
+        validator.email("email", self.email.as_ref());
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RetrieveSubMerchantRequest {
@@ -394,18 +560,6 @@ impl RetrieveSubMerchantRequest {
     }
 }
 
-impl PKISerialize for RetrieveSubMerchantRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option(
-            "subMerchantExternalId",
-            self.sub_merchant_external_id.as_ref(),
-        );
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveSubMerchantRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -442,36 +596,167 @@ impl CreateApprovalRequest {
     }
 }
 
-impl PKISerialize for CreateApprovalRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentTransactionId", self.payment_transaction_id.as_ref());
-        Option::from(ser.build(true))
+impl std::ops::Deref for CreateApprovalRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateApprovalRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
     }
 }
 
-impl std::ops::Deref for CreateApprovalRequest {
+/// Moves an amount between the merchant and one of its sub-merchants.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCrossBookingRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    sub_merchant_key: Option<String>,
+
+    #[serde(serialize_with = "serialize_price")]
+    price: Option<BigDecimal>,
+
+    reason: Option<String>,
+
+    currency: Option<String>,
+}
+
+impl CreateCrossBookingRequest {
+    pub fn new() -> Self {
+        CreateCrossBookingRequest::default()
+    }
+
+    pub fn set_sub_merchant_key<T: Into<String>>(&mut self, sub_merchant_key: T) {
+        self.sub_merchant_key = Some(sub_merchant_key.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_reason<T: Into<String>>(&mut self, reason: T) {
+        self.reason = Some(reason.into());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn sub_merchant_key(&self) -> Option<&String> {
+        self.sub_merchant_key.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn reason(&self) -> Option<&String> {
+        self.reason.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateCrossBookingRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
         &self.request
     }
 }
 
-impl std::ops::DerefMut for CreateApprovalRequest {
+impl std::ops::DerefMut for CreateCrossBookingRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateCrossBookingRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("subMerchantKey", self.sub_merchant_key.as_ref());
+        if let Some(price) = validator.required("price", self.price.as_ref()) {
+            if price <= &BigDecimal::zero() {
+                validator.add("price", "must be greater than 0");
+            }
+        }
+        validator.required("currency", self.currency.as_ref());
+    }
+}
+
+/// Keeps the payouts of a sub merchant as iyzico balance instead of sending them to its iban.
+/// Without a `subMerchantKey` the settlement of the merchant itself is switched. The change
+/// is confirmed on the page returned by `SettlementToBalance::create`, which then redirects
+/// to the callback url.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSettlementToBalanceRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    sub_merchant_key: Option<String>,
+
+    callback_url: Option<String>,
+}
+
+impl CreateSettlementToBalanceRequest {
+    pub fn new() -> Self {
+        CreateSettlementToBalanceRequest::default()
+    }
+
+    pub fn set_sub_merchant_key<T: Into<String>>(&mut self, sub_merchant_key: T) {
+        self.sub_merchant_key = Some(sub_merchant_key.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn sub_merchant_key(&self) -> Option<&String> {
+        self.sub_merchant_key.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateSettlementToBalanceRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateSettlementToBalanceRequest {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.request
     }
 }
 
+impl Validate for CreateSettlementToBalanceRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if self.sub_merchant_key.is_none() && self.callback_url.is_none() {
+            validator.add("subMerchantKey", "or callbackUrl is required");
+        }
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateApmInitializeRequest {
     #[serde(flatten)]
     request: Request,
 
+    #[serde(serialize_with = "serialize_price")]
     price: Option<BigDecimal>,
 
+    #[serde(serialize_with = "serialize_price")]
     paid_price: Option<BigDecimal>,
 
     payment_channel: Option<String>,
@@ -520,11 +805,19 @@ impl CreateApmInitializeRequest {
         self.paid_price = Some(paid_price.into());
     }
 
-    pub fn set_payment_channel<T: Into<String>>(&mut self, payment_channel: T) {
+    pub fn set_payment_channel(&mut self, payment_channel: PaymentChannel) {
+        self.payment_channel = Some(payment_channel.value().to_string());
+    }
+
+    pub fn set_payment_channel_raw<T: Into<String>>(&mut self, payment_channel: T) {
         self.payment_channel = Some(payment_channel.into());
     }
 
-    pub fn set_payment_group<T: Into<String>>(&mut self, payment_group: T) {
+    pub fn set_payment_group(&mut self, payment_group: PaymentGroup) {
+        self.payment_group = Some(payment_group.value().to_string());
+    }
+
+    pub fn set_payment_group_raw<T: Into<String>>(&mut self, payment_group: T) {
         self.payment_group = Some(payment_group.into());
     }
 
@@ -532,7 +825,11 @@ impl CreateApmInitializeRequest {
         self.payment_source = Some(payment_source.into());
     }
 
-    pub fn set_currency<T: Into<String>>(&mut self, currency: T) {
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
         self.currency = Some(currency.into());
     }
 
@@ -640,35 +937,6 @@ impl CreateApmInitializeRequest {
     }
 }
 
-impl PKISerialize for CreateApmInitializeRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_price_option("price", self.price.as_ref());
-        ser.append_price_option("paidPrice", self.paid_price.as_ref());
-        ser.append_option("paymentChannel", self.payment_channel.as_ref());
-        ser.append_option("paymentGroup", self.payment_group.as_ref());
-        ser.append_option("paymentSource", self.payment_source.as_ref());
-        ser.append_option("currency", self.currency.as_ref());
-        ser.append_option("merchantOrderId", self.merchant_order_id.as_ref());
-        ser.append_option("countryCode", self.country_code.as_ref());
-        ser.append_option("accountHolderName", self.account_holder_name.as_ref());
-        ser.append_option("merchantCallbackUrl", self.merchant_callback_url.as_ref());
-        ser.append_option("merchantErrorUrl", self.merchant_error_url.as_ref());
-        ser.append_option(
-            "merchantNotificationUrl",
-            self.merchant_notification_url.as_ref(),
-        );
-        ser.append_option("apmType", self.apm_type.as_ref());
-        ser.append_option("basketId", self.basket_id.as_ref());
-        ser.append_option("buyer", self.buyer.serialize());
-        ser.append_option("shippingAddress", self.shipping_address.serialize());
-        ser.append_option("billingAddress", self.billing_address.serialize());
-        ser.append_option("basketItems", self.basket_items.serialize());
-        Option::from(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for CreateApmInitializeRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
@@ -705,15 +973,6 @@ impl RetrieveApmRequest {
     }
 }
 
-impl PKISerialize for RetrieveApmRequest {
-    fn serialize(&self) -> Option<String> {
-        let mut ser = RequestStringBuilder::new();
-        ser.append_option_val(self.request.serialize());
-        ser.append_option("paymentId", self.payment_id.to_owned());
-        Some(ser.build(true))
-    }
-}
-
 impl std::ops::Deref for RetrieveApmRequest {
     type Target = Request;
     fn deref(&self) -> &Self::Target {
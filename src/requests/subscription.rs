@@ -0,0 +1,868 @@
+use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+use chrono::NaiveDateTime;
+
+use crate::model::date::{deserialize_date_time_field, DateTimeField};
+use crate::model::normalize_phone_number;
+use crate::model::Address;
+use crate::model::Currency;
+use crate::model::IntoDateTime;
+use crate::model::PaymentCard;
+use crate::model::PaymentInterval;
+use crate::model::PlanPaymentType;
+use crate::model::SubscriptionStatus;
+use crate::model::SubscriptionUpgradePeriod;
+use crate::requests::request::to_query_string;
+use crate::requests::Request;
+use crate::requests::RequestQueryParams;
+use crate::requests::Validate;
+use crate::requests::Validator;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionProductRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    name: Option<String>,
+
+    description: Option<String>,
+}
+
+impl CreateSubscriptionProductRequest {
+    pub fn new() -> Self {
+        CreateSubscriptionProductRequest::default()
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = Some(description.into());
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateSubscriptionProductRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateSubscriptionProductRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateSubscriptionProductRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("name", self.name.as_ref());
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubscriptionProductRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    name: Option<String>,
+
+    description: Option<String>,
+}
+
+impl UpdateSubscriptionProductRequest {
+    pub fn new() -> Self {
+        UpdateSubscriptionProductRequest::default()
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_description<T: Into<String>>(&mut self, description: T) {
+        self.description = Some(description.into());
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn description(&self) -> Option<&String> {
+        self.description.as_ref()
+    }
+}
+
+impl std::ops::Deref for UpdateSubscriptionProductRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for UpdateSubscriptionProductRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for UpdateSubscriptionProductRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("name", self.name.as_ref());
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePricingPlanRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    name: Option<String>,
+
+    price: Option<BigDecimal>,
+
+    #[serde(rename = "currencyCode")]
+    currency: Option<String>,
+
+    payment_interval: Option<String>,
+
+    payment_interval_count: Option<u32>,
+
+    trial_period_days: Option<u32>,
+
+    recurrence_count: Option<u32>,
+
+    plan_payment_type: Option<String>,
+}
+
+impl CreatePricingPlanRequest {
+    pub fn new() -> Self {
+        CreatePricingPlanRequest {
+            plan_payment_type: Some(PlanPaymentType::Recurring.value().to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_price<T: Into<BigDecimal>>(&mut self, price: T) {
+        self.price = Some(price.into());
+    }
+
+    pub fn set_currency(&mut self, currency: Currency) {
+        self.currency = Some(currency.value().to_string());
+    }
+
+    pub fn set_currency_raw<T: Into<String>>(&mut self, currency: T) {
+        self.currency = Some(currency.into());
+    }
+
+    pub fn set_payment_interval(&mut self, payment_interval: PaymentInterval) {
+        self.payment_interval = Some(payment_interval.value().to_string());
+    }
+
+    pub fn set_payment_interval_count<T: Into<u32>>(&mut self, payment_interval_count: T) {
+        self.payment_interval_count = Some(payment_interval_count.into());
+    }
+
+    pub fn set_trial_period_days<T: Into<u32>>(&mut self, trial_period_days: T) {
+        self.trial_period_days = Some(trial_period_days.into());
+    }
+
+    pub fn set_recurrence_count<T: Into<u32>>(&mut self, recurrence_count: T) {
+        self.recurrence_count = Some(recurrence_count.into());
+    }
+
+    pub fn set_plan_payment_type(&mut self, plan_payment_type: PlanPaymentType) {
+        self.plan_payment_type = Some(plan_payment_type.value().to_string());
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn price(&self) -> Option<&BigDecimal> {
+        self.price.as_ref()
+    }
+    pub fn currency(&self) -> Option<&String> {
+        self.currency.as_ref()
+    }
+    pub fn payment_interval(&self) -> Option<&String> {
+        self.payment_interval.as_ref()
+    }
+    pub fn payment_interval_count(&self) -> Option<&u32> {
+        self.payment_interval_count.as_ref()
+    }
+    pub fn trial_period_days(&self) -> Option<&u32> {
+        self.trial_period_days.as_ref()
+    }
+    pub fn recurrence_count(&self) -> Option<&u32> {
+        self.recurrence_count.as_ref()
+    }
+    pub fn plan_payment_type(&self) -> Option<&String> {
+        self.plan_payment_type.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreatePricingPlanRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreatePricingPlanRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreatePricingPlanRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("name", self.name.as_ref());
+        if let Some(price) = validator.required("price", self.price.as_ref()) {
+            if price <= &BigDecimal::zero() {
+                validator.add("price", "must be greater than 0");
+            }
+        }
+        validator.required("currencyCode", self.currency.as_ref());
+        validator.required("paymentInterval", self.payment_interval.as_ref());
+        if self.payment_interval_count == Some(0) {
+            validator.add("paymentIntervalCount", "must be greater than 0");
+        }
+    }
+}
+
+/// Only the name and the trial period of a pricing plan can be changed after it is created.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePricingPlanRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    name: Option<String>,
+
+    trial_period_days: Option<u32>,
+}
+
+impl UpdatePricingPlanRequest {
+    pub fn new() -> Self {
+        UpdatePricingPlanRequest::default()
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_trial_period_days<T: Into<u32>>(&mut self, trial_period_days: T) {
+        self.trial_period_days = Some(trial_period_days.into());
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn trial_period_days(&self) -> Option<&u32> {
+        self.trial_period_days.as_ref()
+    }
+}
+
+impl std::ops::Deref for UpdatePricingPlanRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for UpdatePricingPlanRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for UpdatePricingPlanRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("name", self.name.as_ref());
+    }
+}
+
+/// A subscription customer, sent when it is created or updated and when a subscription is
+/// initialized for a new customer.
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionCustomerDetails {
+    name: Option<String>,
+
+    surname: Option<String>,
+
+    identity_number: Option<String>,
+
+    email: Option<String>,
+
+    gsm_number: Option<String>,
+
+    billing_address: Option<Address>,
+
+    shipping_address: Option<Address>,
+}
+
+impl SubscriptionCustomerDetails {
+    pub fn new() -> Self {
+        SubscriptionCustomerDetails::default()
+    }
+
+    pub fn set_name<T: Into<String>>(&mut self, name: T) {
+        self.name = Some(name.into());
+    }
+
+    pub fn set_surname<T: Into<String>>(&mut self, surname: T) {
+        self.surname = Some(surname.into());
+    }
+
+    pub fn set_identity_number<T: Into<String>>(&mut self, identity_number: T) {
+        self.identity_number = Some(identity_number.into());
+    }
+
+    pub fn set_email<T: Into<String>>(&mut self, email: T) {
+        self.email = Some(email.into().trim().to_string());
+    }
+
+    pub fn set_gsm_number<T: Into<String>>(&mut self, gsm_number: T) {
+        let gsm_number = gsm_number.into();
+        self.gsm_number = Some(normalize_phone_number(&gsm_number).unwrap_or(gsm_number));
+    }
+
+    pub fn set_billing_address<T: Into<Address>>(&mut self, billing_address: T) {
+        self.billing_address = Some(billing_address.into());
+    }
+
+    pub fn set_shipping_address<T: Into<Address>>(&mut self, shipping_address: T) {
+        self.shipping_address = Some(shipping_address.into());
+    }
+
+    pub fn name(&self) -> Option<&String> {
+        self.name.as_ref()
+    }
+    pub fn surname(&self) -> Option<&String> {
+        self.surname.as_ref()
+    }
+    pub fn identity_number(&self) -> Option<&String> {
+        self.identity_number.as_ref()
+    }
+    pub fn email(&self) -> Option<&String> {
+        self.email.as_ref()
+    }
+    pub fn gsm_number(&self) -> Option<&String> {
+        self.gsm_number.as_ref()
+    }
+    pub fn billing_address(&self) -> Option<&Address> {
+        self.billing_address.as_ref()
+    }
+    pub fn shipping_address(&self) -> Option<&Address> {
+        self.shipping_address.as_ref()
+    }
+}
+
+impl Validate for SubscriptionCustomerDetails {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("name", self.name.as_ref());
+        validator.required("surname", self.surname.as_ref());
+        validator.required("email", self.email.as_ref());
+        validator.email("email", self.email.as_ref());
+        validator.phone_number("gsmNumber", self.gsm_number.as_ref());
+        validator.required("billingAddress", self.billing_address.as_ref());
+        validator.address("billingAddress", self.billing_address.as_ref());
+        validator.address("shippingAddress", self.shipping_address.as_ref());
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscriptionCustomerRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    #[serde(flatten)]
+    customer: SubscriptionCustomerDetails,
+}
+
+impl SubscriptionCustomerRequest {
+    pub fn new() -> Self {
+        SubscriptionCustomerRequest::default()
+    }
+
+    pub fn set_customer<T: Into<SubscriptionCustomerDetails>>(&mut self, customer: T) {
+        self.customer = customer.into();
+    }
+
+    pub fn customer(&self) -> &SubscriptionCustomerDetails {
+        &self.customer
+    }
+}
+
+impl std::ops::Deref for SubscriptionCustomerRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for SubscriptionCustomerRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for SubscriptionCustomerRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        self.customer.validate_into(validator);
+    }
+}
+
+/// Starts a subscription for a new customer, either directly with a card through
+/// `Subscription::initialize` or through `SubscriptionCheckoutForm::initialize` with a callback
+/// url.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionInitializeRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    pricing_plan_reference_code: Option<String>,
+
+    subscription_initial_status: Option<String>,
+
+    customer: Option<SubscriptionCustomerDetails>,
+
+    payment_card: Option<PaymentCard>,
+
+    callback_url: Option<String>,
+}
+
+impl CreateSubscriptionInitializeRequest {
+    pub fn new() -> Self {
+        CreateSubscriptionInitializeRequest::default()
+    }
+
+    pub fn set_pricing_plan_reference_code<T: Into<String>>(
+        &mut self,
+        pricing_plan_reference_code: T,
+    ) {
+        self.pricing_plan_reference_code = Some(pricing_plan_reference_code.into());
+    }
+
+    /// `SubscriptionStatus::Active` or `SubscriptionStatus::Pending`, the latter waits for
+    /// `Subscription::activate`.
+    pub fn set_subscription_initial_status(
+        &mut self,
+        subscription_initial_status: SubscriptionStatus,
+    ) {
+        self.subscription_initial_status = Some(subscription_initial_status.value().to_string());
+    }
+
+    pub fn set_customer<T: Into<SubscriptionCustomerDetails>>(&mut self, customer: T) {
+        self.customer = Some(customer.into());
+    }
+
+    pub fn set_payment_card<T: Into<PaymentCard>>(&mut self, payment_card: T) {
+        self.payment_card = Some(payment_card.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn pricing_plan_reference_code(&self) -> Option<&String> {
+        self.pricing_plan_reference_code.as_ref()
+    }
+    pub fn subscription_initial_status(&self) -> Option<&String> {
+        self.subscription_initial_status.as_ref()
+    }
+    pub fn customer(&self) -> Option<&SubscriptionCustomerDetails> {
+        self.customer.as_ref()
+    }
+    pub fn payment_card(&self) -> Option<&PaymentCard> {
+        self.payment_card.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateSubscriptionInitializeRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateSubscriptionInitializeRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateSubscriptionInitializeRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required(
+            "pricingPlanReferenceCode",
+            self.pricing_plan_reference_code.as_ref(),
+        );
+        if let Some(customer) = validator.required("customer", self.customer.as_ref()) {
+            validator.nested("customer", |validator| customer.validate_into(validator));
+        }
+        if let Some(payment_card) = self.payment_card.as_ref() {
+            validator.nested("paymentCard", |validator| {
+                payment_card.validate_into(validator)
+            });
+        }
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeSubscriptionRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    new_pricing_plan_reference_code: Option<String>,
+
+    upgrade_period: Option<String>,
+
+    use_trial: Option<bool>,
+
+    reset_recurrence_count: Option<bool>,
+}
+
+impl UpgradeSubscriptionRequest {
+    pub fn new() -> Self {
+        UpgradeSubscriptionRequest::default()
+    }
+
+    pub fn set_new_pricing_plan_reference_code<T: Into<String>>(
+        &mut self,
+        new_pricing_plan_reference_code: T,
+    ) {
+        self.new_pricing_plan_reference_code = Some(new_pricing_plan_reference_code.into());
+    }
+
+    pub fn set_upgrade_period(&mut self, upgrade_period: SubscriptionUpgradePeriod) {
+        self.upgrade_period = Some(upgrade_period.value().to_string());
+    }
+
+    pub fn set_use_trial<T: Into<bool>>(&mut self, use_trial: T) {
+        self.use_trial = Some(use_trial.into());
+    }
+
+    pub fn set_reset_recurrence_count<T: Into<bool>>(&mut self, reset_recurrence_count: T) {
+        self.reset_recurrence_count = Some(reset_recurrence_count.into());
+    }
+
+    pub fn new_pricing_plan_reference_code(&self) -> Option<&String> {
+        self.new_pricing_plan_reference_code.as_ref()
+    }
+    pub fn upgrade_period(&self) -> Option<&String> {
+        self.upgrade_period.as_ref()
+    }
+    pub fn use_trial(&self) -> Option<&bool> {
+        self.use_trial.as_ref()
+    }
+    pub fn reset_recurrence_count(&self) -> Option<&bool> {
+        self.reset_recurrence_count.as_ref()
+    }
+}
+
+impl std::ops::Deref for UpgradeSubscriptionRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for UpgradeSubscriptionRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for UpgradeSubscriptionRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required(
+            "newPricingPlanReferenceCode",
+            self.new_pricing_plan_reference_code.as_ref(),
+        );
+        validator.required("upgradePeriod", self.upgrade_period.as_ref());
+    }
+}
+
+/// Retries the payment of a failed subscription order.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrySubscriptionPaymentRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    reference_code: Option<String>,
+}
+
+impl RetrySubscriptionPaymentRequest {
+    pub fn new() -> Self {
+        RetrySubscriptionPaymentRequest::default()
+    }
+
+    /// Reference code of the failed order.
+    pub fn set_reference_code<T: Into<String>>(&mut self, reference_code: T) {
+        self.reference_code = Some(reference_code.into());
+    }
+
+    pub fn reference_code(&self) -> Option<&String> {
+        self.reference_code.as_ref()
+    }
+}
+
+impl std::ops::Deref for RetrySubscriptionPaymentRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for RetrySubscriptionPaymentRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for RetrySubscriptionPaymentRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        validator.required("referenceCode", self.reference_code.as_ref());
+    }
+}
+
+/// Starts the checkout form a customer updates the card of their subscriptions with. With a
+/// subscription reference code only the card of that subscription is updated.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateSubscriptionCardUpdateRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    customer_reference_code: Option<String>,
+
+    subscription_reference_code: Option<String>,
+
+    callback_url: Option<String>,
+}
+
+impl CreateSubscriptionCardUpdateRequest {
+    pub fn new() -> Self {
+        CreateSubscriptionCardUpdateRequest::default()
+    }
+
+    pub fn set_customer_reference_code<T: Into<String>>(&mut self, customer_reference_code: T) {
+        self.customer_reference_code = Some(customer_reference_code.into());
+    }
+
+    pub fn set_subscription_reference_code<T: Into<String>>(
+        &mut self,
+        subscription_reference_code: T,
+    ) {
+        self.subscription_reference_code = Some(subscription_reference_code.into());
+    }
+
+    pub fn set_callback_url<T: Into<String>>(&mut self, callback_url: T) {
+        self.callback_url = Some(callback_url.into());
+    }
+
+    pub fn customer_reference_code(&self) -> Option<&String> {
+        self.customer_reference_code.as_ref()
+    }
+    pub fn subscription_reference_code(&self) -> Option<&String> {
+        self.subscription_reference_code.as_ref()
+    }
+    pub fn callback_url(&self) -> Option<&String> {
+        self.callback_url.as_ref()
+    }
+}
+
+impl std::ops::Deref for CreateSubscriptionCardUpdateRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for CreateSubscriptionCardUpdateRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl Validate for CreateSubscriptionCardUpdateRequest {
+    fn validate_into(&self, validator: &mut Validator) {
+        if self.subscription_reference_code.is_none() {
+            validator.required(
+                "customerReferenceCode",
+                self.customer_reference_code.as_ref(),
+            );
+        }
+        validator.required("callbackUrl", self.callback_url.as_ref());
+    }
+}
+
+/// Filters and paging of `Subscription::search`, sent as query parameters.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchSubscriptionsRequest {
+    #[serde(flatten)]
+    request: Request,
+
+    subscription_reference_code: Option<String>,
+
+    customer_reference_code: Option<String>,
+
+    pricing_plan_reference_code: Option<String>,
+
+    parent_reference_code: Option<String>,
+
+    subscription_status: Option<String>,
+
+    #[serde(default, deserialize_with = "deserialize_date_time_field")]
+    start_date: Option<DateTimeField>,
+
+    #[serde(default, deserialize_with = "deserialize_date_time_field")]
+    end_date: Option<DateTimeField>,
+
+    page: Option<u32>,
+
+    count: Option<u32>,
+}
+
+impl SearchSubscriptionsRequest {
+    pub fn new() -> Self {
+        SearchSubscriptionsRequest::default()
+    }
+
+    pub fn set_subscription_reference_code<T: Into<String>>(
+        &mut self,
+        subscription_reference_code: T,
+    ) {
+        self.subscription_reference_code = Some(subscription_reference_code.into());
+    }
+
+    pub fn set_customer_reference_code<T: Into<String>>(&mut self, customer_reference_code: T) {
+        self.customer_reference_code = Some(customer_reference_code.into());
+    }
+
+    pub fn set_pricing_plan_reference_code<T: Into<String>>(
+        &mut self,
+        pricing_plan_reference_code: T,
+    ) {
+        self.pricing_plan_reference_code = Some(pricing_plan_reference_code.into());
+    }
+
+    pub fn set_parent_reference_code<T: Into<String>>(&mut self, parent_reference_code: T) {
+        self.parent_reference_code = Some(parent_reference_code.into());
+    }
+
+    pub fn set_subscription_status(&mut self, subscription_status: SubscriptionStatus) {
+        self.subscription_status = Some(subscription_status.value().to_string());
+    }
+
+    pub fn set_start_date<T: IntoDateTime>(&mut self, start_date: T) {
+        self.start_date = DateTimeField::from(start_date);
+    }
+
+    pub fn set_end_date<T: IntoDateTime>(&mut self, end_date: T) {
+        self.end_date = DateTimeField::from(end_date);
+    }
+
+    pub fn set_page<T: Into<u32>>(&mut self, page: T) {
+        self.page = Some(page.into());
+    }
+
+    pub fn set_count<T: Into<u32>>(&mut self, count: T) {
+        self.count = Some(count.into());
+    }
+
+    pub fn subscription_reference_code(&self) -> Option<&String> {
+        self.subscription_reference_code.as_ref()
+    }
+    pub fn customer_reference_code(&self) -> Option<&String> {
+        self.customer_reference_code.as_ref()
+    }
+    pub fn pricing_plan_reference_code(&self) -> Option<&String> {
+        self.pricing_plan_reference_code.as_ref()
+    }
+    pub fn parent_reference_code(&self) -> Option<&String> {
+        self.parent_reference_code.as_ref()
+    }
+    pub fn subscription_status(&self) -> Option<&String> {
+        self.subscription_status.as_ref()
+    }
+    pub fn start_date(&self) -> Option<&NaiveDateTime> {
+        self.start_date.as_ref().and_then(DateTimeField::date_time)
+    }
+    pub fn end_date(&self) -> Option<&NaiveDateTime> {
+        self.end_date.as_ref().and_then(DateTimeField::date_time)
+    }
+    pub fn page(&self) -> Option<&u32> {
+        self.page.as_ref()
+    }
+    pub fn count(&self) -> Option<&u32> {
+        self.count.as_ref()
+    }
+}
+
+impl std::ops::Deref for SearchSubscriptionsRequest {
+    type Target = Request;
+    fn deref(&self) -> &Self::Target {
+        &self.request
+    }
+}
+
+impl std::ops::DerefMut for SearchSubscriptionsRequest {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.request
+    }
+}
+
+impl RequestQueryParams for SearchSubscriptionsRequest {
+    fn get_query_params(&self) -> String {
+        let params = [
+            ("conversationId", self.conversation_id().cloned()),
+            ("locale", self.locale().cloned()),
+            (
+                "subscriptionReferenceCode",
+                self.subscription_reference_code.clone(),
+            ),
+            (
+                "customerReferenceCode",
+                self.customer_reference_code.clone(),
+            ),
+            (
+                "pricingPlanReferenceCode",
+                self.pricing_plan_reference_code.clone(),
+            ),
+            ("parentReferenceCode", self.parent_reference_code.clone()),
+            ("subscriptionStatus", self.subscription_status.clone()),
+            (
+                "startDate",
+                self.start_date.as_ref().map(DateTimeField::format),
+            ),
+            ("endDate", self.end_date.as_ref().map(DateTimeField::format)),
+            ("page", self.page.map(|page| page.to_string())),
+            ("count", self.count.map(|count| count.to_string())),
+        ];
+        to_query_string(&params)
+    }
+}
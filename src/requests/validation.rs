@@ -0,0 +1,424 @@
+use std::error::Error;
+use std::fmt;
+
+use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+use chrono::Local;
+use zeroize::Zeroizing;
+
+use crate::model::Address;
+use crate::model::BasketItem;
+use crate::model::Buyer;
+use crate::model::CardAssociation;
+use crate::model::Iban;
+use crate::model::IdentityNumberType;
+use crate::model::{
+    is_expired, is_luhn_valid, normalize_card_number, normalize_expire_month, normalize_expire_year,
+};
+use crate::model::{is_valid_email, normalize_phone_number, parse_ip};
+use crate::model::{is_valid_foreign_identity_number, is_valid_tckn, is_valid_vkn};
+use crate::options::Options;
+
+/// Longest value accepted for names, e.g. buyer, contact and basket item names.
+pub const MAX_NAME_LENGTH: usize = 255;
+
+/// A field that would be rejected by iyzico, with its path in the JSON body, e.g.
+/// `basketItems[1].subMerchantKey`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Violation {
+    path: String,
+
+    message: String,
+}
+
+impl Violation {
+    pub fn new<P: Into<String>, M: Into<String>>(path: P, message: M) -> Self {
+        Violation {
+            path: path.into(),
+            message: message.into(),
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationErrors(Vec<Violation>);
+
+impl ValidationErrors {
+    pub fn violations(&self) -> &[Violation] {
+        &self.0
+    }
+
+    /// Returns the violations of the field at `path`.
+    pub fn of(&self, path: &str) -> Vec<&Violation> {
+        self.0.iter().filter(|v| v.path == path).collect()
+    }
+}
+
+impl From<Violation> for ValidationErrors {
+    fn from(violation: Violation) -> Self {
+        ValidationErrors(vec![violation])
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let violations: Vec<String> = self.0.iter().map(Violation::to_string).collect();
+        write!(f, "invalid request: {}", violations.join(", "))
+    }
+}
+
+impl Error for ValidationErrors {}
+
+/// Collects the violations of a request.
+#[derive(Debug, Default)]
+pub struct Validator {
+    marketplace: bool,
+
+    prefix: Vec<String>,
+
+    violations: Vec<Violation>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Validator::default()
+    }
+
+    /// Validates for a marketplace merchant, where every basket item belongs to a sub merchant.
+    pub fn marketplace() -> Self {
+        Validator {
+            marketplace: true,
+            ..Validator::default()
+        }
+    }
+
+    pub fn is_marketplace(&self) -> bool {
+        self.marketplace
+    }
+
+    pub fn add<P: Into<String>, M: Into<String>>(&mut self, path: P, message: M) {
+        let path = self
+            .prefix
+            .iter()
+            .cloned()
+            .chain(std::iter::once(path.into()))
+            .collect::<Vec<String>>()
+            .join(".");
+        self.violations.push(Violation::new(path, message));
+    }
+
+    /// Runs `validate` with the paths it reports below `prefix`, e.g. `paymentCard.cvc`.
+    pub fn nested<F: FnOnce(&mut Validator)>(&mut self, prefix: &str, validate: F) {
+        self.prefix.push(prefix.to_string());
+        validate(self);
+        self.prefix.pop();
+    }
+
+    pub fn required<'a, T: ?Sized>(&mut self, path: &str, value: Option<&'a T>) -> Option<&'a T> {
+        if value.is_none() {
+            self.add(path, "is required");
+        }
+        value
+    }
+
+    pub fn max_length(&mut self, path: &str, value: Option<&String>, max: usize) {
+        if let Some(value) = value {
+            if value.chars().count() > max {
+                self.add(path, format!("must not be longer than {} characters", max));
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<(), ValidationErrors> {
+        if self.violations.is_empty() {
+            Ok(())
+        } else {
+            Err(ValidationErrors(self.violations))
+        }
+    }
+
+    /// Checks that the basket is not empty, its items are complete and their prices add up to
+    /// `price`.
+    pub fn basket(&mut self, price: Option<&BigDecimal>, basket_items: Option<&Vec<BasketItem>>) {
+        let price = self.required("price", price);
+        let basket_items = match basket_items {
+            Some(basket_items) if !basket_items.is_empty() => basket_items,
+            _ => {
+                self.add("basketItems", "must not be empty");
+                return;
+            }
+        };
+
+        let mut total = Some(BigDecimal::zero());
+        for (index, item) in basket_items.iter().enumerate() {
+            let path = format!("basketItems[{}]", index);
+            self.required(&format!("{}.id", path), item.id());
+            self.required(&format!("{}.name", path), item.name());
+            self.max_length(&format!("{}.name", path), item.name(), MAX_NAME_LENGTH);
+            self.required(&format!("{}.category1", path), item.category1());
+            self.required(&format!("{}.itemType", path), item.item_type());
+            let item_price = self.required(&format!("{}.price", path), item.price());
+            if let Some(item_price) = item_price {
+                if item_price <= &BigDecimal::zero() {
+                    self.add(format!("{}.price", path), "must be greater than 0");
+                }
+            }
+            total = match (total, item_price) {
+                (Some(total), Some(item_price)) => Some(total + item_price),
+                _ => None,
+            };
+            self.sub_merchant(&path, item);
+        }
+
+        if let (Some(price), Some(total)) = (price, total) {
+            if price != &total {
+                self.add(
+                    "price",
+                    format!("must equal the sum of basket item prices ({})", total),
+                );
+            }
+        }
+    }
+
+    /// Checks that the prices set with `BasketItem::set_price_money` are in the currency of the
+    /// request.
+    pub fn basket_currency(
+        &mut self,
+        currency: Option<&String>,
+        basket_items: Option<&Vec<BasketItem>>,
+    ) {
+        let (currency, basket_items) = match (currency, basket_items) {
+            (Some(currency), Some(basket_items)) => (currency, basket_items),
+            _ => return,
+        };
+        for (index, item) in basket_items.iter().enumerate() {
+            if let Some(item_currency) = item.currency() {
+                if item_currency.value() != currency {
+                    self.add(
+                        format!("basketItems[{}].price", index),
+                        format!("must be in the currency of the request ({})", currency),
+                    );
+                }
+            }
+        }
+    }
+
+    pub fn paid_price(&mut self, price: Option<&BigDecimal>, paid_price: Option<&BigDecimal>) {
+        let paid_price = self.required("paidPrice", paid_price);
+        if let (Some(price), Some(paid_price)) = (price, paid_price) {
+            if paid_price < price {
+                self.add("paidPrice", "must not be less than price");
+            }
+        }
+    }
+
+    pub fn installment(&mut self, path: &str, installment: Option<&u8>) {
+        if let Some(installment) = installment {
+            if !(1..=12).contains(installment) {
+                self.add(path, "must be between 1 and 12");
+            }
+        }
+    }
+
+    pub fn buyer(&mut self, buyer: Option<&Buyer>) {
+        if let Some(buyer) = self.required("buyer", buyer) {
+            self.nested("buyer", |validator| buyer.validate_into(validator));
+        }
+    }
+
+    pub fn address(&mut self, path: &str, address: Option<&Address>) {
+        if let Some(address) = address {
+            self.max_length(
+                &format!("{}.contactName", path),
+                address.contact_name(),
+                MAX_NAME_LENGTH,
+            );
+        }
+    }
+
+    /// Checks the digits, check digit and length of a card number and returns the association
+    /// detected from it.
+    pub fn card_number(
+        &mut self,
+        path: &str,
+        card_number: Option<&str>,
+    ) -> Option<CardAssociation> {
+        let card_number = Zeroizing::new(normalize_card_number(self.required(path, card_number)?));
+        if !card_number.chars().all(|c| c.is_ascii_digit()) {
+            self.add(path, "must only contain digits");
+            return None;
+        }
+        let association = CardAssociation::from_card_number(&card_number)
+            .unwrap_or_else(|| CardAssociation::Unknown(String::new()));
+        if !association
+            .card_number_lengths()
+            .contains(&card_number.len())
+        {
+            self.add(path, "has an invalid length");
+        } else if !is_luhn_valid(&card_number) {
+            self.add(path, "has an invalid check digit");
+        }
+        Some(association)
+    }
+
+    /// Checks that the month and year are valid and not in the past.
+    pub fn expiry(&mut self, expire_month: Option<&str>, expire_year: Option<&str>) {
+        let expire_month = self.required("expireMonth", expire_month);
+        let expire_year = self.required("expireYear", expire_year);
+        if let Some(expire_month) = expire_month {
+            if normalize_expire_month(expire_month).is_none() {
+                self.add("expireMonth", "must be a month between 1 and 12");
+            }
+        }
+        if let Some(expire_year) = expire_year {
+            if normalize_expire_year(expire_year).is_none() {
+                self.add("expireYear", "must be a two or four digit year");
+            }
+        }
+        if let (Some(expire_month), Some(expire_year)) = (expire_month, expire_year) {
+            let today = Local::now().date_naive();
+            if is_expired(expire_month, expire_year, today) == Some(true) {
+                self.add("expireYear", "card is expired");
+            }
+        }
+    }
+
+    pub fn cvc(&mut self, path: &str, cvc: Option<&str>, association: Option<&CardAssociation>) {
+        if let Some(cvc) = self.required(path, cvc) {
+            let length = association.map_or(3, CardAssociation::cvc_length);
+            if cvc.len() != length || !cvc.chars().all(|c| c.is_ascii_digit()) {
+                self.add(path, format!("must be {} digits", length));
+            }
+        }
+    }
+
+    pub fn identity_number(
+        &mut self,
+        path: &str,
+        identity_number: Option<&String>,
+        identity_number_type: IdentityNumberType,
+    ) {
+        if let Some(identity_number) = self.required(path, identity_number) {
+            match identity_number_type {
+                IdentityNumberType::Tckn if !is_valid_tckn(identity_number) => {
+                    self.add(path, "is not a valid TCKN")
+                }
+                IdentityNumberType::Foreign
+                    if !is_valid_foreign_identity_number(identity_number) =>
+                {
+                    self.add(path, "must be 5 to 20 letters or digits")
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Accepts a VKN, or a TCKN for sole proprietors who are taxed by their identity number.
+    pub fn tax_number(&mut self, path: &str, tax_number: Option<&String>) {
+        if let Some(tax_number) = self.required(path, tax_number) {
+            if !is_valid_vkn(tax_number) && !is_valid_tckn(tax_number) {
+                self.add(path, "is not a valid VKN");
+            }
+        }
+    }
+
+    pub fn iban(&mut self, path: &str, iban: Option<&String>) {
+        if let Some(iban) = self.required(path, iban) {
+            if let Err(error) = Iban::parse(iban) {
+                self.add(path, error.to_string());
+            }
+        }
+    }
+
+    /// Checks that a phone number can be converted to E.164.
+    pub fn phone_number(&mut self, path: &str, phone_number: Option<&String>) {
+        if let Some(phone_number) = phone_number {
+            if normalize_phone_number(phone_number).is_none() {
+                self.add(path, "is not a valid phone number");
+            }
+        }
+    }
+
+    pub fn email(&mut self, path: &str, email: Option<&String>) {
+        if let Some(email) = email {
+            if !is_valid_email(email) {
+                self.add(path, "is not a valid email address");
+            }
+        }
+    }
+
+    pub fn ip(&mut self, path: &str, ip: Option<&String>) {
+        if let Some(ip) = ip {
+            if parse_ip(ip).is_none() {
+                self.add(path, "is not a valid IPv4 or IPv6 address");
+            }
+        }
+    }
+
+    fn sub_merchant(&mut self, path: &str, item: &BasketItem) {
+        let key_path = format!("{}.subMerchantKey", path);
+        let price_path = format!("{}.subMerchantPrice", path);
+        if self.marketplace {
+            self.required(&key_path, item.sub_merchant_key());
+            self.required(&price_path, item.sub_merchant_price());
+        } else if item.sub_merchant_key().is_some() != item.sub_merchant_price().is_some() {
+            self.add(
+                if item.sub_merchant_key().is_none() {
+                    key_path
+                } else {
+                    price_path.clone()
+                },
+                "is required together with the other sub merchant field",
+            );
+        }
+        if let (Some(sub_merchant_price), Some(price)) = (item.sub_merchant_price(), item.price()) {
+            if sub_merchant_price > price {
+                self.add(price_path, "must not be greater than price");
+            }
+        }
+    }
+}
+
+/// Checks a request locally for mistakes iyzico would reject it for.
+pub trait Validate {
+    fn validate_into(&self, validator: &mut Validator);
+
+    /// Returns every violation of the request.
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut validator = Validator::new();
+        self.validate_into(&mut validator);
+        validator.finish()
+    }
+
+    /// Like `validate`, but also requires the sub merchant of every basket item.
+    fn validate_marketplace(&self) -> Result<(), ValidationErrors> {
+        let mut validator = Validator::marketplace();
+        self.validate_into(&mut validator);
+        validator.finish()
+    }
+
+    /// Validates as configured in `options`, doing nothing unless `validate_requests` is set.
+    fn validate_for(&self, options: &Options) -> Result<(), ValidationErrors> {
+        if !options.validate_requests() {
+            Ok(())
+        } else if options.marketplace() {
+            self.validate_marketplace()
+        } else {
+            self.validate()
+        }
+    }
+}
@@ -8,11 +8,13 @@ use log::debug;
 use rand::distributions::Alphanumeric;
 use rand::{thread_rng, Rng};
 use reqwest::header::{HeaderMap, HeaderValue};
-use uuid::Uuid;
 
 use crate::hash::HashGenerator;
 use crate::hash::IyziAuthV2Generator;
+use crate::model::date::{deserialize_timestamp, serialize_timestamp};
+use crate::options::AuthScheme;
 use crate::options::Options;
+use crate::requests::PKISerialize;
 
 pub const CLIENT_VERSION: &str = env!("CARGO_PKG_VERSION");
 pub const CLIENT_TITLE: &str = env!("CARGO_PKG_NAME");
@@ -35,7 +37,12 @@ pub struct IyzipayResource {
 
     locale: Option<String>,
 
-    system_time: Option<i64>,
+    #[serde(
+        default,
+        serialize_with = "serialize_timestamp",
+        deserialize_with = "deserialize_timestamp"
+    )]
+    system_time: Option<DateTime<Utc>>,
 
     conversation_id: Option<String>,
 }
@@ -45,18 +52,28 @@ impl IyzipayResource {
         IyzipayResource::default()
     }
 
+    /// Signs `req` with the scheme `options` selects for the endpoint at `uri`: the PKI string for
+    /// IYZWS, the URI path plus the JSON `request` body for IYZWSv2.
+    pub fn get_http_headers_for<T: PKISerialize + ?Sized>(
+        uri: &str,
+        request: &str,
+        req: &T,
+        options: &Options,
+    ) -> HeaderMap {
+        let uri_path = IyziAuthV2Generator::get_uri_path(uri);
+        match options.endpoint_auth_scheme(uri_path.as_str()) {
+            AuthScheme::V1 => {
+                IyzipayResource::get_http_headers(req.serialize().unwrap_or_default(), options)
+            }
+            AuthScheme::V2 => {
+                IyzipayResource::get_http_headers_v2(uri.to_owned(), request.to_owned(), options)
+            }
+        }
+    }
+
     pub fn get_http_headers(request: String, options: &Options) -> HeaderMap {
         let mut headers = HeaderMap::new();
-        let mut rng = thread_rng();
-        let random_alpanumeric: String = iter::repeat(())
-            .map(|()| rng.sample(Alphanumeric))
-            .take(RANDOM_STRING_SIZE)
-            .collect();
-        let random_string = format!(
-            "{}{}",
-            IyzipayResource::get_unix_timestamp_ms(),
-            random_alpanumeric
-        );
+        let random_string = IyzipayResource::get_random_string();
         debug!("Request:{}", request);
 
         headers.insert(
@@ -129,7 +146,13 @@ impl IyzipayResource {
 
     pub fn get_http_headers_v2(uri: String, request: String, options: &Options) -> HeaderMap {
         let mut headers = HeaderMap::new();
-        let random_string = Uuid::new_v4().to_string();
+        let random_string = IyzipayResource::get_random_string();
+        debug!("Request:{}", request);
+
+        headers.insert(
+            RANDOM_HEADER_NAME,
+            HeaderValue::from_str(random_string.as_str()).unwrap(),
+        );
         headers.insert(
             AUTHORIZATION,
             IyzipayResource::prepare_authorization_header_v2(uri, request, random_string, options),
@@ -150,6 +173,19 @@ impl IyzipayResource {
         headers
     }
 
+    fn get_random_string() -> String {
+        let mut rng = thread_rng();
+        let random_alpanumeric: String = iter::repeat(())
+            .map(|()| rng.sample(Alphanumeric))
+            .take(RANDOM_STRING_SIZE)
+            .collect();
+        format!(
+            "{}{}",
+            IyzipayResource::get_unix_timestamp_ms(),
+            random_alpanumeric
+        )
+    }
+
     fn get_unix_timestamp_ms() -> i64 {
         let now = Utc::now();
         let seconds: i64 = now.timestamp();
@@ -185,7 +221,7 @@ impl IyzipayResource {
         self.locale = Some(locale.into());
     }
 
-    pub fn set_system_time<T: Into<i64>>(&mut self, system_time: T) {
+    pub fn set_system_time<T: Into<DateTime<Utc>>>(&mut self, system_time: T) {
         self.system_time = Some(system_time.into());
     }
 
@@ -208,9 +244,14 @@ impl IyzipayResource {
     pub fn locale(&self) -> Option<&String> {
         self.locale.as_ref()
     }
-    pub fn system_time(&self) -> Option<&i64> {
+    pub fn system_time(&self) -> Option<&DateTime<Utc>> {
         self.system_time.as_ref()
     }
+    /// Returns the system time as milliseconds since the epoch, as iyzico sends it.
+    pub fn system_time_millis(&self) -> Option<i64> {
+        self.system_time
+            .map(|system_time| system_time.timestamp_millis())
+    }
     pub fn conversation_id(&self) -> Option<&String> {
         self.conversation_id.as_ref()
     }
@@ -0,0 +1,91 @@
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+use zeroize::Zeroize;
+
+use crate::model::mask_card_number;
+
+/// A sensitive value such as a card number or a secret key. The value is overwritten with zeros
+/// when it is dropped and `Debug` only prints `***`, or the masked number of a card number, but it
+/// is serialized as is so that it can be sent to iyzico.
+#[derive(Clone, Default)]
+pub struct Secret {
+    value: String,
+    card_number: bool,
+}
+
+impl Secret {
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        Secret {
+            value: value.into(),
+            card_number: false,
+        }
+    }
+
+    /// A card number, which `Debug` prints masked, e.g. `552879******0008`.
+    pub fn card_number<T: Into<String>>(card_number: T) -> Self {
+        Secret {
+            value: card_number.into(),
+            card_number: true,
+        }
+    }
+
+    pub fn expose(&self) -> &str {
+        self.value.as_str()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.card_number {
+            write!(f, "{}", mask_card_number(self.expose()))
+        } else {
+            write!(f, "***")
+        }
+    }
+}
+
+impl From<String> for Secret {
+    fn from(value: String) -> Self {
+        Secret::new(value)
+    }
+}
+
+impl From<&str> for Secret {
+    fn from(value: &str) -> Self {
+        Secret::new(value)
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.expose())
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Secret::new)
+    }
+}
+
+/// Deserializes an optional card number as `Secret::card_number`.
+pub(crate) fn deserialize_card_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Secret>, D::Error> {
+    Option::<String>::deserialize(deserializer)
+        .map(|card_number| card_number.map(Secret::card_number))
+}
@@ -0,0 +1,313 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use base64::decode;
+use chrono::Utc;
+
+use crate::hash::HashGenerator;
+use crate::hash::IyziAuthV2Generator;
+use crate::options::AuthScheme;
+use crate::requests::PKISerialize;
+
+const TIMESTAMP_LENGTH: usize = 13;
+const DEFAULT_MAX_AGE_MS: u64 = 5 * 60 * 1000;
+const DEFAULT_MAX_CLOCK_SKEW_MS: u64 = 30 * 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthVerificationError {
+    MalformedHeader,
+    MissingRandomKey,
+    RandomKeyMismatch,
+    UnknownApiKey(String),
+    SignatureMismatch,
+    InvalidTimestamp,
+    Expired,
+}
+
+impl fmt::Display for AuthVerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuthVerificationError::MalformedHeader => write!(f, "malformed authorization header"),
+            AuthVerificationError::MissingRandomKey => write!(f, "missing random key"),
+            AuthVerificationError::RandomKeyMismatch => {
+                write!(f, "random key does not match x-iyzi-rnd header")
+            }
+            AuthVerificationError::UnknownApiKey(api_key) => {
+                write!(f, "unknown api key: {}", api_key)
+            }
+            AuthVerificationError::SignatureMismatch => write!(f, "signature mismatch"),
+            AuthVerificationError::InvalidTimestamp => {
+                write!(f, "random key does not start with a timestamp")
+            }
+            AuthVerificationError::Expired => write!(f, "random key timestamp is out of range"),
+        }
+    }
+}
+
+impl Error for AuthVerificationError {}
+
+/// Rejects requests whose random key timestamp (milliseconds since epoch, as generated by
+/// `IyzipayResource`) is older than `max_age` or further than `max_clock_skew` in the future.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReplayProtection {
+    max_age: Duration,
+
+    max_clock_skew: Duration,
+}
+
+impl ReplayProtection {
+    pub fn new(max_age: Duration, max_clock_skew: Duration) -> Self {
+        ReplayProtection {
+            max_age,
+            max_clock_skew,
+        }
+    }
+
+    pub fn max_age(&self) -> &Duration {
+        &self.max_age
+    }
+
+    pub fn max_clock_skew(&self) -> &Duration {
+        &self.max_clock_skew
+    }
+
+    fn check(&self, random_key: &str, now_ms: i64) -> Result<(), AuthVerificationError> {
+        let timestamp = random_key
+            .get(..TIMESTAMP_LENGTH)
+            .filter(|timestamp| timestamp.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|timestamp| timestamp.parse::<i64>().ok())
+            .ok_or(AuthVerificationError::InvalidTimestamp)?;
+        let age = now_ms - timestamp;
+        if age > self.max_age.as_millis() as i64 || -age > self.max_clock_skew.as_millis() as i64 {
+            return Err(AuthVerificationError::Expired);
+        }
+        Ok(())
+    }
+}
+
+impl Default for ReplayProtection {
+    fn default() -> Self {
+        ReplayProtection::new(
+            Duration::from_millis(DEFAULT_MAX_AGE_MS),
+            Duration::from_millis(DEFAULT_MAX_CLOCK_SKEW_MS),
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedAuthorization {
+    api_key: String,
+
+    random_key: String,
+
+    auth_scheme: AuthScheme,
+}
+
+impl VerifiedAuthorization {
+    pub fn api_key(&self) -> &String {
+        &self.api_key
+    }
+    pub fn random_key(&self) -> &String {
+        &self.random_key
+    }
+    pub fn auth_scheme(&self) -> AuthScheme {
+        self.auth_scheme
+    }
+}
+
+/// Verifies `IYZWS` and `IYZWSv2` authorization headers produced by this client, looking up the
+/// secret key of the caller through `secret_provider`.
+pub struct AuthVerifier<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    secret_provider: F,
+
+    replay_protection: Option<ReplayProtection>,
+}
+
+impl<F> AuthVerifier<F>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    pub fn new(secret_provider: F) -> Self {
+        AuthVerifier {
+            secret_provider,
+            replay_protection: Some(ReplayProtection::default()),
+        }
+    }
+
+    pub fn set_replay_protection<T: Into<Option<ReplayProtection>>>(
+        &mut self,
+        replay_protection: T,
+    ) {
+        self.replay_protection = replay_protection.into();
+    }
+
+    pub fn replay_protection(&self) -> Option<&ReplayProtection> {
+        self.replay_protection.as_ref()
+    }
+
+    /// Verifies either scheme. `uri` and `request` (the JSON body) are used for `IYZWSv2`,
+    /// `req` is serialized to its PKI string for `IYZWS`.
+    pub fn verify<T: PKISerialize + ?Sized>(
+        &self,
+        authorization: &str,
+        random_header: Option<&str>,
+        uri: &str,
+        request: &str,
+        req: &T,
+    ) -> Result<VerifiedAuthorization, AuthVerificationError> {
+        match Self::scheme_of(authorization)? {
+            AuthScheme::V1 => self.verify_v1(
+                authorization,
+                random_header,
+                req.serialize().unwrap_or_default().as_str(),
+            ),
+            AuthScheme::V2 => self.verify_v2(authorization, random_header, uri, request),
+        }
+    }
+
+    /// Verifies `IYZWS apiKey:signature` against the PKI string of the request.
+    pub fn verify_v1(
+        &self,
+        authorization: &str,
+        random_header: Option<&str>,
+        pki_request: &str,
+    ) -> Result<VerifiedAuthorization, AuthVerificationError> {
+        let credentials = Self::credentials_of(authorization, AuthScheme::V1)?;
+        let mut parts = credentials.splitn(2, ':');
+        let api_key = parts.next().unwrap_or_default();
+        let signature = parts.next().ok_or(AuthVerificationError::MalformedHeader)?;
+        let random_key = random_header
+            .filter(|random_key| !random_key.is_empty())
+            .ok_or(AuthVerificationError::MissingRandomKey)?;
+        self.check_replay(random_key)?;
+
+        let secret_key = self.secret_key_of(api_key)?;
+        let expected =
+            HashGenerator::generate_hash(api_key, secret_key.as_str(), random_key, pki_request);
+        Self::check_signature(expected.as_str(), signature)?;
+
+        Ok(VerifiedAuthorization {
+            api_key: api_key.to_string(),
+            random_key: random_key.to_string(),
+            auth_scheme: AuthScheme::V1,
+        })
+    }
+
+    /// Verifies `IYZWSv2 base64(apiKey:..&randomKey:..&signature:..)` against the URI path and
+    /// the JSON body. The `x-iyzi-rnd` header is optional but must match when present.
+    pub fn verify_v2(
+        &self,
+        authorization: &str,
+        random_header: Option<&str>,
+        uri: &str,
+        request: &str,
+    ) -> Result<VerifiedAuthorization, AuthVerificationError> {
+        let credentials = Self::credentials_of(authorization, AuthScheme::V2)?;
+        let decoded = decode(credentials)
+            .ok()
+            .and_then(|decoded| String::from_utf8(decoded).ok())
+            .ok_or(AuthVerificationError::MalformedHeader)?;
+
+        let mut api_key = None;
+        let mut random_key = None;
+        let mut signature = None;
+        for pair in decoded.split('&') {
+            let mut parts = pair.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some("apiKey"), Some(value)) => api_key = Some(value),
+                (Some("randomKey"), Some(value)) => random_key = Some(value),
+                (Some("signature"), Some(value)) => signature = Some(value),
+                _ => return Err(AuthVerificationError::MalformedHeader),
+            }
+        }
+        let api_key = api_key.ok_or(AuthVerificationError::MalformedHeader)?;
+        signature
+            .filter(|signature| !signature.is_empty())
+            .ok_or(AuthVerificationError::MalformedHeader)?;
+        let random_key = random_key
+            .filter(|random_key| !random_key.is_empty())
+            .ok_or(AuthVerificationError::MissingRandomKey)?;
+        if let Some(random_header) = random_header {
+            if !constant_time_eq(random_header.as_bytes(), random_key.as_bytes()) {
+                return Err(AuthVerificationError::RandomKeyMismatch);
+            }
+        }
+        self.check_replay(random_key)?;
+
+        let secret_key = self.secret_key_of(api_key)?;
+        let expected = IyziAuthV2Generator::generate_auth_content(
+            uri,
+            api_key,
+            secret_key.as_str(),
+            random_key,
+            request,
+        );
+        Self::check_signature(expected.as_str(), credentials)?;
+
+        Ok(VerifiedAuthorization {
+            api_key: api_key.to_string(),
+            random_key: random_key.to_string(),
+            auth_scheme: AuthScheme::V2,
+        })
+    }
+
+    fn scheme_of(authorization: &str) -> Result<AuthScheme, AuthVerificationError> {
+        match authorization.trim().split(' ').next() {
+            Some(scheme) if scheme == AuthScheme::V1.value() => Ok(AuthScheme::V1),
+            Some(scheme) if scheme == AuthScheme::V2.value() => Ok(AuthScheme::V2),
+            _ => Err(AuthVerificationError::MalformedHeader),
+        }
+    }
+
+    fn credentials_of(
+        authorization: &str,
+        auth_scheme: AuthScheme,
+    ) -> Result<&str, AuthVerificationError> {
+        let mut parts = authorization.trim().splitn(2, ' ');
+        match (parts.next(), parts.next()) {
+            (Some(scheme), Some(credentials)) if scheme == auth_scheme.value() => {
+                Ok(credentials.trim())
+            }
+            _ => Err(AuthVerificationError::MalformedHeader),
+        }
+    }
+
+    fn secret_key_of(&self, api_key: &str) -> Result<String, AuthVerificationError> {
+        if api_key.is_empty() {
+            return Err(AuthVerificationError::MalformedHeader);
+        }
+        (self.secret_provider)(api_key)
+            .ok_or_else(|| AuthVerificationError::UnknownApiKey(api_key.to_string()))
+    }
+
+    fn check_replay(&self, random_key: &str) -> Result<(), AuthVerificationError> {
+        match &self.replay_protection {
+            Some(replay_protection) => {
+                replay_protection.check(random_key, Utc::now().timestamp_millis())
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn check_signature(expected: &str, actual: &str) -> Result<(), AuthVerificationError> {
+        if constant_time_eq(expected.as_bytes(), actual.as_bytes()) {
+            Ok(())
+        } else {
+            Err(AuthVerificationError::SignatureMismatch)
+        }
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter()
+        .zip(b.iter())
+        .fold(0u8, |acc, (x, y)| acc | (x ^ y))
+        == 0
+}
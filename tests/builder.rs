@@ -0,0 +1,236 @@
+pub mod builder_test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use iyzipay_rust::model::Address;
+    use iyzipay_rust::model::BasketItem;
+    use iyzipay_rust::model::Buyer;
+    use iyzipay_rust::model::CardInformation;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::Locale;
+    use iyzipay_rust::model::Money;
+    use iyzipay_rust::model::PaymentCard;
+    use iyzipay_rust::model::PaymentChannel;
+    use iyzipay_rust::model::RefundReason;
+    use iyzipay_rust::model::SubMerchantType;
+    use iyzipay_rust::requests::CreateAmountBasedRefundRequest;
+    use iyzipay_rust::requests::CreateCardRequest;
+    use iyzipay_rust::requests::CreateCheckoutFormInitializeRequest;
+    use iyzipay_rust::requests::CreatePaymentRequest;
+    use iyzipay_rust::requests::CreateRefundRequest;
+    use iyzipay_rust::requests::CreateSubMerchantRequest;
+    use iyzipay_rust::requests::IyziLinkSaveRequest;
+    use iyzipay_rust::requests::Validate;
+
+    fn basket_items() -> Vec<BasketItem> {
+        let mut basket_item = BasketItem::new();
+        basket_item.set_id("BI101");
+        basket_item.set_price(BigDecimal::from_str("1").unwrap());
+        vec![basket_item]
+    }
+
+    fn address() -> Address {
+        let mut address = Address::new();
+        address.set_contact_name("Jane Doe");
+        address
+    }
+
+    fn buyer() -> Buyer {
+        let mut buyer = Buyer::new();
+        buyer.set_id("BY789");
+        buyer
+    }
+
+    fn json<T: serde::Serialize>(value: &T) -> String {
+        serde_json::to_string(value).unwrap()
+    }
+
+    #[test]
+    fn should_build_payment_request() {
+        let mut payment_card = PaymentCard::new();
+        payment_card.set_card_number("5528790000000008");
+
+        let mut expected = CreatePaymentRequest::new();
+        expected.set_locale(Locale::TR);
+        expected.set_price(BigDecimal::from_str("1").unwrap());
+        expected.set_paid_price(BigDecimal::from_str("1.2").unwrap());
+        expected.set_installment(1);
+        expected.set_payment_channel(PaymentChannel::Web);
+        expected.set_payment_card(payment_card.clone());
+        expected.set_buyer(buyer());
+        expected.set_billing_address(address());
+        expected.set_basket_items(basket_items());
+
+        let request = CreatePaymentRequest::builder()
+            .basket_items(basket_items())
+            .buyer(buyer())
+            .price(BigDecimal::from_str("1").unwrap())
+            .paid_price(BigDecimal::from_str("1.2").unwrap())
+            .installment(1)
+            .locale(Locale::TR)
+            .payment_channel(PaymentChannel::Web)
+            .payment_card(payment_card.clone())
+            .billing_address(address())
+            .build();
+        assert_eq!(json(&expected), json(&request));
+
+        expected.set_callback_url("https://www.merchant.com/callback");
+        let request = CreatePaymentRequest::builder()
+            .price(BigDecimal::from_str("1").unwrap())
+            .paid_price(BigDecimal::from_str("1.2").unwrap())
+            .installment(1)
+            .locale(Locale::TR)
+            .payment_channel(PaymentChannel::Web)
+            .payment_card(payment_card)
+            .buyer(buyer())
+            .billing_address(address())
+            .basket_items(basket_items())
+            .callback_url("https://www.merchant.com/callback")
+            .build_threeds();
+        assert_eq!(json(&expected), json(&request));
+    }
+
+    #[test]
+    fn should_build_checkout_form_initialize_request() {
+        let mut expected = CreateCheckoutFormInitializeRequest::new();
+        expected.set_conversation_id("123456789");
+        expected.set_price(BigDecimal::from_str("1").unwrap());
+        expected.set_paid_price(BigDecimal::from_str("1.2").unwrap());
+        expected.set_currency(Currency::TRY);
+        expected.set_buyer(buyer());
+        expected.set_billing_address(address());
+        expected.set_basket_items(basket_items());
+        expected.set_callback_url("https://www.merchant.com/callback");
+        expected.set_enabled_installments(vec![2, 3]);
+
+        let request = CreateCheckoutFormInitializeRequest::builder()
+            .conversation_id("123456789")
+            .price(BigDecimal::from_str("1").unwrap())
+            .paid_price(BigDecimal::from_str("1.2").unwrap())
+            .currency(Currency::TRY)
+            .buyer(buyer())
+            .billing_address(address())
+            .basket_items(basket_items())
+            .callback_url("https://www.merchant.com/callback")
+            .enabled_installments(vec![2, 3])
+            .build();
+        assert_eq!(json(&expected), json(&request));
+    }
+
+    #[test]
+    fn should_build_sub_merchant_request_per_type() {
+        let builder = || {
+            CreateSubMerchantRequest::builder()
+                .name("John's market")
+                .email("email@submerchantemail.com")
+                .gsm_number("+905350000000")
+                .address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1")
+                .iban("TR180006200119000006672315")
+                .sub_merchant_external_id("B49224")
+                .currency(Currency::TRY)
+        };
+
+        let personal = builder()
+            .contact_name("John")
+            .contact_surname("Doe")
+            .identity_number("31300864726")
+            .build_personal();
+        assert_eq!(
+            Some(&SubMerchantType::Personal.to_string()),
+            personal.sub_merchant_type()
+        );
+        assert_eq!(Some(&String::from("Doe")), personal.contact_surname());
+
+        let private_company = builder()
+            .tax_office("Tax office")
+            .legal_company_title("John Doe inc")
+            .identity_number("31300864726")
+            .build_private_company();
+        assert_eq!(
+            Some(&SubMerchantType::PrivateCompany.to_string()),
+            private_company.sub_merchant_type()
+        );
+
+        let limited_company = builder()
+            .tax_office("Tax office")
+            .tax_number("9261877")
+            .legal_company_title("XYZ inc")
+            .build_limited_or_joint_stock_company();
+        assert_eq!(
+            Some(&SubMerchantType::LimitedOrJointStockCompany.to_string()),
+            limited_company.sub_merchant_type()
+        );
+        assert_eq!(Some(&String::from("9261877")), limited_company.tax_number());
+    }
+
+    #[test]
+    fn should_build_refund_request() {
+        let request = CreateRefundRequest::builder()
+            .payment_transaction_id("1")
+            .price_money(Money::parse("0.5", Currency::USD).unwrap())
+            .ip("85.34.78.112")
+            .build();
+
+        assert_eq!(Some(&String::from("1")), request.payment_transaction_id());
+        assert_eq!(Some(&BigDecimal::from_str("0.5").unwrap()), request.price());
+        assert_eq!(Some(&String::from("USD")), request.currency());
+    }
+
+    #[test]
+    fn should_build_amount_based_refund_request() {
+        let request = CreateAmountBasedRefundRequest::builder()
+            .payment_id("1")
+            .price_money(Money::parse("1.5", Currency::TRY).unwrap())
+            .reason(RefundReason::BuyerRequest)
+            .build();
+
+        assert_eq!(Some(&String::from("1")), request.payment_id());
+        assert_eq!(Some(&BigDecimal::from_str("1.5").unwrap()), request.price());
+        assert_eq!(Some(&String::from("TRY")), request.currency());
+        assert_eq!(Some(&RefundReason::BuyerRequest), request.reason());
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            "BUYER_REQUEST",
+            serde_json::to_value(&request).unwrap()["reason"]
+        );
+    }
+
+    #[test]
+    fn should_build_card_request_for_new_or_existing_user() {
+        let mut card = CardInformation::new();
+        card.set_card_alias("card alias");
+
+        let new_user = CreateCardRequest::builder()
+            .card(card.clone())
+            .email("email@email.com")
+            .build();
+        assert_eq!(Some(&String::from("email@email.com")), new_user.email());
+        assert_eq!(None, new_user.card_user_key());
+
+        let existing_user = CreateCardRequest::builder()
+            .card_user_key("card user key")
+            .card(card)
+            .build();
+        assert_eq!(
+            Some(&String::from("card user key")),
+            existing_user.card_user_key()
+        );
+    }
+
+    #[test]
+    fn should_build_iyzi_link_save_request() {
+        let request = IyziLinkSaveRequest::builder()
+            .name("ft-name")
+            .description("ft-description")
+            .base64_encoded_image("aW1hZ2U=")
+            .price(BigDecimal::from_str("10").unwrap())
+            .currency(Currency::TRY)
+            .sold_limit(1)
+            .build();
+
+        assert_eq!(Some(&String::from("ft-name")), request.name());
+        assert_eq!(Some(&String::from("TRY")), request.currency());
+        assert_eq!(Some(&1), request.sold_limit());
+    }
+}
@@ -0,0 +1,157 @@
+pub mod card_data_test {
+    use chrono::NaiveDate;
+
+    use iyzipay_rust::model::bin_number;
+    use iyzipay_rust::model::is_expired;
+    use iyzipay_rust::model::is_luhn_valid;
+    use iyzipay_rust::model::mask_card_number;
+    use iyzipay_rust::model::normalize_expire_month;
+    use iyzipay_rust::model::normalize_expire_year;
+    use iyzipay_rust::model::CardAssociation;
+    use iyzipay_rust::model::CardInformation;
+    use iyzipay_rust::model::PaymentCard;
+    use iyzipay_rust::requests::Validate;
+    use iyzipay_rust::secret::Secret;
+
+    fn payment_card(card_number: &str, cvc: &str) -> PaymentCard {
+        let mut payment_card = PaymentCard::new();
+        payment_card.set_card_holder_name("John Doe");
+        payment_card.set_card_number(card_number);
+        payment_card.set_expire_month("12");
+        payment_card.set_expire_year("2099");
+        payment_card.set_cvc(cvc);
+        payment_card
+    }
+
+    fn paths(card: &dyn Validate) -> Vec<String> {
+        card.validate()
+            .err()
+            .map(|errors| {
+                errors
+                    .violations()
+                    .iter()
+                    .map(|violation| violation.path().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn should_check_luhn() {
+        assert!(is_luhn_valid("5528790000000008"));
+        assert!(is_luhn_valid("4543 5900 0000 0006"));
+        assert!(is_luhn_valid("374427000000003"));
+        assert!(!is_luhn_valid("5528790000000009"));
+        assert!(!is_luhn_valid("5528x90000000008"));
+        assert!(!is_luhn_valid(""));
+    }
+
+    #[test]
+    fn should_detect_card_association() {
+        let association = CardAssociation::from_card_number;
+        assert_eq!(Some(CardAssociation::Visa), association("4543590000000006"));
+        assert_eq!(
+            Some(CardAssociation::MasterCard),
+            association("5528790000000008")
+        );
+        assert_eq!(
+            Some(CardAssociation::MasterCard),
+            association("2221000000000009")
+        );
+        assert_eq!(Some(CardAssociation::Amex), association("374427000000003"));
+        assert_eq!(Some(CardAssociation::Troy), association("9792030000000000"));
+        assert_eq!(None, association("6011000000000004"));
+        assert_eq!(4, CardAssociation::Amex.cvc_length());
+        assert_eq!(&[15], CardAssociation::Amex.card_number_lengths());
+    }
+
+    #[test]
+    fn should_return_bin_and_masked_card_number() {
+        assert_eq!(Some(String::from("552879")), bin_number("5528790000000008"));
+        assert_eq!(None, bin_number("55287"));
+        assert_eq!("552879******0008", mask_card_number("5528 7900 0000 0008"));
+        assert_eq!("*****6789", mask_card_number("123456789"));
+
+        let card = payment_card("5528-7900-0000-0008", "123");
+        assert_eq!(
+            Some("5528790000000008"),
+            card.card_number().map(Secret::expose)
+        );
+        assert_eq!(Some(String::from("552879")), card.bin_number());
+        assert_eq!(
+            Some(String::from("552879******0008")),
+            card.masked_card_number()
+        );
+        assert_eq!(
+            Some(CardAssociation::MasterCard),
+            card.detected_card_association()
+        );
+    }
+
+    #[test]
+    fn should_normalize_expiry() {
+        assert_eq!(Some(String::from("01")), normalize_expire_month("1"));
+        assert_eq!(Some(String::from("12")), normalize_expire_month("12"));
+        assert_eq!(None, normalize_expire_month("13"));
+        assert_eq!(None, normalize_expire_month("001"));
+        assert_eq!(Some(String::from("2030")), normalize_expire_year("30"));
+        assert_eq!(Some(String::from("2030")), normalize_expire_year("2030"));
+        assert_eq!(None, normalize_expire_year("203"));
+
+        let mut card = CardInformation::new();
+        card.set_expire_month("3");
+        card.set_expire_year("31");
+        assert_eq!(Some("03"), card.expire_month().map(Secret::expose));
+        assert_eq!(Some("2031"), card.expire_year().map(Secret::expose));
+
+        let today = NaiveDate::from_ymd_opt(2024, 5, 31).unwrap();
+        assert_eq!(Some(false), is_expired("05", "2024", today));
+        assert_eq!(Some(true), is_expired("4", "24", today));
+        assert_eq!(None, is_expired("13", "2024", today));
+    }
+
+    #[test]
+    fn should_validate_payment_card() {
+        assert!(paths(&payment_card("5528790000000008", "123")).is_empty());
+        assert!(paths(&payment_card("374427000000003", "1234")).is_empty());
+        assert_eq!(vec!["cvc"], paths(&payment_card("374427000000003", "123")));
+        assert_eq!(
+            vec!["cardNumber"],
+            paths(&payment_card("5528790000000009", "123"))
+        );
+        assert_eq!(
+            vec!["cardNumber"],
+            paths(&payment_card("55287900000000", "123"))
+        );
+
+        let mut expired = payment_card("5528790000000008", "123");
+        expired.set_expire_month("13");
+        expired.set_expire_year("2001");
+        let errors = expired.validate().unwrap_err();
+        assert_eq!(
+            "expireMonth: must be a month between 1 and 12",
+            errors.violations()[0].to_string()
+        );
+
+        expired.set_expire_month("1");
+        let errors = expired.validate().unwrap_err();
+        assert_eq!("card is expired", errors.of("expireYear")[0].message());
+
+        let mut stored_card = PaymentCard::new();
+        stored_card.set_card_token("card token");
+        assert_eq!(vec!["cardUserKey"], paths(&stored_card));
+    }
+
+    #[test]
+    fn should_validate_card_information() {
+        let mut card = CardInformation::new();
+        card.set_card_number("4543590000000006");
+        card.set_expire_month("12");
+        card.set_expire_year("2099");
+        assert!(paths(&card).is_empty());
+
+        card.set_card_number("4543590000000007");
+        card.set_expire_year("");
+        assert_eq!(vec!["cardNumber", "expireYear"], paths(&card));
+    }
+}
@@ -0,0 +1,113 @@
+pub mod contact_test {
+    use std::net::IpAddr;
+
+    use iyzipay_rust::model::Buyer;
+    use iyzipay_rust::model::InitialConsumer;
+    use iyzipay_rust::model::{
+        is_valid_email, normalize_phone_number, normalize_phone_number_for, parse_ip,
+    };
+    use iyzipay_rust::requests::UpdateSubMerchantRequest;
+    use iyzipay_rust::requests::Validate;
+
+    #[test]
+    fn should_normalize_phone_number() {
+        assert_eq!(
+            Some(String::from("+905350000000")),
+            normalize_phone_number("+905350000000")
+        );
+        assert_eq!(
+            Some(String::from("+905350000000")),
+            normalize_phone_number("0535 000 00 00")
+        );
+        assert_eq!(
+            Some(String::from("+905350000000")),
+            normalize_phone_number("(535) 000-00-00")
+        );
+        assert_eq!(
+            Some(String::from("+905350000000")),
+            normalize_phone_number("00 90 535 000 00 00")
+        );
+        assert_eq!(
+            Some(String::from("+4915112345678")),
+            normalize_phone_number("+49 151 12345678")
+        );
+        assert_eq!(
+            Some(String::from("+4915112345678")),
+            normalize_phone_number_for("0151 12345678", "49")
+        );
+        assert_eq!(None, normalize_phone_number("phone"));
+        assert_eq!(None, normalize_phone_number("+90 535 000 00 00 00 00"));
+        assert_eq!(None, normalize_phone_number("+0535"));
+    }
+
+    #[test]
+    fn should_check_email() {
+        assert!(is_valid_email("email@email.com"));
+        assert!(is_valid_email("john.doe+shop@sub.merchant.com.tr"));
+        assert!(!is_valid_email("email"));
+        assert!(!is_valid_email("email@email"));
+        assert!(!is_valid_email("@email.com"));
+        assert!(!is_valid_email("john..doe@email.com"));
+        assert!(!is_valid_email("email@-email.com"));
+        assert!(!is_valid_email("email@email.c"));
+        assert!(!is_valid_email("john doe@email.com"));
+    }
+
+    #[test]
+    fn should_parse_ip() {
+        assert_eq!(
+            Some("85.34.78.112".parse::<IpAddr>().unwrap()),
+            parse_ip("85.34.78.112")
+        );
+        assert!(parse_ip("2001:db8::1").is_some_and(|ip| ip.is_ipv6()));
+        assert!(parse_ip(" 85.34.78.112 ").is_some());
+        assert_eq!(None, parse_ip("85.34.78.256"));
+        assert_eq!(None, parse_ip("localhost"));
+    }
+
+    #[test]
+    fn should_normalize_and_validate_buyer_contact() {
+        let mut buyer = Buyer::new();
+        buyer.set_identity_number("10000000146");
+        buyer.set_gsm_number("0535 000 00 00");
+        buyer.set_email(" email@email.com ");
+        buyer.set_ip("85.34.78.112");
+
+        assert_eq!(Some(&String::from("+905350000000")), buyer.gsm_number());
+        assert_eq!(Some(&String::from("email@email.com")), buyer.email());
+        assert!(buyer.validate().is_ok());
+
+        buyer.set_gsm_number("phone");
+        buyer.set_email("email");
+        buyer.set_ip("85.34.78");
+
+        assert_eq!(Some(&String::from("phone")), buyer.gsm_number());
+        let paths: Vec<String> = buyer
+            .validate()
+            .unwrap_err()
+            .violations()
+            .iter()
+            .map(|v| v.path().to_string())
+            .collect();
+        assert_eq!(vec!["gsmNumber", "email", "ip"], paths);
+    }
+
+    #[test]
+    fn should_validate_sub_merchant_and_consumer_contact() {
+        let mut request = UpdateSubMerchantRequest::new();
+        request.set_sub_merchant_key("sub merchant key");
+        request.set_gsm_number("535 000 00 00");
+        request.set_email("email@submerchantemail.com");
+
+        assert_eq!(Some(&String::from("+905350000000")), request.gsm_number());
+        assert!(request.validate().is_ok());
+
+        let mut consumer = InitialConsumer::new();
+        consumer.set_gsm_number("+905556667788");
+        consumer.set_email("consumermail@");
+
+        let errors = consumer.validate().unwrap_err();
+        assert_eq!(1, errors.violations().len());
+        assert_eq!(1, errors.of("email").len());
+    }
+}
@@ -0,0 +1,69 @@
+pub mod currency_test {
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::Money;
+
+    #[test]
+    fn should_return_currency_metadata() {
+        assert_eq!(Some(949), Currency::TRY.numeric_code());
+        assert_eq!(Some("₺"), Currency::TRY.symbol());
+        assert_eq!(2, Currency::TRY.minor_units());
+        assert_eq!(0, Currency::JPY.minor_units());
+        assert_eq!(3, Currency::KWD.minor_units());
+        assert_eq!(Some(36), Currency::AUD.numeric_code());
+        assert_eq!(Some(Currency::EUR), Currency::from_numeric_code(978));
+        assert_eq!(None, Currency::from_numeric_code(1));
+    }
+
+    #[test]
+    fn should_parse_currency() {
+        assert_eq!(Currency::USD, "USD".parse::<Currency>().unwrap());
+        assert_eq!(Currency::GBP, "gbp".parse::<Currency>().unwrap());
+        assert_eq!(
+            Currency::Unknown(String::from("XYZ")),
+            "XYZ".parse::<Currency>().unwrap()
+        );
+        assert!(Currency::from("XYZ").is_unknown());
+        assert_eq!("XYZ", Currency::from("XYZ").value());
+        assert_eq!(None, Currency::from("XYZ").symbol());
+        assert_eq!(2, Currency::from("XYZ").minor_units());
+    }
+
+    #[test]
+    fn should_serialize_and_deserialize_currency() {
+        assert_eq!(r#""TRY""#, serde_json::to_string(&Currency::TRY).unwrap());
+        assert_eq!(
+            Currency::CHF,
+            serde_json::from_str::<Currency>(r#""CHF""#).unwrap()
+        );
+        assert_eq!(
+            Currency::Unknown(String::from("XAU")),
+            serde_json::from_str::<Currency>(r#""XAU""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_list_every_known_currency() {
+        assert!(Currency::all()
+            .iter()
+            .all(|currency| !currency.is_unknown()));
+        assert!(Currency::all()
+            .iter()
+            .all(|currency| Currency::from(currency.value()) == *currency));
+        assert_eq!(28, Currency::all().len());
+        assert_eq!(Some(&Currency::UAH), Currency::VARIANTS.last());
+        assert_eq!(
+            "1.235",
+            Money::parse("1.2345", Currency::KWD)
+                .unwrap()
+                .amount()
+                .to_string()
+        );
+        assert_eq!(
+            "1",
+            Money::parse("1.2", Currency::JPY)
+                .unwrap()
+                .amount()
+                .to_string()
+        );
+    }
+}
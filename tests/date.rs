@@ -0,0 +1,127 @@
+pub mod date_test {
+    use chrono::NaiveDate;
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    use iyzipay_rust::model::parse_date_time;
+    use iyzipay_rust::model::Buyer;
+    use iyzipay_rust::model::PaymentItem;
+    use iyzipay_rust::requests::to_pki_string;
+    use iyzipay_rust::requests::PKISerialize;
+    use iyzipay_rust::requests::RetrieveTransactionsRequest;
+    use iyzipay_rust::resource::IyzipayResource;
+
+    #[test]
+    fn should_parse_date_time() {
+        let date_time = NaiveDate::from_ymd_opt(2013, 4, 21)
+            .unwrap()
+            .and_hms_opt(15, 12, 9)
+            .unwrap();
+
+        assert_eq!(Some(date_time), parse_date_time("2013-04-21 15:12:09"));
+        assert_eq!(Some(date_time), parse_date_time("2013-04-21T15:12:09"));
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2013, 4, 21)
+                .unwrap()
+                .and_hms_opt(0, 0, 0),
+            parse_date_time("2013-04-21")
+        );
+        assert_eq!(None, parse_date_time("21.04.2013"));
+        assert_eq!(None, parse_date_time("2013-04-21 25:12:09"));
+    }
+
+    #[test]
+    fn should_set_buyer_dates_from_strings_and_chrono() {
+        let registration_date = NaiveDate::from_ymd_opt(2013, 4, 21)
+            .unwrap()
+            .and_hms_opt(15, 12, 9)
+            .unwrap();
+        let mut buyer = Buyer::new();
+        buyer.set_registration_date(registration_date);
+        buyer.set_last_login_date("2015-10-05 12:43:35");
+
+        assert_eq!(Some(&registration_date), buyer.registration_date());
+        assert_eq!(
+            parse_date_time("2015-10-05 12:43:35").as_ref(),
+            buyer.last_login_date()
+        );
+        let json = serde_json::to_value(&buyer).unwrap();
+        assert_eq!("2013-04-21 15:12:09", json["registrationDate"]);
+        assert_eq!("2015-10-05 12:43:35", json["lastLoginDate"]);
+        assert_eq!(
+            Some(String::from(
+                "[registrationDate=2013-04-21 15:12:09,lastLoginDate=2015-10-05 12:43:35]"
+            )),
+            to_pki_string(&buyer).unwrap()
+        );
+
+        buyer.set_last_login_date("yesterday");
+        assert_eq!(None, buyer.last_login_date());
+    }
+
+    #[test]
+    fn should_send_strings_that_are_not_dates_as_they_are() {
+        let mut buyer = Buyer::new();
+        buyer.set_last_login_date("05.10.2015");
+
+        assert_eq!(None, buyer.last_login_date());
+        assert_eq!(
+            "05.10.2015",
+            serde_json::to_value(&buyer).unwrap()["lastLoginDate"]
+        );
+        assert_eq!(
+            Some(String::from("[lastLoginDate=05.10.2015]")),
+            to_pki_string(&buyer).unwrap()
+        );
+
+        let mut request = RetrieveTransactionsRequest::new();
+        request.set_date(String::from("22.01.2016"));
+
+        assert_eq!(None, request.date());
+        assert_eq!(Some(String::from("[date=22.01.2016]")), request.serialize());
+        let request: RetrieveTransactionsRequest =
+            serde_json::from_str(r#"{"date": "22.01.2016"}"#).unwrap();
+        assert_eq!(Some(String::from("[date=22.01.2016]")), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_transactions_date() {
+        let mut request = RetrieveTransactionsRequest::new();
+        request.set_date(NaiveDate::from_ymd_opt(2016, 1, 22).unwrap());
+
+        assert_eq!(
+            "2016-01-22 00:00:00",
+            serde_json::to_value(&request).unwrap()["date"]
+        );
+        assert_eq!(
+            Some(String::from("[date=2016-01-22 00:00:00]")),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_response_dates() {
+        let payment_item: PaymentItem =
+            serde_json::from_str(r#"{"blockageResolvedDate": "2019-02-25 10:04:02"}"#).unwrap();
+        assert_eq!(
+            parse_date_time("2019-02-25 10:04:02").as_ref(),
+            payment_item.blockage_resolved_date()
+        );
+
+        let payment_item: PaymentItem =
+            serde_json::from_str(r#"{"blockageResolvedDate": "not a date"}"#).unwrap();
+        assert_eq!(None, payment_item.blockage_resolved_date());
+
+        let resource: IyzipayResource =
+            serde_json::from_str(r#"{"status": "success", "systemTime": 1458545234852}"#).unwrap();
+        assert_eq!(
+            Some(&Utc.timestamp_millis_opt(1458545234852).unwrap()),
+            resource.system_time()
+        );
+        assert_eq!(Some(1458545234852), resource.system_time_millis());
+        assert_eq!(
+            r#"{"status":"success","errorCode":null,"errorMessage":null,"errorGroup":null,"locale":null,"systemTime":1458545234852,"conversationId":null}"#,
+            serde_json::to_string(&resource).unwrap()
+        );
+    }
+}
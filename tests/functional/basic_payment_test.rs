@@ -0,0 +1,96 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use iyzipay_rust::model::BasicPayment;
+use iyzipay_rust::model::BasicPaymentPostAuth;
+use iyzipay_rust::model::BasicPaymentPreAuth;
+use iyzipay_rust::model::BasicThreedsInitialize;
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::requests::CreateBasicPaymentRequest;
+use iyzipay_rust::requests::CreatePaymentPostAuthRequest;
+
+use crate::functional::builder::Builder;
+use crate::functional::builder::PaymentCardBuilder;
+use crate::get_test_options;
+
+fn basic_payment_request() -> CreateBasicPaymentRequest {
+    let mut request = CreateBasicPaymentRequest::builder()
+        .price(BigDecimal::from_str("1").unwrap())
+        .paid_price(BigDecimal::from_str("1.1").unwrap())
+        .payment_card(PaymentCardBuilder::create().build())
+        .installment(1)
+        .buyer_email("email@email.com")
+        .buyer_id("B2323")
+        .buyer_ip("85.34.78.112")
+        .currency(Currency::TRY)
+        .callback_url("https://www.merchant.com/callback")
+        .build_threeds();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request
+}
+
+#[test]
+fn should_create_basic_payment() {
+    let _ = env_logger::try_init();
+    let payment = BasicPayment::create(&basic_payment_request(), &get_test_options()).unwrap();
+
+    debug!("{:?}", payment);
+
+    assert_eq!(Some(&Locale::TR.to_string()), payment.locale());
+    assert_eq!(Some(&Status::Success.to_string()), payment.status());
+    assert_eq!(Some(&String::from("123456789")), payment.conversation_id());
+    assert_ne!(None, payment.payment_id());
+    assert_ne!(None, payment.payment_transaction_id());
+    assert_eq!(
+        payment.paid_price().unwrap(),
+        &BigDecimal::from_str("1.1").unwrap()
+    );
+    assert_eq!(None, payment.error_code());
+    assert_eq!(None, payment.error_message());
+    assert_eq!(None, payment.error_group());
+}
+
+#[test]
+fn should_create_basic_pre_auth_and_capture_it() {
+    let _ = env_logger::try_init();
+    let pre_auth =
+        BasicPaymentPreAuth::create(&basic_payment_request(), &get_test_options()).unwrap();
+
+    debug!("{:?}", pre_auth);
+
+    assert_eq!(Some(&Status::Success.to_string()), pre_auth.status());
+
+    let post_auth_request = CreatePaymentPostAuthRequest::builder()
+        .payment_id(pre_auth.payment_id().unwrap().to_owned())
+        .paid_price(BigDecimal::from_str("1.1").unwrap())
+        .currency(Currency::TRY)
+        .build();
+    let post_auth = BasicPaymentPostAuth::create(&post_auth_request, &get_test_options()).unwrap();
+
+    debug!("{:?}", post_auth);
+
+    assert_eq!(Some(&Status::Success.to_string()), post_auth.status());
+    assert_eq!(pre_auth.payment_id(), post_auth.payment_id());
+    assert_eq!(None, post_auth.error_code());
+}
+
+#[test]
+fn should_initialize_basic_threeds_payment() {
+    let _ = env_logger::try_init();
+    let threeds_initialize =
+        BasicThreedsInitialize::create(&basic_payment_request(), &get_test_options()).unwrap();
+
+    debug!("{:?}", threeds_initialize);
+
+    assert_eq!(
+        Some(&Status::Success.to_string()),
+        threeds_initialize.status()
+    );
+    assert_ne!(None, threeds_initialize.html_content());
+    assert_eq!(None, threeds_initialize.error_code());
+}
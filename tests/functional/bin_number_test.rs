@@ -1,6 +1,9 @@
 use log::debug;
 
 use iyzipay_rust::model::BinNumber;
+use iyzipay_rust::model::CardAssociation;
+use iyzipay_rust::model::CardFamily;
+use iyzipay_rust::model::CardType;
 use iyzipay_rust::model::Status;
 use iyzipay_rust::requests::RetrieveBinNumberRequest;
 
@@ -29,12 +32,12 @@ fn should_retrieve_bin() {
     assert_eq!(None, bin_number.error_message());
     assert_eq!(None, bin_number.error_group());
     assert_eq!(Some(&String::from("554960")), bin_number.bin_number());
-    assert_eq!(Some(&String::from("CREDIT_CARD")), bin_number.card_type());
+    assert_eq!(Some(&CardType::CreditCard), bin_number.card_type());
     assert_eq!(
-        Some(&String::from("MASTER_CARD")),
+        Some(&CardAssociation::MasterCard),
         bin_number.card_association()
     );
-    assert_eq!(Some(&String::from("Bonus")), bin_number.card_family());
+    assert_eq!(Some(&CardFamily::Bonus), bin_number.card_family());
     assert_eq!(
         Some(&String::from("Garanti Bankası")),
         bin_number.bank_name()
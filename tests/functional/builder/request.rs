@@ -102,7 +102,7 @@ impl Builder for RetrieveBinNumberRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -176,7 +176,7 @@ impl Builder for CreateCardRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -459,7 +459,7 @@ impl Builder for CreateSubMerchantRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -498,10 +498,10 @@ impl Builder for CreateSubMerchantRequestBuilder {
             .and_then(|x| Some(request.set_tax_number(x)));
         self.sub_merchant_type
             .to_owned()
-            .and_then(|x| Some(request.set_sub_merchant_type(x)));
+            .and_then(|x| Some(request.set_sub_merchant_type_raw(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         self.swift_code
             .to_owned()
             .and_then(|x| Some(request.set_swift_code(x)));
@@ -647,7 +647,7 @@ impl Builder for UpdateSubMerchantRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -686,7 +686,7 @@ impl Builder for UpdateSubMerchantRequestBuilder {
             .and_then(|x| Some(request.set_tax_number(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         self.swift_code
             .to_owned()
             .and_then(|x| Some(request.set_swift_code(x)));
@@ -738,7 +738,7 @@ impl Builder for RetrieveSubMerchantRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -925,7 +925,7 @@ impl Builder for CreatePaymentRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -941,13 +941,13 @@ impl Builder for CreatePaymentRequestBuilder {
             .and_then(|x| Some(request.set_installment(x)));
         self.payment_channel
             .to_owned()
-            .and_then(|x| Some(request.set_payment_channel(x)));
+            .and_then(|x| Some(request.set_payment_channel_raw(x)));
         self.basket_id
             .to_owned()
             .and_then(|x| Some(request.set_basket_id(x)));
         self.payment_group
             .to_owned()
-            .and_then(|x| Some(request.set_payment_group(x)));
+            .and_then(|x| Some(request.set_payment_group_raw(x)));
         self.payment_card
             .to_owned()
             .and_then(|x| Some(request.set_payment_card(x)));
@@ -977,7 +977,7 @@ impl Builder for CreatePaymentRequestBuilder {
             .and_then(|x| Some(request.set_connector_name(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         request
     }
 }
@@ -1484,7 +1484,7 @@ impl Builder for BasketItemBuilder {
             .and_then(|x| Some(basket_item.set_category2(x)));
         self.item_type
             .to_owned()
-            .and_then(|x| Some(basket_item.set_item_type(x)));
+            .and_then(|x| Some(basket_item.set_item_type_raw(x)));
         self.sub_merchant_key
             .to_owned()
             .and_then(|x| Some(basket_item.set_sub_merchant_key(x)));
@@ -1580,7 +1580,7 @@ impl Builder for CreateApprovalRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -1738,7 +1738,7 @@ impl Builder for CreateCheckoutFormInitializeRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -1754,13 +1754,13 @@ impl Builder for CreateCheckoutFormInitializeRequestBuilder {
             .and_then(|x| Some(request.set_basket_id(x)));
         self.payment_group
             .to_owned()
-            .and_then(|x| Some(request.set_payment_group(x)));
+            .and_then(|x| Some(request.set_payment_group_raw(x)));
         self.payment_source
             .to_owned()
             .and_then(|x| Some(request.set_payment_source(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         self.buyer
             .to_owned()
             .and_then(|x| Some(request.set_buyer(x)));
@@ -1833,7 +1833,7 @@ impl Builder for RetrieveCheckoutFormRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -1948,7 +1948,7 @@ impl Builder for CreateBkmInitializeRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -1961,7 +1961,7 @@ impl Builder for CreateBkmInitializeRequestBuilder {
             .and_then(|x| Some(request.set_basket_id(x)));
         self.payment_group
             .to_owned()
-            .and_then(|x| Some(request.set_payment_group(x)));
+            .and_then(|x| Some(request.set_payment_group_raw(x)));
         self.payment_source
             .to_owned()
             .and_then(|x| Some(request.set_payment_source(x)));
@@ -2073,7 +2073,7 @@ impl Builder for CardManagementPageRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -2240,7 +2240,7 @@ impl Builder for CreateIyziupFormInitializeRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -2250,7 +2250,7 @@ impl Builder for CreateIyziupFormInitializeRequestBuilder {
             .and_then(|x| Some(request.set_merchant_order_id(x)));
         self.payment_group
             .to_owned()
-            .and_then(|x| Some(request.set_payment_group(x)));
+            .and_then(|x| Some(request.set_payment_group_raw(x)));
         self.payment_source
             .to_owned()
             .and_then(|x| Some(request.set_payment_source(x)));
@@ -2265,7 +2265,7 @@ impl Builder for CreateIyziupFormInitializeRequestBuilder {
             .and_then(|x| Some(request.set_enabled_card_family(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         self.price
             .to_owned()
             .and_then(|x| Some(request.set_price(x)));
@@ -2403,7 +2403,7 @@ impl Builder for OrderItemBuilder {
             .and_then(|x| Some(order_item.set_category2(x)));
         self.item_type
             .to_owned()
-            .and_then(|x| Some(order_item.set_item_type(x)));
+            .and_then(|x| Some(order_item.set_item_type_raw(x)));
         self.item_url
             .to_owned()
             .and_then(|x| Some(order_item.set_item_url(x)));
@@ -2455,7 +2455,7 @@ impl Builder for CardManagementRetrieveCardBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -2508,7 +2508,7 @@ impl Builder for RetrieveIyziupFormRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -2631,7 +2631,7 @@ impl Builder for CreatePeccoInitializeRequestBuilder {
         self.base
             .get_locale()
             .to_owned()
-            .and_then(|x| Some(request.set_locale(x)));
+            .and_then(|x| Some(request.set_locale_raw(x)));
         self.base
             .get_conversation_id()
             .to_owned()
@@ -2644,13 +2644,13 @@ impl Builder for CreatePeccoInitializeRequestBuilder {
             .and_then(|x| Some(request.set_paid_price(x)));
         self.currency
             .to_owned()
-            .and_then(|x| Some(request.set_currency(x)));
+            .and_then(|x| Some(request.set_currency_raw(x)));
         self.basket_id
             .to_owned()
             .and_then(|x| Some(request.set_basket_id(x)));
         self.payment_group
             .to_owned()
-            .and_then(|x| Some(request.set_payment_group(x)));
+            .and_then(|x| Some(request.set_payment_group_raw(x)));
         self.payment_source
             .to_owned()
             .and_then(|x| Some(request.set_payment_source(x)));
@@ -1,7 +1,10 @@
 use log::debug;
 
 use iyzipay_rust::model::Card;
+use iyzipay_rust::model::CardAssociation;
+use iyzipay_rust::model::CardFamily;
 use iyzipay_rust::model::CardList;
+use iyzipay_rust::model::CardType;
 use iyzipay_rust::model::Locale;
 use iyzipay_rust::model::Status;
 use iyzipay_rust::requests::DeleteCardRequest;
@@ -37,9 +40,9 @@ fn should_create_user_and_add_card() {
     assert_eq!(None, card.error_group());
     assert_eq!(Some(&String::from("552879")), card.bin_number());
     assert_eq!(Some(&String::from("card alias")), card.card_alias());
-    assert_eq!(Some(&String::from("CREDIT_CARD")), card.card_type());
-    assert_eq!(Some(&String::from("MASTER_CARD")), card.card_association());
-    assert_eq!(Some(&String::from("Paraf")), card.card_family());
+    assert_eq!(Some(&CardType::CreditCard), card.card_type());
+    assert_eq!(Some(&CardAssociation::MasterCard), card.card_association());
+    assert_eq!(Some(&CardFamily::Paraf), card.card_family());
     assert_eq!(Some(&String::from("Halk Bankası")), card.card_bank_name());
     assert_eq!(Some(&i64::from(12)), card.card_bank_code());
 }
@@ -76,9 +79,9 @@ fn should_create_card_and_add_card_to_existing_user() {
     assert_eq!(None, card.error_group());
     assert_eq!(Some(&String::from("552879")), card.bin_number());
     assert_eq!(Some(&String::from("card alias")), card.card_alias());
-    assert_eq!(Some(&String::from("CREDIT_CARD")), card.card_type());
-    assert_eq!(Some(&String::from("MASTER_CARD")), card.card_association());
-    assert_eq!(Some(&String::from("Paraf")), card.card_family());
+    assert_eq!(Some(&CardType::CreditCard), card.card_type());
+    assert_eq!(Some(&CardAssociation::MasterCard), card.card_association());
+    assert_eq!(Some(&CardFamily::Paraf), card.card_family());
     assert_eq!(Some(&String::from("Halk Bankası")), card.card_bank_name());
     assert_eq!(Some(&String::from(external_user_id)), card.external_id());
     assert_eq!(Some(&i64::from(12)), card.card_bank_code());
@@ -122,7 +125,7 @@ fn should_retrieve_card() {
     let card: Card = create_card();
 
     let mut request: RetrieveCardListRequest = RetrieveCardListRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_card_user_key(card.card_user_key().unwrap().to_owned());
 
@@ -0,0 +1,66 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use iyzipay_rust::model::CrossBookingFromSubMerchant;
+use iyzipay_rust::model::CrossBookingToSubMerchant;
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::model::SubMerchant;
+use iyzipay_rust::requests::CreateCrossBookingRequest;
+
+use crate::functional::builder::Builder;
+use crate::functional::builder::CreateSubMerchantRequestBuilder;
+use crate::get_test_options;
+
+fn cross_booking_request() -> CreateCrossBookingRequest {
+    let sub_merchant_request = CreateSubMerchantRequestBuilder::create()
+        .personal_sub_merchant_request()
+        .build();
+    let sub_merchant = SubMerchant::create(&sub_merchant_request, &get_test_options()).unwrap();
+
+    let mut request = CreateCrossBookingRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_sub_merchant_key(sub_merchant.sub_merchant_key().unwrap().as_str());
+    request.set_price(BigDecimal::from_str("1").unwrap());
+    request.set_reason("reason text");
+    request.set_currency(Currency::TRY);
+    request
+}
+
+#[test]
+fn should_send_cross_booking_to_sub_merchant() {
+    let _ = env_logger::try_init();
+    let request = cross_booking_request();
+
+    let cross_booking = CrossBookingToSubMerchant::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", cross_booking);
+
+    assert_eq!(Some(&Status::Success.to_string()), cross_booking.status());
+    assert_eq!(Some(&Locale::TR.to_string()), cross_booking.locale());
+    assert_ne!(None, cross_booking.system_time());
+    assert_eq!(None, cross_booking.error_code());
+    assert_eq!(None, cross_booking.error_message());
+    assert_eq!(None, cross_booking.error_group());
+}
+
+#[test]
+fn should_receive_cross_booking_from_sub_merchant() {
+    let _ = env_logger::try_init();
+    let request = cross_booking_request();
+
+    let cross_booking = CrossBookingFromSubMerchant::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", cross_booking);
+
+    assert_eq!(Some(&Status::Success.to_string()), cross_booking.status());
+    assert_eq!(Some(&Locale::TR.to_string()), cross_booking.locale());
+    assert_ne!(None, cross_booking.system_time());
+    assert_eq!(None, cross_booking.error_code());
+    assert_eq!(None, cross_booking.error_message());
+    assert_eq!(None, cross_booking.error_group());
+}
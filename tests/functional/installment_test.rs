@@ -4,6 +4,9 @@ use bigdecimal::BigDecimal;
 use bigdecimal::Zero;
 use log::debug;
 
+use iyzipay_rust::model::CardAssociation;
+use iyzipay_rust::model::CardFamily;
+use iyzipay_rust::model::CardType;
 use iyzipay_rust::model::InstallmentInfo;
 use iyzipay_rust::model::Locale;
 use iyzipay_rust::model::Status;
@@ -16,7 +19,7 @@ fn should_retrieve_installments() {
     let _ = env_logger::try_init();
     let mut request: RetrieveInstallmentInfoRequest = RetrieveInstallmentInfoRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_bin_number("554960");
     request.set_price(BigDecimal::from_str("100").unwrap());
@@ -47,15 +50,15 @@ fn should_retrieve_installments() {
         installment_details[0].price()
     );
     assert_eq!(
-        Some(&String::from("CREDIT_CARD")),
+        Some(&CardType::CreditCard),
         installment_details[0].card_type()
     );
     assert_eq!(
-        Some(&String::from("MASTER_CARD")),
+        Some(&CardAssociation::MasterCard),
         installment_details[0].card_association()
     );
     assert_eq!(
-        Some(&String::from("Bonus")),
+        Some(&CardFamily::Bonus),
         installment_details[0].card_family_name()
     );
     assert_ne!(None, installment_details[0].installment_prices());
@@ -24,7 +24,7 @@ use crate::get_test_options;
 fn should_create_iyzi_link() {
     let _ = env_logger::try_init();
     let mut request = IyziLinkSaveRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_name("ft-name");
     request.set_description("ft-description");
@@ -32,7 +32,7 @@ fn should_create_iyzi_link() {
     path.push("tests/images/sample_image.jpg");
     request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     request.set_price(BigDecimal::one());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_address_ignorable(false);
     request.set_sold_limit(1);
     request.set_installment_requested(false);
@@ -50,7 +50,7 @@ fn should_create_iyzi_link() {
 fn should_update_iyzi_link() {
     let _ = env_logger::try_init();
     let mut create_request: IyziLinkSaveRequest = IyziLinkSaveRequest::new();
-    create_request.set_locale(Locale::TR.value());
+    create_request.set_locale(Locale::TR);
     create_request.set_conversation_id("123456789");
     create_request.set_name("ft-name");
     create_request.set_description("ft-description");
@@ -58,7 +58,7 @@ fn should_update_iyzi_link() {
     path.push("tests/images/sample_image.jpg");
     create_request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     create_request.set_price(BigDecimal::one());
-    create_request.set_currency(Currency::TRY.value());
+    create_request.set_currency(Currency::TRY);
     create_request.set_address_ignorable(false);
     create_request.set_sold_limit(1);
     create_request.set_installment_requested(false);
@@ -72,12 +72,12 @@ fn should_update_iyzi_link() {
         .to_string();
 
     let mut request: IyziLinkSaveRequest = IyziLinkSaveRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_name("ft-name-updated");
     request.set_description("ft-description-updated");
     request.set_price(BigDecimal::from_str("10").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let response: IyziLinkSaveResource =
         IyziLink::update(token.as_str(), &request, &get_test_options()).unwrap();
@@ -96,7 +96,7 @@ fn should_update_iyzi_link() {
 fn should_retrieve_iyzi_links_with_pagination() {
     let _ = env_logger::try_init();
     let mut create_request = IyziLinkSaveRequest::new();
-    create_request.set_locale(Locale::TR.value());
+    create_request.set_locale(Locale::TR);
     create_request.set_conversation_id("123456789");
     create_request.set_name("ft-name");
     create_request.set_description("ft-description");
@@ -104,7 +104,7 @@ fn should_retrieve_iyzi_links_with_pagination() {
     path.push("tests/images/sample_image.jpg");
     create_request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     create_request.set_price(BigDecimal::one());
-    create_request.set_currency(Currency::TRY.value());
+    create_request.set_currency(Currency::TRY);
     create_request.set_address_ignorable(false);
     create_request.set_sold_limit(1);
     create_request.set_installment_requested(false);
@@ -114,7 +114,7 @@ fn should_retrieve_iyzi_links_with_pagination() {
     let mut paging_request = PagingRequest::new();
     paging_request.set_page(Some(1));
     paging_request.set_count(Some(1));
-    paging_request.set_locale(Locale::TR.value());
+    paging_request.set_locale(Locale::TR);
     paging_request.set_conversation_id("123456789");
 
     let response: IyziLinkPagingResource =
@@ -134,7 +134,7 @@ fn should_retrieve_iyzi_links_with_pagination() {
 fn should_retrieve_iyzi_link_with_token() {
     let _ = env_logger::try_init();
     let mut create_request = IyziLinkSaveRequest::new();
-    create_request.set_locale(Locale::TR.value());
+    create_request.set_locale(Locale::TR);
     create_request.set_conversation_id("123456789");
     create_request.set_name("ft-name");
     create_request.set_description("ft-description");
@@ -142,7 +142,7 @@ fn should_retrieve_iyzi_link_with_token() {
     path.push("tests/images/sample_image.jpg");
     create_request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     create_request.set_price(BigDecimal::one());
-    create_request.set_currency(Currency::TRY.value());
+    create_request.set_currency(Currency::TRY);
     create_request.set_address_ignorable(false);
     create_request.set_sold_limit(1);
     create_request.set_installment_requested(false);
@@ -190,7 +190,7 @@ fn should_retrieve_iyzi_link_with_token() {
 pub fn should_delete_iyzi_link() {
     let _ = env_logger::try_init();
     let mut create_request = IyziLinkSaveRequest::new();
-    create_request.set_locale(Locale::TR.value());
+    create_request.set_locale(Locale::TR);
     create_request.set_conversation_id("123456789");
     create_request.set_name("ft-name");
     create_request.set_description("ft-description");
@@ -198,7 +198,7 @@ pub fn should_delete_iyzi_link() {
     path.push("tests/images/sample_image.jpg");
     create_request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     create_request.set_price(BigDecimal::one());
-    create_request.set_currency(Currency::TRY.value());
+    create_request.set_currency(Currency::TRY);
     create_request.set_address_ignorable(false);
     create_request.set_sold_limit(1);
     create_request.set_installment_requested(false);
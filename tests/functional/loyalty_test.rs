@@ -0,0 +1,39 @@
+use log::debug;
+
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::Loyalty;
+use iyzipay_rust::model::PaymentCard;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::requests::RetrieveLoyaltyRequest;
+
+use crate::get_test_options;
+
+#[test]
+fn should_retrieve_loyalty_points() {
+    let _ = env_logger::try_init();
+    let mut payment_card = PaymentCard::new();
+    payment_card.set_card_holder_name("John Doe");
+    payment_card.set_card_number("5451030000000000");
+    payment_card.set_expire_month("12");
+    payment_card.set_expire_year("2030");
+    payment_card.set_cvc("123");
+
+    let mut request = RetrieveLoyaltyRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_payment_card(payment_card);
+    request.set_currency(Currency::TRY);
+
+    let loyalty = Loyalty::retrieve(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", loyalty);
+
+    assert_eq!(Some(&Status::Success.to_string()), loyalty.status());
+    assert_eq!(Some(&Locale::TR.to_string()), loyalty.locale());
+    assert_ne!(None, loyalty.points());
+    assert_ne!(None, loyalty.amount());
+    assert_eq!(None, loyalty.error_code());
+    assert_eq!(None, loyalty.error_message());
+    assert_eq!(None, loyalty.error_group());
+}
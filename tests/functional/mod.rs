@@ -2,6 +2,7 @@ pub use self::util::RandomGenerator;
 
 mod api_test;
 mod approve_test;
+mod basic_payment_test;
 mod bin_number_test;
 mod bkm_test;
 mod builder;
@@ -10,14 +11,19 @@ mod card_management_page_retrieve_card_test;
 mod card_management_page_test;
 mod card_storage_test;
 mod checkout_form_test;
+mod cross_booking_test;
 mod disapprove_test;
 mod installment_test;
 mod iyzilink_test;
 mod iyziup_form_test;
+mod loyalty_test;
 mod payment_test;
 mod payout_completed_transaction_test;
 mod pecco_test;
+mod pre_auth_test;
 mod refund_test;
+mod reporting_test;
 mod sub_merchant_test;
+mod subscription_test;
 mod threeds_test;
 mod util;
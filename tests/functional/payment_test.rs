@@ -185,7 +185,7 @@ fn should_retrieve_payment() {
 
     let mut request = RetrievePaymentRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id(created_payment.payment_id().unwrap().to_owned());
 
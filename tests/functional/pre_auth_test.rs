@@ -0,0 +1,117 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use iyzipay_rust::model::Cancel;
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::PaymentPostAuth;
+use iyzipay_rust::model::PaymentPreAuth;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::model::ThreedsInitializePreAuth;
+use iyzipay_rust::requests::CreatePaymentPostAuthRequest;
+
+use crate::functional::builder::Builder;
+use crate::functional::builder::CreatePaymentRequestBuilder;
+use crate::get_test_options;
+
+#[test]
+fn should_create_pre_auth_and_capture_it() {
+    let _ = env_logger::try_init();
+    let request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+    let pre_auth: PaymentPreAuth = PaymentPreAuth::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", pre_auth);
+
+    assert_eq!(Some(&Status::Success.to_string()), pre_auth.status());
+    assert_ne!(None, pre_auth.payment_id());
+
+    let post_auth_request = CreatePaymentPostAuthRequest::builder()
+        .payment_id(pre_auth.payment_id().unwrap().to_owned())
+        .paid_price(BigDecimal::from_str("1.1").unwrap())
+        .currency(Currency::TRY)
+        .ip("85.34.78.112")
+        .build();
+    let post_auth: PaymentPostAuth =
+        PaymentPostAuth::create(&post_auth_request, &get_test_options()).unwrap();
+
+    debug!("{:?}", post_auth);
+
+    assert_eq!(Some(&Locale::TR.to_string()), post_auth.locale());
+    assert_eq!(Some(&Status::Success.to_string()), post_auth.status());
+    assert_eq!(pre_auth.payment_id(), post_auth.payment_id());
+    assert_eq!(
+        post_auth.paid_price().unwrap(),
+        &BigDecimal::from_str("1.1").unwrap()
+    );
+    assert_ne!(None, post_auth.system_time());
+    assert_eq!(None, post_auth.error_code());
+    assert_eq!(None, post_auth.error_message());
+    assert_eq!(None, post_auth.error_group());
+}
+
+#[test]
+fn should_capture_part_of_pre_auth() {
+    let _ = env_logger::try_init();
+    let request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+    let pre_auth: PaymentPreAuth = PaymentPreAuth::create(&request, &get_test_options()).unwrap();
+
+    let post_auth_request = CreatePaymentPostAuthRequest::builder()
+        .payment_id(pre_auth.payment_id().unwrap().to_owned())
+        .paid_price(BigDecimal::from_str("0.5").unwrap())
+        .currency(Currency::TRY)
+        .build();
+    let post_auth: PaymentPostAuth =
+        PaymentPostAuth::create(&post_auth_request, &get_test_options()).unwrap();
+
+    debug!("{:?}", post_auth);
+
+    assert_eq!(Some(&Status::Success.to_string()), post_auth.status());
+    assert_eq!(
+        post_auth.paid_price().unwrap(),
+        &BigDecimal::from_str("0.5").unwrap()
+    );
+}
+
+#[test]
+fn should_void_pre_auth() {
+    let _ = env_logger::try_init();
+    let request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+    let pre_auth: PaymentPreAuth = PaymentPreAuth::create(&request, &get_test_options()).unwrap();
+    let cancel: Cancel = pre_auth.void(&get_test_options()).unwrap();
+
+    debug!("{:?}", cancel);
+
+    assert_eq!(Some(&Status::Success.to_string()), cancel.status());
+    assert_eq!(pre_auth.payment_id(), cancel.payment_id());
+    assert_eq!(Some(&String::from("123456789")), cancel.conversation_id());
+    assert_eq!(None, cancel.error_code());
+}
+
+#[test]
+fn should_initialize_threeds_pre_auth() {
+    let _ = env_logger::try_init();
+    let request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .callback_url("https://www.merchant.com/callback")
+        .build();
+    let threeds_initialize =
+        ThreedsInitializePreAuth::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", threeds_initialize);
+
+    assert_eq!(
+        Some(&Status::Success.to_string()),
+        threeds_initialize.status()
+    );
+    assert_ne!(None, threeds_initialize.system_time());
+    assert_ne!(None, threeds_initialize.html_content());
+    assert_eq!(None, threeds_initialize.error_code());
+}
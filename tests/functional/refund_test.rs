@@ -3,13 +3,17 @@ use std::str::FromStr;
 use bigdecimal::BigDecimal;
 use log::debug;
 
+use iyzipay_rust::model::AmountBasedRefund;
 use iyzipay_rust::model::Currency;
 use iyzipay_rust::model::Locale;
 use iyzipay_rust::model::Payment;
 use iyzipay_rust::model::Refund;
 use iyzipay_rust::model::RefundReason;
+use iyzipay_rust::model::RefundToBalance;
 use iyzipay_rust::model::Status;
+use iyzipay_rust::requests::CreateAmountBasedRefundRequest;
 use iyzipay_rust::requests::CreateRefundRequest;
+use iyzipay_rust::requests::CreateRefundToBalanceRequest;
 
 use crate::functional::builder::Builder;
 use crate::functional::builder::CreatePaymentRequestBuilder;
@@ -24,7 +28,7 @@ fn should_refund_payment() {
     let payment = Payment::create(&payment_request, &get_test_options()).unwrap();
 
     let mut request = CreateRefundRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id(
         payment.payment_items().unwrap()[0]
@@ -33,7 +37,7 @@ fn should_refund_payment() {
             .to_owned(),
     );
     request.set_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_ip("85.34.78.112");
 
     let refund: Refund = Refund::create(&request, &get_test_options()).unwrap();
@@ -66,7 +70,7 @@ fn should_refund_fraudulent_payment() {
     let payment = Payment::create(&payment_request, &get_test_options()).unwrap();
 
     let mut request = CreateRefundRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id(
         payment.payment_items().unwrap()[0]
@@ -75,7 +79,7 @@ fn should_refund_fraudulent_payment() {
             .to_owned(),
     );
     request.set_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_ip("85.34.78.112");
     request.set_reason(RefundReason::Fraud);
     request.set_description("stolen card request with 11000 try payment for default sample");
@@ -99,3 +103,55 @@ fn should_refund_fraudulent_payment() {
     assert_eq!(None, refund.error_message());
     assert_eq!(None, refund.error_group());
 }
+
+#[test]
+fn should_refund_amount_of_payment() {
+    let _ = env_logger::try_init();
+    let payment_request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+
+    let payment = Payment::create(&payment_request, &get_test_options()).unwrap();
+
+    let mut request = CreateAmountBasedRefundRequest::builder()
+        .payment_id(payment.payment_id().unwrap().as_str())
+        .price(BigDecimal::from_str("0.5").unwrap())
+        .ip("85.34.78.112")
+        .reason(RefundReason::BuyerRequest)
+        .build();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+
+    let refund = AmountBasedRefund::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", refund);
+
+    assert_eq!(Some(&Status::Success.to_string()), refund.status());
+    assert_eq!(payment.payment_id(), refund.payment_id());
+    assert_eq!(Some(&BigDecimal::from_str("0.5").unwrap()), refund.price());
+    assert_eq!(None, refund.error_code());
+}
+
+#[test]
+fn should_refund_payment_to_balance() {
+    let _ = env_logger::try_init();
+    let payment_request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+
+    let payment = Payment::create(&payment_request, &get_test_options()).unwrap();
+
+    let mut request = CreateRefundToBalanceRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_payment_id(payment.payment_id().unwrap().as_str());
+    request.set_callback_url("https://www.merchant.com/callback");
+
+    let refund = RefundToBalance::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", refund);
+
+    assert_eq!(Some(&Status::Success.to_string()), refund.status());
+    assert_ne!(None, refund.token());
+    assert_ne!(None, refund.url());
+}
@@ -0,0 +1,55 @@
+use chrono::Utc;
+use log::debug;
+
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::Payment;
+use iyzipay_rust::model::PaymentDetailsReport;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::model::TransactionReport;
+use iyzipay_rust::requests::RetrievePaymentDetailsRequest;
+use iyzipay_rust::requests::RetrieveTransactionReportRequest;
+
+use crate::functional::builder::Builder;
+use crate::functional::builder::CreatePaymentRequestBuilder;
+use crate::get_test_options;
+
+#[test]
+fn should_retrieve_every_page_of_transaction_report() {
+    let _ = env_logger::try_init();
+    let mut request = RetrieveTransactionReportRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_transaction_date(Utc::now());
+
+    for report in TransactionReport::pages(&request, &get_test_options()) {
+        let report = report.unwrap();
+
+        debug!("{:?}", report);
+
+        assert_eq!(Some(&Status::Success.to_string()), report.status());
+    }
+}
+
+#[test]
+fn should_retrieve_payment_details() {
+    let _ = env_logger::try_init();
+    let payment_request = CreatePaymentRequestBuilder::create()
+        .standard_listing_payment()
+        .build();
+    let payment = Payment::create(&payment_request, &get_test_options()).unwrap();
+
+    let mut request = RetrievePaymentDetailsRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_payment_id(payment.payment_id().unwrap().as_str());
+
+    let report = PaymentDetailsReport::retrieve(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", report);
+
+    assert_eq!(Some(&Status::Success.to_string()), report.status());
+    assert_eq!(
+        payment.payment_id(),
+        report.payments().unwrap()[0].payment_id()
+    );
+}
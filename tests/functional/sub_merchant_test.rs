@@ -240,3 +240,24 @@ fn should_retrieve_sub_merchant() {
     assert_eq!(None, sub_merchant.error_message());
     assert_eq!(None, sub_merchant.error_group());
 }
+
+#[test]
+fn should_init_settlement_to_balance() {
+    let _ = env_logger::try_init();
+    let request = CreateSubMerchantRequestBuilder::create()
+        .personal_sub_merchant_request()
+        .build();
+    let sub_merchant = SubMerchant::create(&request, &get_test_options()).unwrap();
+
+    let settlement = sub_merchant
+        .init_settlement_to_balance("https://www.merchant.com/callback", &get_test_options())
+        .unwrap();
+
+    debug!("{:?}", settlement);
+
+    assert_eq!(Some(&Status::Success.to_string()), settlement.status());
+    assert_eq!(Some(&Locale::TR.to_string()), settlement.locale());
+    assert_ne!(None, settlement.token());
+    assert_ne!(None, settlement.url());
+    assert_eq!(None, settlement.error_code());
+}
@@ -0,0 +1,141 @@
+use bigdecimal::BigDecimal;
+use log::debug;
+
+use iyzipay_rust::model::Address;
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::PaymentCard;
+use iyzipay_rust::model::PaymentInterval;
+use iyzipay_rust::model::PricingPlan;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::model::Subscription;
+use iyzipay_rust::model::SubscriptionCheckoutForm;
+use iyzipay_rust::model::SubscriptionProduct;
+use iyzipay_rust::model::SubscriptionStatus;
+use iyzipay_rust::requests::CreatePricingPlanRequest;
+use iyzipay_rust::requests::CreateSubscriptionInitializeRequest;
+use iyzipay_rust::requests::CreateSubscriptionProductRequest;
+use iyzipay_rust::requests::Request;
+use iyzipay_rust::requests::SearchSubscriptionsRequest;
+use iyzipay_rust::requests::SubscriptionCustomerDetails;
+
+use crate::functional::RandomGenerator;
+use crate::get_test_options;
+
+fn create_pricing_plan() -> String {
+    let mut product_request = CreateSubscriptionProductRequest::new();
+    product_request.set_locale(Locale::TR);
+    product_request.set_conversation_id("123456789");
+    product_request.set_name(format!("ft-product-{}", RandomGenerator::random_id()));
+    product_request.set_description("ft-description");
+    let product = SubscriptionProduct::create(&product_request, &get_test_options()).unwrap();
+
+    let mut plan_request = CreatePricingPlanRequest::new();
+    plan_request.set_locale(Locale::TR);
+    plan_request.set_conversation_id("123456789");
+    plan_request.set_name(format!("ft-plan-{}", RandomGenerator::random_id()));
+    plan_request.set_price(BigDecimal::from(10));
+    plan_request.set_currency(Currency::TRY);
+    plan_request.set_payment_interval(PaymentInterval::Monthly);
+    plan_request.set_payment_interval_count(1u32);
+    let plan = PricingPlan::create(
+        product.data().unwrap().reference_code().unwrap(),
+        &plan_request,
+        &get_test_options(),
+    )
+    .unwrap();
+
+    plan.data().unwrap().reference_code().unwrap().to_owned()
+}
+
+fn customer() -> SubscriptionCustomerDetails {
+    let mut address = Address::new();
+    address.set_contact_name("Jane Doe");
+    address.set_city("Istanbul");
+    address.set_country("Turkey");
+    address.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
+    address.set_zip_code("34742");
+    let mut customer = SubscriptionCustomerDetails::new();
+    customer.set_name("John");
+    customer.set_surname("Doe");
+    customer.set_identity_number("74300864791");
+    customer.set_email(format!("{}@email.com", RandomGenerator::random_id()));
+    customer.set_gsm_number("+905350000000");
+    customer.set_billing_address(address.clone());
+    customer.set_shipping_address(address);
+    customer
+}
+
+#[test]
+fn should_create_subscription_product() {
+    let _ = env_logger::try_init();
+    let mut request = CreateSubscriptionProductRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_name(format!("ft-product-{}", RandomGenerator::random_id()));
+    request.set_description("ft-description");
+
+    let response = SubscriptionProduct::create(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", response);
+
+    assert_eq!(Some(&Status::Success.to_string()), response.status());
+    assert_ne!(None, response.data().unwrap().reference_code());
+}
+
+#[test]
+fn should_initialize_and_cancel_subscription() {
+    let _ = env_logger::try_init();
+    let mut payment_card = PaymentCard::new();
+    payment_card.set_card_holder_name("John Doe");
+    payment_card.set_card_number("5528790000000008");
+    payment_card.set_expire_month("12");
+    payment_card.set_expire_year("2030");
+    payment_card.set_cvc("123");
+    let mut request = CreateSubscriptionInitializeRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_pricing_plan_reference_code(create_pricing_plan());
+    request.set_subscription_initial_status(SubscriptionStatus::Active);
+    request.set_customer(customer());
+    request.set_payment_card(payment_card);
+
+    let response = Subscription::initialize(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", response);
+
+    assert_eq!(Some(&Status::Success.to_string()), response.status());
+    let reference_code = response.data().unwrap().reference_code().unwrap();
+
+    let mut search_request = SearchSubscriptionsRequest::new();
+    search_request.set_subscription_reference_code(reference_code.as_str());
+    let search = Subscription::search(&search_request, &get_test_options()).unwrap();
+
+    assert_eq!(Some(&Status::Success.to_string()), search.status());
+    assert_eq!(Some(&1), search.data().unwrap().total_count());
+
+    let cancel =
+        Subscription::cancel(reference_code, &Request::default(), &get_test_options()).unwrap();
+
+    assert_eq!(Some(&Status::Success.to_string()), cancel.status());
+}
+
+#[test]
+fn should_initialize_subscription_checkout_form() {
+    let _ = env_logger::try_init();
+    let mut request = CreateSubscriptionInitializeRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_callback_url("https://www.merchant.com/callback");
+    request.set_pricing_plan_reference_code(create_pricing_plan());
+    request.set_subscription_initial_status(SubscriptionStatus::Active);
+    request.set_customer(customer());
+
+    let response = SubscriptionCheckoutForm::initialize(&request, &get_test_options()).unwrap();
+
+    debug!("{:?}", response);
+
+    assert_eq!(Some(&Status::Success.to_string()), response.status());
+    assert_ne!(None, response.token());
+    assert_ne!(None, response.checkout_form_content());
+}
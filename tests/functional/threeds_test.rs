@@ -78,7 +78,7 @@ fn should_auth_threeds() {
     let mut create_threeds_payment_request = CreateThreedsPaymentRequest::new();
     create_threeds_payment_request.set_conversation_data("conversion data");
     create_threeds_payment_request.set_payment_id("1");
-    create_threeds_payment_request.set_locale(Locale::TR.value());
+    create_threeds_payment_request.set_locale(Locale::TR);
     create_threeds_payment_request.set_conversation_id("123456789");
 
     let threeds_payment =
@@ -0,0 +1,106 @@
+pub mod iban_test {
+    use iyzipay_rust::model::Iban;
+    use iyzipay_rust::model::IbanError;
+    use iyzipay_rust::model::SubMerchant;
+    use iyzipay_rust::options::Options;
+    use iyzipay_rust::requests::CreateSubMerchantRequest;
+    use iyzipay_rust::requests::UpdateSubMerchantRequest;
+    use iyzipay_rust::requests::Validate;
+    use iyzipay_rust::requests::ValidationErrors;
+
+    #[test]
+    fn should_parse_iban() {
+        let iban = Iban::parse(" tr18 0006 2001 1900 0006 6723 15 ").unwrap();
+
+        assert_eq!("TR180006200119000006672315", iban.value());
+        assert_eq!("TR", iban.country_code());
+        assert_eq!("18", iban.check_digits());
+        assert_eq!("0006200119000006672315", iban.bban());
+        assert_eq!("TR18 0006 2001 1900 0006 6723 15", iban.formatted());
+        assert_eq!(iban, "TR180006200119000006672315".parse::<Iban>().unwrap());
+
+        let iban = Iban::parse("DE89 3704 0044 0532 0130 00").unwrap();
+        assert_eq!("DE", iban.country_code());
+        assert_eq!(None, iban.bank_code());
+        assert!(Iban::parse("GB82WEST12345698765432").is_ok());
+    }
+
+    #[test]
+    fn should_reject_invalid_iban() {
+        assert_eq!(
+            Err(IbanError::InvalidChecksum),
+            Iban::parse("TR180006200119000006672316")
+        );
+        assert_eq!(
+            Err(IbanError::InvalidLength {
+                expected: String::from("26"),
+                found: 25
+            }),
+            Iban::parse("TR18000620011900000667231")
+        );
+        assert_eq!(
+            Err(IbanError::InvalidCharacters),
+            Iban::parse("TR18-0006-2001-1900-0006-6723-15")
+        );
+        assert_eq!(Err(IbanError::InvalidCharacters), Iban::parse("1818"));
+        assert!(Iban::parse("DE8937040044").is_err());
+    }
+
+    #[test]
+    fn should_identify_turkish_bank() {
+        let iban = Iban::parse("TR180006200119000006672315").unwrap();
+
+        assert_eq!(Some("00062"), iban.bank_code());
+        assert_eq!(Some("Türkiye Garanti Bankası"), iban.bank_name());
+    }
+
+    #[test]
+    fn should_validate_sub_merchant_iban() {
+        let mut request = CreateSubMerchantRequest::new();
+        request.set_iban("tr18 0006 2001 1900 0006 6723 15");
+        assert_eq!(
+            Some(&String::from("TR180006200119000006672315")),
+            request.iban()
+        );
+
+        let mut update_request = UpdateSubMerchantRequest::new();
+        update_request.set_sub_merchant_key("sub merchant key");
+        assert!(update_request.validate().is_ok());
+
+        update_request.set_iban("TR180006200119000006672316");
+        let errors = update_request.validate().unwrap_err();
+        assert_eq!(
+            "iban: has an invalid check digit",
+            errors.violations()[0].to_string()
+        );
+    }
+
+    #[test]
+    fn should_not_send_sub_merchant_with_invalid_iban() {
+        let mut options = Options::new();
+        options.set_base_url("http://127.0.0.1:9");
+        options.set_validate_requests(true);
+        let mut request = UpdateSubMerchantRequest::new();
+        request.set_sub_merchant_key("sub merchant key");
+        request.set_iban("TR180006200119000006672316");
+
+        let error = SubMerchant::update(&request, &options).unwrap_err();
+
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(
+            "iban: has an invalid check digit",
+            errors.violations()[0].to_string()
+        );
+
+        let mut create_request = CreateSubMerchantRequest::new();
+        create_request.set_iban("TR180006200119000006672316");
+
+        let error = SubMerchant::create(&create_request, &options).unwrap_err();
+
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert!(errors
+            .violations()
+            .iter()
+            .any(|violation| violation.to_string() == "iban: has an invalid check digit"));
+    }
+}
@@ -0,0 +1,105 @@
+pub mod identity_test {
+    use iyzipay_rust::model::is_valid_foreign_identity_number;
+    use iyzipay_rust::model::is_valid_tckn;
+    use iyzipay_rust::model::is_valid_vkn;
+    use iyzipay_rust::model::Buyer;
+    use iyzipay_rust::model::IdentityNumberType;
+    use iyzipay_rust::model::SubMerchantType;
+    use iyzipay_rust::requests::CreateSubMerchantRequest;
+    use iyzipay_rust::requests::Validate;
+
+    fn paths(request: &dyn Validate) -> Vec<String> {
+        request
+            .validate()
+            .err()
+            .map(|errors| {
+                errors
+                    .violations()
+                    .iter()
+                    .map(|violation| violation.path().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn should_check_tckn() {
+        assert!(is_valid_tckn("10000000146"));
+        assert!(is_valid_tckn("12345678950"));
+        assert!(is_valid_tckn("99999999990"));
+        assert!(!is_valid_tckn("12345678951"));
+        assert!(!is_valid_tckn("02345678950"));
+        assert!(!is_valid_tckn("1234567895"));
+        assert!(!is_valid_tckn("1234567895a"));
+    }
+
+    #[test]
+    fn should_check_vkn() {
+        assert!(is_valid_vkn("1234567890"));
+        assert!(is_valid_vkn("9261877003"));
+        assert!(!is_valid_vkn("1234567891"));
+        assert!(!is_valid_vkn("926187700"));
+        assert!(!is_valid_vkn("12345678950"));
+    }
+
+    #[test]
+    fn should_check_foreign_identity_number() {
+        assert!(is_valid_foreign_identity_number("U12345678"));
+        assert!(!is_valid_foreign_identity_number("U123"));
+        assert!(!is_valid_foreign_identity_number("U 12345678"));
+    }
+
+    #[test]
+    fn should_validate_buyer_identity_number() {
+        let mut buyer = Buyer::new();
+        buyer.set_identity_number("10000000146");
+        assert!(paths(&buyer).is_empty());
+
+        buyer.set_identity_number("74300864791");
+        assert_eq!(vec!["identityNumber"], paths(&buyer));
+
+        buyer.set_foreign_identity_number("U12345678");
+        assert_eq!(IdentityNumberType::Foreign, buyer.identity_number_type());
+        assert!(paths(&buyer).is_empty());
+        assert!(!serde_json::to_string(&buyer)
+            .unwrap()
+            .contains("identityNumberType"));
+
+        assert_eq!(vec!["identityNumber"], paths(&Buyer::new()));
+    }
+
+    #[test]
+    fn should_validate_sub_merchant_numbers_per_type() {
+        let mut personal = CreateSubMerchantRequest::new();
+        personal.set_iban("TR180006200119000006672315");
+        personal.set_sub_merchant_type(SubMerchantType::Personal);
+        personal.set_identity_number("31300864726");
+        assert_eq!(vec!["identityNumber"], paths(&personal));
+        personal.set_identity_number("10000000146");
+        assert!(paths(&personal).is_empty());
+
+        let mut private_company = CreateSubMerchantRequest::new();
+        private_company.set_iban("TR180006200119000006672315");
+        private_company.set_sub_merchant_type(SubMerchantType::PrivateCompany);
+        private_company.set_foreign_identity_number("U12345678");
+        assert_eq!(
+            vec!["taxOffice", "legalCompanyTitle"],
+            paths(&private_company)
+        );
+
+        let mut limited_company = CreateSubMerchantRequest::new();
+        limited_company.set_iban("TR180006200119000006672315");
+        limited_company.set_sub_merchant_type(SubMerchantType::LimitedOrJointStockCompany);
+        limited_company.set_tax_office("Tax office");
+        limited_company.set_legal_company_title("XYZ inc");
+        limited_company.set_tax_number("9261877");
+        assert_eq!(vec!["taxNumber"], paths(&limited_company));
+        limited_company.set_tax_number("9261877003");
+        assert!(paths(&limited_company).is_empty());
+
+        assert_eq!(
+            vec!["iban", "subMerchantType", "identityNumber"],
+            paths(&CreateSubMerchantRequest::new())
+        );
+    }
+}
@@ -15,4 +15,46 @@ pub mod iyzi_auth_v2_generator_test {
     fn should_generate_hash_when_given_request_body_is_empty_request() {
         assert_eq!("YXBpS2V5OmFwaUtleSZyYW5kb21LZXk6cmFuZG9tJnNpZ25hdHVyZTpjOWU1OTI2NjE4ODNlY2NkYjEzYmEwOGFhYTdhNTJiMDhmZTFkNDhkZTU2OGZmNDgxZDZmOGM3ZWFkMjkzN2Uy", IyziAuthV2Generator::generate_auth_content("/v2/uri?test=true", "apiKey", "secretKey", "random", ""));
     }
+
+    #[test]
+    fn should_generate_hash_for_v1_endpoint_uri() {
+        assert_eq!(
+            IyziAuthV2Generator::generate_auth_content(
+                "/payment/bin/check",
+                "apiKey",
+                "secretKey",
+                "random",
+                "{\"data\":\"value\"}"
+            ),
+            IyziAuthV2Generator::generate_auth_content(
+                "https://sandbox-api.iyzipay.com/payment/bin/check?locale=tr",
+                "apiKey",
+                "secretKey",
+                "random",
+                "{\"data\":\"value\"}"
+            )
+        );
+    }
+
+    #[test]
+    fn should_get_uri_path() {
+        assert_eq!(
+            "/v2/iyzilink/products",
+            IyziAuthV2Generator::get_uri_path(
+                "https://sandbox-api.iyzipay.com/v2/iyzilink/products?locale=tr"
+            )
+        );
+        assert_eq!(
+            "/payment/auth",
+            IyziAuthV2Generator::get_uri_path("https://sandbox-api.iyzipay.com/payment/auth")
+        );
+        assert_eq!(
+            "/payment/auth",
+            IyziAuthV2Generator::get_uri_path("/payment/auth?locale=tr")
+        );
+        assert_eq!(
+            "",
+            IyziAuthV2Generator::get_uri_path("https://sandbox-api.iyzipay.com")
+        );
+    }
 }
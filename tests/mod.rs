@@ -8,8 +8,26 @@ pub fn get_test_options() -> Options {
     options
 }
 
+mod builder;
+mod card_data;
+mod contact;
+mod currency;
+mod date;
 mod functional;
 mod hash;
+mod iban;
+mod identity;
 mod iyziauth;
+mod money;
+mod options;
+mod pki;
+mod pki_diagnostics;
+mod reporting;
 mod request_formatter;
+mod resource;
+mod response_enum;
 mod sample;
+mod secret;
+mod subscription;
+mod validation;
+mod verification;
@@ -0,0 +1,177 @@
+pub mod money_test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use iyzipay_rust::model::BasketItem;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::Money;
+    use iyzipay_rust::model::MoneyError;
+    use iyzipay_rust::requests::CreatePaymentRequest;
+    use iyzipay_rust::requests::CreateRefundRequest;
+    use iyzipay_rust::requests::IyziLinkSaveRequest;
+    use iyzipay_rust::requests::PKISerialize;
+    use iyzipay_rust::requests::RequestFormatter;
+    use iyzipay_rust::requests::Validate;
+
+    fn money(amount: &str, currency: Currency) -> Money {
+        Money::parse(amount, currency).unwrap()
+    }
+
+    #[test]
+    fn should_round_to_minor_units() {
+        assert_eq!(
+            &BigDecimal::from_str("1.01").unwrap(),
+            money("1.005", Currency::TRY).amount()
+        );
+        assert_eq!(
+            &BigDecimal::from_str("1").unwrap(),
+            money("1.004", Currency::EUR).amount()
+        );
+        assert_eq!(
+            &BigDecimal::from_str("-2.35").unwrap(),
+            money("-2.345", Currency::USD).amount()
+        );
+        assert!(Money::parse("1,5", Currency::TRY).is_err());
+    }
+
+    #[test]
+    fn should_round_amounts_beyond_i128() {
+        assert_eq!(
+            &BigDecimal::from_str("10.12").unwrap(),
+            money("10.1234567890123456789012345678901234567891", Currency::TRY).amount()
+        );
+        assert_eq!(
+            &BigDecimal::from_str("3.33").unwrap(),
+            money("10", Currency::TRY)
+                .multiply(BigDecimal::from(1) / BigDecimal::from(3))
+                .amount()
+        );
+        assert_eq!(
+            &BigDecimal::from_str("-6.67").unwrap(),
+            money("-20", Currency::TRY)
+                .multiply(BigDecimal::from(1) / BigDecimal::from(3))
+                .amount()
+        );
+    }
+
+    #[test]
+    fn should_format_like_request_formatter() {
+        for amount in &["0", "1", "1.2", "1.20", "100.05", "22.5", "0.10"] {
+            let money = money(amount, Currency::TRY);
+            assert_eq!(
+                RequestFormatter::format_price(&BigDecimal::from_str(amount).unwrap()),
+                money.format_price()
+            );
+        }
+        assert_eq!("1.2 TRY", money("1.20", Currency::TRY).to_string());
+    }
+
+    #[test]
+    fn should_calculate_in_same_currency() {
+        let price = money("0.3", Currency::TRY);
+        let sum = Money::sum(
+            Currency::TRY,
+            &[
+                price.clone(),
+                money("0.5", Currency::TRY),
+                price.multiply(2),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(money("1.4", Currency::TRY), sum);
+        assert_eq!(
+            money("1.1", Currency::TRY),
+            sum.checked_sub(&price).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_fail_across_currencies() {
+        let error = money("1", Currency::TRY)
+            .checked_add(&money("1", Currency::EUR))
+            .unwrap_err();
+
+        assert_eq!(
+            MoneyError::CurrencyMismatch {
+                expected: Currency::TRY,
+                found: Currency::EUR
+            },
+            error
+        );
+        assert!(Money::sum(
+            Currency::USD,
+            &[money("1", Currency::USD), money("1", Currency::GBP)]
+        )
+        .is_err());
+
+        let mut request = CreatePaymentRequest::new();
+        assert!(request
+            .set_money(money("1", Currency::TRY), money("1.2", Currency::USD))
+            .is_err());
+        assert_eq!(None, request.price());
+    }
+
+    #[test]
+    fn should_keep_currency_of_basket_item_money() {
+        assert_eq!(
+            Ok(()),
+            money("1", Currency::TRY).same_currency(&money("2", Currency::TRY))
+        );
+
+        let mut request = CreatePaymentRequest::new();
+        request
+            .set_money(money("1", Currency::TRY), money("1.2", Currency::TRY))
+            .unwrap();
+        let mut basket_item = BasketItem::new();
+        basket_item.set_price_money(money("1", Currency::EUR));
+        assert_eq!(Some(&Currency::EUR), basket_item.currency());
+        request.set_basket_items(vec![basket_item]);
+
+        let errors = request.validate().unwrap_err();
+
+        assert_eq!(
+            "must be in the currency of the request (TRY)",
+            errors.of("basketItems[0].price")[0].message()
+        );
+        assert_eq!(
+            Some(String::from(
+                "[price=1.0,paidPrice=1.2,basketItems=[[price=1.0]],currency=TRY]"
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_set_money_on_requests() {
+        let mut payment_request = CreatePaymentRequest::new();
+        payment_request
+            .set_money(money("1", Currency::EUR), money("1.20", Currency::EUR))
+            .unwrap();
+        let mut basket_item = BasketItem::new();
+        basket_item.set_price(money("1", Currency::EUR));
+        payment_request.set_basket_items(vec![basket_item]);
+        assert_eq!(
+            Some(String::from(
+                "[price=1.0,paidPrice=1.2,basketItems=[[price=1.0]],currency=EUR]"
+            )),
+            payment_request.serialize()
+        );
+
+        let mut refund_request = CreateRefundRequest::new();
+        refund_request.set_price_money(money("0.5", Currency::USD));
+        assert_eq!(
+            Some(String::from("[price=0.5,currency=USD]")),
+            refund_request.serialize()
+        );
+
+        let mut iyzi_link_request = IyziLinkSaveRequest::new();
+        iyzi_link_request.set_price_money(money("10", Currency::TRY));
+        assert_eq!(Some(&String::from("TRY")), iyzi_link_request.currency());
+        assert_eq!(
+            Some(&BigDecimal::from_str("10").unwrap()),
+            iyzi_link_request.price()
+        );
+    }
+}
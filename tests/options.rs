@@ -0,0 +1,51 @@
+pub mod options_test {
+    use iyzipay_rust::options::AuthScheme;
+    use iyzipay_rust::options::Options;
+
+    #[test]
+    fn should_use_v1_by_default() {
+        let options = Options::new();
+        assert_eq!(AuthScheme::V1, options.auth_scheme());
+        assert_eq!(
+            AuthScheme::V1,
+            options.endpoint_auth_scheme("/payment/auth")
+        );
+    }
+
+    #[test]
+    fn should_use_global_auth_scheme() {
+        let mut options = Options::new();
+        options.set_auth_scheme(AuthScheme::V2);
+        assert_eq!(
+            AuthScheme::V2,
+            options.endpoint_auth_scheme("/payment/auth")
+        );
+    }
+
+    #[test]
+    fn should_prefer_longest_endpoint_override() {
+        let mut options = Options::new();
+        options.set_auth_scheme(AuthScheme::V2);
+        options.set_endpoint_auth_scheme("/payment", AuthScheme::V1);
+        options.set_endpoint_auth_scheme("/payment/auth", AuthScheme::V2);
+
+        assert_eq!(
+            AuthScheme::V2,
+            options.endpoint_auth_scheme("/payment/auth")
+        );
+        assert_eq!(
+            AuthScheme::V1,
+            options.endpoint_auth_scheme("/payment/refund")
+        );
+        assert_eq!(
+            AuthScheme::V2,
+            options.endpoint_auth_scheme("/onboarding/submerchant")
+        );
+
+        options.remove_endpoint_auth_scheme("/payment");
+        assert_eq!(
+            AuthScheme::V2,
+            options.endpoint_auth_scheme("/payment/refund")
+        );
+    }
+}
@@ -0,0 +1,554 @@
+pub mod pki_test {
+    use serde::de::DeserializeOwned;
+
+    use iyzipay_rust::model::BasketItem;
+    use iyzipay_rust::model::BasketItemType;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::Locale;
+    use iyzipay_rust::model::PaymentChannel;
+    use iyzipay_rust::model::PaymentGroup;
+    use iyzipay_rust::requests::*;
+
+    const LOCALE: &str = r#""locale": "tr", "conversationId": "123456789""#;
+
+    const REQUEST_PKI: &str = "locale=tr,conversationId=123456789";
+
+    const PAYMENT_CARD_PKI: &str = "[cardHolderName=John Doe,cardNumber=5528790000000008,expireYear=2030,expireMonth=12,cvc=123,registerCard=0,cardAlias=card alias,cardToken=card token,cardUserKey=card user key]";
+
+    const BUYER_PKI: &str = "[id=BY789,name=John,surname=Doe,identityNumber=74300864791,email=email@email.com,gsmNumber=+905350000000,registrationDate=2013-04-21 15:12:09,lastLoginDate=2015-10-05 12:43:35,registrationAddress=Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1,city=Istanbul,country=Turkey,zipCode=34732,ip=85.34.78.112]";
+
+    const ADDRESS_PKI: &str = "[address=Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1,zipCode=34742,contactName=Jane Doe,city=Istanbul,country=Turkey]";
+
+    const BASKET_ITEMS_PKI: &str = "[[id=BI101,price=0.3,name=Binocular,category1=Collectibles,category2=Accessories,itemType=PHYSICAL,subMerchantKey=sub merchant key,subMerchantPrice=0.27], [id=BI102,price=2.0,name=Game code,category1=Game,itemType=VIRTUAL]]";
+
+    fn buyer() -> String {
+        String::from(
+            r#"{"id": "BY789", "name": "John", "surname": "Doe", "identityNumber": "74300864791", "email": "email@email.com", "gsmNumber": "+905350000000", "registrationDate": "2013-04-21 15:12:09", "lastLoginDate": "2015-10-05 12:43:35", "registrationAddress": "Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1", "city": "Istanbul", "country": "Turkey", "zipCode": "34732", "ip": "85.34.78.112"}"#,
+        )
+    }
+
+    fn address() -> String {
+        String::from(
+            r#"{"address": "Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1", "zipCode": "34742", "contactName": "Jane Doe", "city": "Istanbul", "country": "Turkey"}"#,
+        )
+    }
+
+    fn basket_items() -> String {
+        String::from(
+            r#"[{"id": "BI101", "price": "0.30", "name": "Binocular", "category1": "Collectibles", "category2": "Accessories", "itemType": "PHYSICAL", "subMerchantKey": "sub merchant key", "subMerchantPrice": "0.27"}, {"id": "BI102", "price": "2", "name": "Game code", "category1": "Game", "itemType": "VIRTUAL"}]"#,
+        )
+    }
+
+    fn payment_card() -> String {
+        String::from(
+            r#"{"cardHolderName": "John Doe", "cardNumber": "5528790000000008", "expireYear": "2030", "expireMonth": "12", "cvc": "123", "registerCard": 0, "cardAlias": "card alias", "cardToken": "card token", "cardUserKey": "card user key"}"#,
+        )
+    }
+
+    fn parse<T: DeserializeOwned>(json: String) -> T {
+        serde_json::from_str(json.as_str()).unwrap()
+    }
+
+    #[test]
+    fn should_serialize_create_payment_request() {
+        let request: CreatePaymentRequest = parse(format!(
+            r#"{{{}, "price": "1.0", "paidPrice": "1.20", "installment": 1, "paymentChannel": "WEB", "basketId": "B67832", "paymentGroup": "PRODUCT", "paymentCard": {}, "buyer": {}, "shippingAddress": {}, "billingAddress": {}, "basketItems": {}, "paymentSource": "source", "currency": "TRY", "posOrderId": "pos order id", "connectorName": "connector name", "callbackUrl": "https://www.merchant.com/callback"}}"#,
+            LOCALE,
+            payment_card(),
+            buyer(),
+            address(),
+            address(),
+            basket_items()
+        ));
+        assert_eq!(Some(format!("[{},price=1.0,paidPrice=1.2,installment=1,paymentChannel=WEB,basketId=B67832,paymentGroup=PRODUCT,paymentCard={},buyer={},shippingAddress={},billingAddress={},basketItems={},paymentSource=source,posOrderId=pos order id,currency=TRY,connectorName=connector name,callbackUrl=https://www.merchant.com/callback]", REQUEST_PKI, PAYMENT_CARD_PKI, BUYER_PKI, ADDRESS_PKI, ADDRESS_PKI, BASKET_ITEMS_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_payment_request() {
+        let request: RetrievePaymentRequest = parse(format!(
+            r#"{{{}, "paymentId": "1", "paymentConversationId": "123456789"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},paymentId=1,paymentConversationId=123456789]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_cancel_request() {
+        let request: CreateCancelRequest = parse(format!(
+            r#"{{{}, "paymentId": "1", "ip": "85.34.78.112", "reason": "DOUBLE_PAYMENT", "description": "customer requested"}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},paymentId=1,ip=85.34.78.112,reason=DOUBLE_PAYMENT,description=customer requested]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_create_threeds_payment_request() {
+        let request: CreateThreedsPaymentRequest = parse(format!(
+            r#"{{{}, "paymentId": "1", "conversationData": "conversation data"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},paymentId=1,conversationData=conversation data]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_refund_request() {
+        let request: CreateRefundRequest = parse(format!(
+            r#"{{{}, "paymentTransactionId": "1", "price": "0.50", "ip": "85.34.78.112", "currency": "TRY", "reason": "FRAUD", "description": "stolen card"}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},paymentTransactionId=1,price=0.5,ip=85.34.78.112,currency=TRY,reason=FRAUD,description=stolen card]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_create_payment_post_auth_request() {
+        let request: CreatePaymentPostAuthRequest = parse(format!(
+            r#"{{{}, "paymentId": "1", "paidPrice": "0.50", "ip": "85.34.78.112", "currency": "TRY"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},paymentId=1,paidPrice=0.5,ip=85.34.78.112,currency=TRY]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_basic_payment_request() {
+        let request: CreateBasicPaymentRequest = parse(format!(
+            r#"{{{}, "price": "1.0", "paidPrice": "1.20", "installment": 1, "buyerEmail": "email@email.com", "buyerId": "B2323", "buyerIp": "85.34.78.112", "posOrderId": "pos order id", "paymentCard": {}, "currency": "TRY", "connectorName": "connector name", "callbackUrl": "https://www.merchant.com/callback"}}"#,
+            LOCALE,
+            payment_card()
+        ));
+        assert_eq!(Some(format!("[{},price=1.0,paidPrice=1.2,installment=1,buyerEmail=email@email.com,buyerId=B2323,buyerIp=85.34.78.112,posOrderId=pos order id,paymentCard={},currency=TRY,connectorName=connector name,callbackUrl=https://www.merchant.com/callback]", REQUEST_PKI, PAYMENT_CARD_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_create_basic_bkm_initialize_request() {
+        let request: CreateBasicBkmInitializeRequest = parse(format!(
+            r#"{{{}, "connectorName": "connector name", "price": "1", "callbackUrl": "https://www.merchant.com/callback", "buyerEmail": "email@email.com", "buyerId": "B2323", "buyerIp": "85.34.78.112", "posOrderId": "pos order id", "installmentDetails": [{{"bankId": 12, "installmentPrices": [{{"installmentNumber": 1, "totalPrice": "1.00"}}, {{"installmentNumber": 2, "totalPrice": "1.10"}}]}}]}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},connectorName=connector name,price=1.0,callbackUrl=https://www.merchant.com/callback,buyerEmail=email@email.com,buyerId=B2323,buyerIp=85.34.78.112,posOrderId=pos order id,installmentDetails=[[bankId=12,installmentPrices=[[installmentNumber=1,totalPrice=1.0], [installmentNumber=2,totalPrice=1.1]]]]]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_create_bkm_initialize_request() {
+        let request: CreateBkmInitializeRequest = parse(format!(
+            r#"{{{}, "price": "1", "basketId": "B67832", "paymentGroup": "PRODUCT", "buyer": {}, "shippingAddress": {}, "billingAddress": {}, "basketItems": {}, "callbackUrl": "https://www.merchant.com/callback", "paymentSource": "source", "currency": "TRY", "enabledInstallments": [2, 3, 6, 9]}}"#,
+            LOCALE,
+            buyer(),
+            address(),
+            address(),
+            basket_items()
+        ));
+        assert_eq!(Some(format!("[{},price=1.0,basketId=B67832,paymentGroup=PRODUCT,buyer={},shippingAddress={},billingAddress={},basketItems={},callbackUrl=https://www.merchant.com/callback,paymentSource=source,enabledInstallments=[2, 3, 6, 9]]", REQUEST_PKI, BUYER_PKI, ADDRESS_PKI, ADDRESS_PKI, BASKET_ITEMS_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_bkm_request() {
+        let request: RetrieveBkmRequest = parse(format!(r#"{{{}, "token": "token"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},token=token]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_retrieve_transactions_request() {
+        let request: RetrieveTransactionsRequest =
+            parse(format!(r#"{{{}, "date": "2016-01-22 19:13:00"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},date=2016-01-22 19:13:00]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_pecco_initialize_request() {
+        let request: CreatePeccoInitializeRequest = parse(format!(
+            r#"{{{}, "price": "100000", "paidPrice": "120000.5", "basketId": "B67832", "paymentGroup": "PRODUCT", "paymentSource": "source", "currency": "IRR", "buyer": {}, "shippingAddress": {}, "billingAddress": {}, "basketItems": {}, "callbackUrl": "https://www.merchant.com/callback"}}"#,
+            LOCALE,
+            buyer(),
+            address(),
+            address(),
+            basket_items()
+        ));
+        assert_eq!(Some(format!("[{},price=100000.0,basketId=B67832,paymentGroup=PRODUCT,buyer={},shippingAddress={},billingAddress={},basketItems={},callbackUrl=https://www.merchant.com/callback,paymentSource=source,paidPrice=120000.5]", REQUEST_PKI, BUYER_PKI, ADDRESS_PKI, ADDRESS_PKI, BASKET_ITEMS_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_update_payment_item_request() {
+        let request: UpdatePaymentItemRequest = parse(format!(
+            r#"{{{}, "subMerchantKey": "sub merchant key", "paymentTransactionId": 11, "subMerchantPrice": "0.40"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},subMerchantKey=sub merchant key,paymentTransactionId=11,subMerchantPrice=0.4]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_retrieve_bin_number_request() {
+        let request: RetrieveBinNumberRequest =
+            parse(format!(r#"{{{}, "binNumber": "554960"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},binNumber=554960]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_card_request() {
+        let request: CreateCardRequest = parse(format!(
+            r#"{{{}, "externalId": "external id", "email": "email@email.com", "cardUserKey": "card user key", "card": {{"cardAlias": "card alias", "cardNumber": "5528790000000008", "expireYear": "2030", "expireMonth": "12", "cardHolderName": "John Doe"}}}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},externalId=external id,email=email@email.com,cardUserKey=card user key,card=[cardAlias=card alias,cardNumber=5528790000000008,expireYear=2030,expireMonth=12,cardHolderName=John Doe]]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_delete_card_request() {
+        let request: DeleteCardRequest = parse(format!(
+            r#"{{{}, "cardUserKey": "card user key", "cardToken": "card token"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},cardUserKey=card user key,cardToken=card token]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_retrieve_card_list_request() {
+        let request: RetrieveCardListRequest =
+            parse(format!(r#"{{{}, "cardUserKey": "card user key"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},cardUserKey=card user key]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_card_management_page_initialize_request() {
+        let request: CreateCardManagementPageInitializeRequest = parse(format!(
+            r#"{{{}, "addNewCardEnabled": true, "validateNewCard": false, "externalId": "external id", "email": "email@email.com", "cardUserKey": "card user key", "callbackUrl": "https://www.merchant.com/callback", "debitCardAllowed": true}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},addNewCardEnabled=true,validateNewCard=false,externalId=external id,email=email@email.com,cardUserKey=card user key,callbackUrl=https://www.merchant.com/callback,debitCardAllowed=true]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_card_management_page_card_request() {
+        let request: RetrieveCardManagementPageCardRequest =
+            parse(format!(r#"{{{}, "pageToken": "page token"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},token=page token]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_checkout_form_initialize_request() {
+        let request: CreateCheckoutFormInitializeRequest = parse(format!(
+            r#"{{{}, "price": "1", "paidPrice": "1.2", "basketId": "B67832", "paymentGroup": "PRODUCT", "paymentSource": "source", "currency": "TRY", "buyer": {}, "shippingAddress": {}, "billingAddress": {}, "basketItems": {}, "callbackUrl": "https://www.merchant.com/callback", "forceThreeDS": 1, "cardUserKey": "card user key", "posOrderId": "pos order id", "enabledInstallments": [2, 3], "paymentWithNewCardEnabled": true, "debitCardAllowed": false}}"#,
+            LOCALE,
+            buyer(),
+            address(),
+            address(),
+            basket_items()
+        ));
+        assert_eq!(Some(format!("[{},price=1.0,basketId=B67832,paymentGroup=PRODUCT,buyer={},shippingAddress={},billingAddress={},basketItems={},callbackUrl=https://www.merchant.com/callback,paymentSource=source,currency=TRY,posOrderId=pos order id,paidPrice=1.2,forceThreeDS=1,cardUserKey=card user key,enabledInstallments=[2, 3],paymentWithNewCardEnabled=true,debitCardAllowed=false]", REQUEST_PKI, BUYER_PKI, ADDRESS_PKI, ADDRESS_PKI, BASKET_ITEMS_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_checkout_form_request() {
+        let request: RetrieveCheckoutFormRequest =
+            parse(format!(r#"{{{}, "token": "token"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},token=token]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_retrieve_installment_info_request() {
+        let request: RetrieveInstallmentInfoRequest = parse(format!(
+            r#"{{{}, "binNumber": "554960", "price": "100", "currency": "TRY"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},binNumber=554960,price=100.0,currency=TRY]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_iyzi_link_save_request() {
+        let request: IyziLinkSaveRequest = parse(format!(
+            r#"{{{}, "name": "ft-product-name", "description": "ft-product-description", "encodedImageFile": "image", "price": "1", "currencyCode": "TRY", "addressIgnorable": false, "soldLimit": 1, "installmentRequested": false}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{}]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_create_iyziup_form_initialize_request() {
+        let request: CreateIyziupFormInitializeRequest = parse(format!(
+            r#"{{{}, "merchantOrderId": "order id", "paymentGroup": "PRODUCT", "paymentSource": "source", "forceThreeDS": 0, "enabledInstallments": [2, 3, 6], "enabledCardFamily": "Bonus", "currency": "TRY", "price": "100", "paidPrice": "100.10", "shippingPrice": "0", "callbackUrl": "https://www.merchant.com/callback", "termsUrl": "https://www.merchant.com/terms", "preSalesContractUrl": "https://www.merchant.com/contract", "orderItems": [{{"id": "OI101", "price": "50.50", "name": "item", "category1": "Game", "category2": "Online", "itemType": "VIRTUAL", "itemUrl": "https://www.merchant.com/item", "itemDescription": "description"}}], "initialConsumer": {{"name": "John", "surname": "Doe", "email": "email@email.com", "gsmNumber": "+905350000000", "addressList": [{{"alias": "home", "addressLine1": "line 1", "addressLine2": "line 2", "zipCode": "34742", "contactName": "Jane Doe", "city": "Istanbul", "country": "Turkey"}}]}}}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},merchantOrderId=order id,paymentGroup=PRODUCT,paymentSource=source,forceThreeDS=0,enabledInstallments=[2, 3, 6],enabledCardFamily=Bonus,currency=TRY,price=100.0,paidPrice=100.1,shippingPrice=0.0,callbackUrl=https://www.merchant.com/callback,termsUrl=https://www.merchant.com/terms,preSalesContractUrl=https://www.merchant.com/contract,orderItems=[[id=OI101,price=50.5,name=item,category1=Game,category2=Online,itemType=VIRTUAL,itemUrl=https://www.merchant.com/item,itemDescription=description]],initialConsumer=[name=John,surname=Doe,email=email@email.com,gsmNumber=+905350000000,addressList=[[alias=home,addressLine1=line 1,addressLine2=line 2,zipCode=34742,contactName=Jane Doe,city=Istanbul,country=Turkey]]]]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_iyziup_form_request() {
+        let request: RetrieveIyziupFormRequest =
+            parse(format!(r#"{{{}, "token": "token"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},token=token]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_sub_merchant_request() {
+        let request: CreateSubMerchantRequest = parse(format!(
+            r#"{{{}, "name": "John's market", "email": "email@submerchantemail.com", "gsmNumber": "+905350000000", "address": "Nidakule Göztepe", "iban": "TR180006200119000006672315", "taxOffice": "Tax office", "contactName": "John", "contactSurname": "Doe", "legalCompanyTitle": "John Doe inc", "swiftCode": "TEKFTRIS", "currency": "TRY", "identityNumber": "31300864726", "taxNumber": "1234567890", "subMerchantExternalId": "B49224", "subMerchantType": "LIMITED_OR_JOINT_STOCK_COMPANY"}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},name=John's market,email=email@submerchantemail.com,gsmNumber=+905350000000,address=Nidakule Göztepe,iban=TR180006200119000006672315,taxOffice=Tax office,contactName=John,contactSurname=Doe,legalCompanyTitle=John Doe inc,swiftCode=TEKFTRIS,currency=TRY,subMerchantExternalId=B49224,identityNumber=31300864726,taxNumber=1234567890,subMerchantType=LIMITED_OR_JOINT_STOCK_COMPANY]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_update_sub_merchant_request() {
+        let request: UpdateSubMerchantRequest = parse(format!(
+            r#"{{{}, "name": "John's market", "email": "email@submerchantemail.com", "gsmNumber": "+905350000000", "address": "Nidakule Göztepe", "iban": "TR180006200119000006672315", "taxOffice": "Tax office", "contactName": "John", "contactSurname": "Doe", "legalCompanyTitle": "John Doe inc", "swiftCode": "TEKFTRIS", "currency": "TRY", "identityNumber": "31300864726", "taxNumber": "1234567890", "subMerchantKey": "sub merchant key"}}"#,
+            LOCALE
+        ));
+        assert_eq!(Some(format!("[{},name=John's market,email=email@submerchantemail.com,gsmNumber=+905350000000,address=Nidakule Göztepe,iban=TR180006200119000006672315,taxOffice=Tax office,contactName=John,contactSurname=Doe,legalCompanyTitle=John Doe inc,swiftCode=TEKFTRIS,currency=TRY,subMerchantKey=sub merchant key,identityNumber=31300864726,taxNumber=1234567890]", REQUEST_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_sub_merchant_request() {
+        let request: RetrieveSubMerchantRequest = parse(format!(
+            r#"{{{}, "subMerchantExternalId": "AS49224"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!("[{},subMerchantExternalId=AS49224]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_approval_request() {
+        let request: CreateApprovalRequest =
+            parse(format!(r#"{{{}, "paymentTransactionId": "1"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},paymentTransactionId=1]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_apm_initialize_request() {
+        let request: CreateApmInitializeRequest = parse(format!(
+            r#"{{{}, "price": "1", "paidPrice": "1.2", "paymentChannel": "WEB", "paymentGroup": "PRODUCT", "paymentSource": "source", "currency": "EUR", "merchantOrderId": "B67832", "countryCode": "DE", "accountHolderName": "success", "merchantCallbackUrl": "https://www.merchant.com/callback", "merchantErrorUrl": "https://www.merchant.com/error", "merchantNotificationUrl": "https://www.merchant.com/notification", "apmType": "SOFORT", "basketId": "B67832", "buyer": {}, "shippingAddress": {}, "billingAddress": {}, "basketItems": {}}}"#,
+            LOCALE,
+            buyer(),
+            address(),
+            address(),
+            basket_items()
+        ));
+        assert_eq!(Some(format!("[{},price=1.0,paidPrice=1.2,paymentChannel=WEB,paymentGroup=PRODUCT,paymentSource=source,currency=EUR,merchantOrderId=B67832,countryCode=DE,accountHolderName=success,merchantCallbackUrl=https://www.merchant.com/callback,merchantErrorUrl=https://www.merchant.com/error,merchantNotificationUrl=https://www.merchant.com/notification,apmType=SOFORT,basketId=B67832,buyer={},shippingAddress={},billingAddress={},basketItems={}]", REQUEST_PKI, BUYER_PKI, ADDRESS_PKI, ADDRESS_PKI, BASKET_ITEMS_PKI)), request.serialize());
+    }
+
+    #[test]
+    fn should_serialize_retrieve_apm_request() {
+        let request: RetrieveApmRequest = parse(format!(r#"{{{}, "paymentId": "1"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},paymentId=1]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_pecco_payment_request() {
+        let request: CreatePeccoPaymentRequest =
+            parse(format!(r#"{{{}, "token": "token"}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},token=token]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_cross_booking_request() {
+        let request: CreateCrossBookingRequest = parse(format!(
+            r#"{{{}, "subMerchantKey": "sub merchant key", "price": "1.00", "reason": "reason", "currency": "TRY"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},subMerchantKey=sub merchant key,price=1.0,reason=reason,currency=TRY]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_create_settlement_to_balance_request() {
+        let request: CreateSettlementToBalanceRequest = parse(format!(
+            r#"{{{}, "subMerchantKey": "sub merchant key", "callbackUrl": "https://www.merchant.com/callback"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},subMerchantKey=sub merchant key,callbackUrl=https://www.merchant.com/callback]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_retrieve_loyalty_request() {
+        let request: RetrieveLoyaltyRequest = parse(format!(
+            r#"{{{}, "paymentCard": {{"cardHolderName": "John Doe", "cardNumber": "5528790000000008", "expireYear": "2030", "expireMonth": "12", "cvc": "123", "reward": {{"rewardAmount": "1.00", "rewardUsage": 1}}}}, "currency": "TRY"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},paymentCard=[cardHolderName=John Doe,cardNumber=5528790000000008,expireYear=2030,expireMonth=12,cvc=123,reward=[rewardAmount=1.0,rewardUsage=1]],currency=TRY]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_campaign_of_create_payment_request() {
+        let request: CreatePaymentRequest = parse(format!(
+            r#"{{{}, "price": "1.0", "paidPrice": "1.20", "installment": 3, "currency": "TRY", "campaignId": "campaign id"}}"#,
+            LOCALE
+        ));
+        assert_eq!(
+            Some(format!(
+                "[{},price=1.0,paidPrice=1.2,installment=3,currency=TRY,campaignId=campaign id]",
+                REQUEST_PKI
+            )),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_serialize_paging_request() {
+        let request: PagingRequest = parse(format!(r#"{{{}, "page": 1, "count": 10}}"#, LOCALE));
+        assert_eq!(
+            Some(format!("[{},page=1,count=10]", REQUEST_PKI)),
+            request.serialize()
+        );
+    }
+
+    #[test]
+    fn should_skip_none_and_keep_empty_values() {
+        let request = Request::new("", "tr");
+        assert_eq!(
+            Some(String::from("[locale=tr,conversationId=]")),
+            request.serialize()
+        );
+
+        let mut paging_request = PagingRequest::new();
+        paging_request.set_locale(Locale::TR);
+        paging_request.set_count(10);
+        assert_eq!(
+            Some(String::from("[locale=tr,count=10]")),
+            paging_request.serialize()
+        );
+        assert_eq!(None, to_pki_string(&None::<Request>).unwrap());
+    }
+
+    #[test]
+    fn should_format_prices_only_in_pki_string() {
+        let basket_items: Vec<BasketItem> = parse(basket_items());
+
+        assert_eq!(
+            Some(String::from(BASKET_ITEMS_PKI)),
+            basket_items.serialize()
+        );
+        assert!(serde_json::to_string(&basket_items)
+            .unwrap()
+            .contains(r#""price":"0.30""#));
+    }
+
+    #[test]
+    fn should_skip_fields_only_in_pki_string() {
+        let request: IyziLinkSaveRequest = parse(format!(
+            r#"{{{}, "name": "ft-product-name", "price": "1"}}"#,
+            LOCALE
+        ));
+
+        assert_eq!(Some(format!("[{}]", REQUEST_PKI)), request.serialize());
+        assert!(serde_json::to_string(&request)
+            .unwrap()
+            .contains(r#""name":"ft-product-name""#));
+    }
+
+    #[test]
+    fn should_serialize_typed_and_raw_values_identically() {
+        let mut typed = CreatePaymentRequest::new();
+        typed.set_locale(Locale::TR);
+        typed.set_currency(Currency::TRY);
+        typed.set_payment_channel(PaymentChannel::Web);
+        typed.set_payment_group(PaymentGroup::Product);
+        let mut basket_item = BasketItem::new();
+        basket_item.set_item_type(BasketItemType::Physical);
+        typed.set_basket_items(vec![basket_item]);
+
+        let mut raw = CreatePaymentRequest::new();
+        raw.set_locale_raw("tr");
+        raw.set_currency_raw("TRY");
+        raw.set_payment_channel_raw("WEB");
+        raw.set_payment_group_raw("PRODUCT");
+        let mut basket_item = BasketItem::new();
+        basket_item.set_item_type_raw("PHYSICAL");
+        raw.set_basket_items(vec![basket_item]);
+
+        assert_eq!(
+            serde_json::to_string(&raw).unwrap(),
+            serde_json::to_string(&typed).unwrap()
+        );
+        assert_eq!(
+            Some(String::from("[locale=tr,paymentChannel=WEB,paymentGroup=PRODUCT,basketItems=[[itemType=PHYSICAL]],currency=TRY]")),
+            typed.serialize()
+        );
+
+        raw.set_currency_raw("XYZ");
+        assert_eq!(Some(&String::from("XYZ")), raw.currency());
+    }
+}
@@ -0,0 +1,154 @@
+pub mod pki_diagnostics_test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use iyzipay_rust::model::Address;
+    use iyzipay_rust::model::BasketItem;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::Locale;
+    use iyzipay_rust::requests::*;
+
+    fn value(value: &str) -> PKIValue {
+        PKIValue::Value(value.to_string())
+    }
+
+    #[test]
+    fn should_parse_pki_string() {
+        let parsed = parse_pki_string(
+            "[locale=tr,price=1.0,shippingAddress=[address=Nidakule Göztepe, Merdivenköy Mah. No:1,city=Istanbul],basketItems=[[id=BI101,price=0.3], [id=BI102]],enabledInstallments=[2, 3],token=]",
+        )
+        .unwrap();
+
+        assert_eq!(
+            PKIValue::Object(vec![
+                (String::from("locale"), value("tr")),
+                (String::from("price"), value("1.0")),
+                (
+                    String::from("shippingAddress"),
+                    PKIValue::Object(vec![
+                        (
+                            String::from("address"),
+                            value("Nidakule Göztepe, Merdivenköy Mah. No:1")
+                        ),
+                        (String::from("city"), value("Istanbul")),
+                    ])
+                ),
+                (
+                    String::from("basketItems"),
+                    PKIValue::Array(vec![
+                        PKIValue::Object(vec![
+                            (String::from("id"), value("BI101")),
+                            (String::from("price"), value("0.3")),
+                        ]),
+                        PKIValue::Object(vec![(String::from("id"), value("BI102"))]),
+                    ])
+                ),
+                (
+                    String::from("enabledInstallments"),
+                    PKIValue::Array(vec![value("2"), value("3")])
+                ),
+                (String::from("token"), value("")),
+            ]),
+            parsed
+        );
+    }
+
+    #[test]
+    fn should_not_parse_unbracketed_pki_string() {
+        assert!(parse_pki_string("locale=tr").is_err());
+        assert!(parse_pki_string("[locale=tr],[id=1]").is_err());
+        assert!(parse_pki_string("[locale=[tr]").is_err());
+    }
+
+    #[test]
+    fn should_report_price_formatting() {
+        let mut request = CreatePaymentRequest::new();
+        request.set_locale(Locale::TR);
+        request.set_price(BigDecimal::from_str("1.20").unwrap());
+        let mut basket_item = BasketItem::new();
+        basket_item.set_id("BI101");
+        basket_item.set_price(BigDecimal::from_str("1.2").unwrap());
+        request.set_basket_items(vec![basket_item]);
+
+        let diagnostics = PKIDiagnostics::of(&request).unwrap();
+
+        let paths: Vec<&String> = diagnostics.fields().iter().map(|f| f.path()).collect();
+        assert_eq!(
+            vec![
+                "locale",
+                "price",
+                "basketItems[0].id",
+                "basketItems[0].price"
+            ],
+            paths
+        );
+        let price = &diagnostics.fields()[1];
+        assert_eq!(PKIFieldStatus::PriceFormatted, price.status());
+        assert_eq!(Some(&String::from("1.20")), price.json_value());
+        assert_eq!(Some(&String::from("1.2")), price.pki_value());
+        assert_eq!(PKIFieldStatus::Match, diagnostics.fields()[3].status());
+        assert!(diagnostics.missing_in_pki().is_empty());
+        assert!(diagnostics.has_issues());
+    }
+
+    #[test]
+    fn should_report_fields_missing_in_pki() {
+        let mut request = CreateBkmInitializeRequest::new();
+        request.set_locale(Locale::TR);
+        request.set_basket_id("B67832");
+        request.set_currency(Currency::TRY);
+        let mut address = Address::new();
+        address.set_city("Istanbul");
+        request.set_billing_address(address);
+
+        let diagnostics = PKIDiagnostics::of(&request).unwrap();
+
+        let missing: Vec<&String> = diagnostics
+            .missing_in_pki()
+            .iter()
+            .map(|f| f.path())
+            .collect();
+        assert_eq!(vec!["currency"], missing);
+        assert_eq!(
+            PKIFieldStatus::Match,
+            diagnostics
+                .fields()
+                .iter()
+                .find(|f| f.path() == "billingAddress.city")
+                .unwrap()
+                .status()
+        );
+    }
+
+    #[test]
+    fn should_report_field_order_and_values() {
+        let diagnostics = PKIDiagnostics::compare(
+            r#"{"locale":"tr","currency":"TRY","posOrderId":"1","buyer":{"name":"John"},"ip":"1.1.1.1","callbackUrl":null}"#,
+            "[locale=tr,posOrderId=1,currency=TRY,buyer=[name=Jane],connectorName=c]",
+        )
+        .unwrap();
+
+        let report: Vec<(&str, PKIFieldStatus, bool)> = diagnostics
+            .fields()
+            .iter()
+            .map(|f| (f.path().as_str(), f.status(), f.out_of_order()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("locale", PKIFieldStatus::Match, false),
+                ("posOrderId", PKIFieldStatus::Match, true),
+                ("currency", PKIFieldStatus::Match, true),
+                ("buyer.name", PKIFieldStatus::Mismatch, false),
+                ("connectorName", PKIFieldStatus::MissingInJson, false),
+                ("ip", PKIFieldStatus::MissingInPki, false),
+            ],
+            report
+        );
+
+        let table = diagnostics.to_string();
+        assert!(table.starts_with("field         | json    | pki  | status\n"));
+        assert!(table.contains("posOrderId    | 1       | 1    | ok, out of order\n"));
+        assert!(table.contains("ip            | 1.1.1.1 | -    | missing in PKI\n"));
+    }
+}
@@ -0,0 +1,136 @@
+pub mod reporting_test {
+    use bigdecimal::BigDecimal;
+    use chrono::NaiveDate;
+
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::PaymentDetailsReport;
+    use iyzipay_rust::model::ReportingTransactionType;
+    use iyzipay_rust::model::TransactionReport;
+    use iyzipay_rust::model::TransactionStatus;
+    use iyzipay_rust::requests::RequestQueryParams;
+    use iyzipay_rust::requests::RetrievePaymentDetailsRequest;
+    use iyzipay_rust::requests::RetrieveTransactionReportRequest;
+    use iyzipay_rust::requests::Validate;
+
+    #[test]
+    fn should_build_transaction_report_query_params() {
+        let mut request = RetrieveTransactionReportRequest::new();
+        request.set_conversation_id("123456789");
+        request.set_transaction_date("2020-01-15 10:30:00");
+
+        assert_eq!(
+            "?conversationId=123456789&transactionDate=2020-01-15",
+            request.get_query_params()
+        );
+
+        request.set_page(3u32);
+
+        assert_eq!(
+            "?conversationId=123456789&transactionDate=2020-01-15&page=3",
+            request.get_query_params()
+        );
+        assert_eq!(
+            "?conversationId=123456789&transactionDate=2020-01-15&page=4",
+            request.get_query_params_for_page(Some(4))
+        );
+    }
+
+    #[test]
+    fn should_require_payment_id_or_conversation_id() {
+        let mut request = RetrievePaymentDetailsRequest::new();
+
+        assert!(request.validate().is_err());
+
+        request.set_payment_conversation_id("123456789");
+
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            "?paymentConversationId=123456789",
+            request.get_query_params()
+        );
+        assert!(RetrieveTransactionReportRequest::new().validate().is_err());
+    }
+
+    #[test]
+    fn should_deserialize_transaction_report() {
+        let report: TransactionReport = serde_json::from_str(
+            r#"{
+                "status": "success",
+                "currentPage": 1,
+                "totalPageCount": 2,
+                "transactions": [{
+                    "transactionType": "PAYMENT",
+                    "transactionDate": "2020-01-15 10:30:00",
+                    "transactionId": 12345678,
+                    "paymentId": "11591522",
+                    "price": 1.0,
+                    "paidPrice": 1.1,
+                    "transactionCurrency": "TRY",
+                    "installment": 1
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(&2), report.total_page_count());
+        let transaction = &report.transactions().unwrap()[0];
+        assert_eq!(
+            Some(&ReportingTransactionType::Payment),
+            transaction.transaction_type()
+        );
+        assert_eq!(
+            NaiveDate::from_ymd_opt(2020, 1, 15)
+                .unwrap()
+                .and_hms_opt(10, 30, 0)
+                .as_ref(),
+            transaction.transaction_date()
+        );
+        assert_eq!(
+            Some(&String::from("12345678")),
+            transaction.transaction_id()
+        );
+        assert_eq!(Some(&Currency::TRY), transaction.transaction_currency());
+    }
+
+    #[test]
+    fn should_deserialize_payment_details() {
+        let report: PaymentDetailsReport = serde_json::from_str(
+            r#"{
+                "status": "success",
+                "payments": [{
+                    "paymentId": 11591522,
+                    "paymentConversationId": "123456789",
+                    "price": 1.0,
+                    "currency": "TRY",
+                    "cancels": [{"amount": 1.1, "currency": "TRY", "createdDate": "2020-01-16 09:00:00"}],
+                    "itemTransactions": [{
+                        "paymentTransactionId": 13041690,
+                        "transactionStatus": 2,
+                        "price": 0.3,
+                        "refunds": [{"paymentTransactionId": "13041690", "refundPrice": 0.3, "currency": "TRY"}]
+                    }]
+                }]
+            }"#,
+        )
+        .unwrap();
+
+        let payment = &report.payments().unwrap()[0];
+        assert_eq!(Some(&String::from("11591522")), payment.payment_id());
+        assert_eq!(
+            Some(&BigDecimal::from(1)),
+            payment.cancels().unwrap()[0]
+                .amount()
+                .map(|amount| amount.round(0))
+                .as_ref()
+        );
+        let item = &payment.item_transactions().unwrap()[0];
+        assert_eq!(
+            Some(&TransactionStatus::Approved),
+            item.transaction_status()
+        );
+        assert_eq!(
+            Some(&String::from("13041690")),
+            item.refunds().unwrap()[0].payment_transaction_id()
+        );
+    }
+}
@@ -0,0 +1,44 @@
+pub mod resource_test {
+    use iyzipay_rust::options::AuthScheme;
+    use iyzipay_rust::options::Options;
+    use iyzipay_rust::requests::Request;
+    use iyzipay_rust::resource::IyzipayResource;
+
+    fn authorization(uri: &str, options: &Options) -> String {
+        let request = Request::new("123456789", "tr");
+        let headers = IyzipayResource::get_http_headers_for(uri, "{}", &request, options);
+        assert!(headers.contains_key("x-iyzi-rnd"));
+        headers
+            .get("Authorization")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn should_sign_with_configured_auth_scheme() {
+        let mut options = Options::new();
+        options.set_api_key("apiKey");
+        options.set_secret_key("secretKey");
+        options.set_base_url("https://sandbox-api.iyzipay.com");
+        options.set_endpoint_auth_scheme("/payment/auth", AuthScheme::V2);
+
+        assert!(authorization(
+            "https://sandbox-api.iyzipay.com/payment/bin/check",
+            &options
+        )
+        .starts_with("IYZWS apiKey:"));
+        assert!(
+            authorization("https://sandbox-api.iyzipay.com/payment/auth", &options)
+                .starts_with("IYZWSv2 ")
+        );
+
+        options.set_auth_scheme(AuthScheme::V2);
+        assert!(authorization(
+            "https://sandbox-api.iyzipay.com/payment/bin/check",
+            &options
+        )
+        .starts_with("IYZWSv2 "));
+    }
+}
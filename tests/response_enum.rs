@@ -0,0 +1,96 @@
+pub mod response_enum_test {
+    use std::str::FromStr;
+
+    use iyzipay_rust::model::BinNumber;
+    use iyzipay_rust::model::CardAssociation;
+    use iyzipay_rust::model::CardFamily;
+    use iyzipay_rust::model::CardType;
+    use iyzipay_rust::model::FraudStatus;
+    use iyzipay_rust::model::InstallmentDetail;
+    use iyzipay_rust::model::Payment;
+    use iyzipay_rust::model::PaymentStatus;
+    use iyzipay_rust::model::Phase;
+    use iyzipay_rust::model::TransactionStatus;
+
+    #[test]
+    fn should_deserialize_payment_enums() {
+        let payment: Payment = serde_json::from_str(
+            r#"{"status": "success", "paymentStatus": "SUCCESS", "fraudStatus": 1, "cardType": "CREDIT_CARD", "cardAssociation": "MASTER_CARD", "cardFamily": "Bonus", "phase": "AUTH", "itemTransactions": [{"transactionStatus": 2}, {"transactionStatus": 1}]}"#,
+        )
+        .unwrap();
+
+        assert_eq!(Some(&PaymentStatus::Success), payment.payment_status());
+        assert_eq!(Some(&FraudStatus::Approved), payment.fraud_status());
+        assert_eq!(Some(&CardType::CreditCard), payment.card_type());
+        assert_eq!(
+            Some(&CardAssociation::MasterCard),
+            payment.card_association()
+        );
+        assert_eq!(Some(&CardFamily::Bonus), payment.card_family());
+        assert_eq!(Some(&Phase::Auth), payment.phase());
+        let payment_items = payment.payment_items().unwrap();
+        assert_eq!(
+            Some(&TransactionStatus::Approved),
+            payment_items[0].transaction_status()
+        );
+        assert_eq!(
+            Some(&TransactionStatus::WaitingForApproval),
+            payment_items[1].transaction_status()
+        );
+    }
+
+    #[test]
+    fn should_deserialize_leniently() {
+        let bin_number: BinNumber = serde_json::from_str(
+            r#"{"cardType": "debit card", "cardAssociation": "Amex", "cardFamily": "PARAF"}"#,
+        )
+        .unwrap();
+        assert_eq!(Some(&CardType::DebitCard), bin_number.card_type());
+        assert_eq!(Some(&CardAssociation::Amex), bin_number.card_association());
+        assert_eq!(Some(&CardFamily::Paraf), bin_number.card_family());
+
+        let payment: Payment =
+            serde_json::from_str(r#"{"fraudStatus": "-1", "phase": "pre-auth"}"#).unwrap();
+        assert_eq!(Some(&FraudStatus::Rejected), payment.fraud_status());
+        assert_eq!(Some(&Phase::PreAuth), payment.phase());
+    }
+
+    #[test]
+    fn should_keep_unknown_values() {
+        let installment_detail: InstallmentDetail = serde_json::from_str(
+            r#"{"cardType": "GIFT_CARD", "cardAssociation": "UNION_PAY", "cardFamilyName": "Saglam Kart"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            Some(&CardType::Unknown(String::from("GIFT_CARD"))),
+            installment_detail.card_type()
+        );
+        assert_eq!(
+            Some(&CardAssociation::Unknown(String::from("UNION_PAY"))),
+            installment_detail.card_association()
+        );
+        assert_eq!(
+            Some(&CardFamily::Unknown(String::from("Saglam Kart"))),
+            installment_detail.card_family_name()
+        );
+        assert_eq!(
+            TransactionStatus::Unknown(String::from("7")),
+            TransactionStatus::from_str("7").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_serialize_canonical_values() {
+        assert_eq!(
+            r#""MASTER_CARD""#,
+            serde_json::to_string(&CardAssociation::from("Master Card")).unwrap()
+        );
+        assert_eq!("-1", serde_json::to_string(&FraudStatus::Rejected).unwrap());
+        assert_eq!(
+            r#""GIFT_CARD""#,
+            serde_json::to_string(&CardType::from("GIFT_CARD")).unwrap()
+        );
+        assert_eq!("AMERICAN_EXPRESS", CardAssociation::Amex.to_string());
+    }
+}
@@ -16,14 +16,14 @@ use crate::get_test_options;
 fn should_initialize_apm_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateApmInitializeRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::EUR.value());
+    request.set_currency(Currency::EUR);
     request.set_country_code("DE");
-    request.set_payment_channel(PaymentChannel::Web.value());
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_channel(PaymentChannel::Web);
+    request.set_payment_group(PaymentGroup::Product);
     request.set_account_holder_name("John Doe");
     request.set_merchant_callback_url("https://www.merchant.com/callback");
     request.set_merchant_error_url("https://www.merchant.com/error");
@@ -68,7 +68,7 @@ fn should_initialize_apm_payment() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
     basket_items.push(first_basket_item);
 
@@ -77,7 +77,7 @@ fn should_initialize_apm_payment() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
     basket_items.push(second_basket_item);
 
@@ -86,7 +86,7 @@ fn should_initialize_apm_payment() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
     basket_items.push(third_basket_item);
     request.set_basket_items(basket_items);
@@ -111,7 +111,7 @@ fn should_initialize_apm_payment() {
 fn should_retrieve_apm_result() {
     let _ = env_logger::try_init();
     let mut retrieve_apm_request = RetrieveApmRequest::new();
-    retrieve_apm_request.set_locale(Locale::TR.value());
+    retrieve_apm_request.set_locale(Locale::TR);
     retrieve_apm_request.set_conversation_id("123456789");
     retrieve_apm_request.set_payment_id("1");
 
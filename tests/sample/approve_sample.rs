@@ -8,7 +8,7 @@ use crate::get_test_options;
 #[test]
 fn should_approve_payment_item() {
     let mut request = CreateApprovalRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id("1");
 
@@ -20,11 +20,11 @@ use crate::get_test_options;
 fn should_initialize_bkm() {
     let mut request = CreateBkmInitializeRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_basket_id("B67832");
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
 
     let enabled_installments = vec![2, 3, 6, 9];
@@ -70,7 +70,7 @@ fn should_initialize_bkm() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
 
     basket_items.push(first_basket_item);
@@ -81,7 +81,7 @@ fn should_initialize_bkm() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
 
     basket_items.push(second_basket_item);
@@ -91,7 +91,7 @@ fn should_initialize_bkm() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
 
     basket_items.push(third_basket_item);
@@ -118,7 +118,7 @@ fn should_initialize_bkm() {
 #[test]
 fn should_retrieve_bkm_result() {
     let mut request = RetrieveBkmRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_token("token");
 
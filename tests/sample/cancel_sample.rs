@@ -10,7 +10,7 @@ use crate::get_test_options;
 fn should_cancel_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateCancelRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id("2");
     request.set_ip("85.34.78.112");
@@ -32,7 +32,7 @@ fn should_cancel_payment() {
 fn should_cancel_payment_with_reason() {
     let _ = env_logger::try_init();
     let mut request = CreateCancelRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id("2");
     request.set_ip("85.34.78.112");
@@ -56,7 +56,7 @@ fn should_cancel_payment_with_reason() {
 fn should_cancel_fraudulent_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateCancelRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id("2");
     request.set_ip("85.34.78.112");
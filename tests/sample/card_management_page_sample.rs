@@ -18,7 +18,7 @@ fn should_initialize_card_management_page() {
     request.set_debit_card_allowed(false);
     request.set_card_user_key("card user key");
     request.set_debit_card_allowed(true);
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
 
     let card_management_page_initialize =
         CardManagementPageInitialize::create(&request, &get_test_options()).unwrap();
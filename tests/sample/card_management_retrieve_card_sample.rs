@@ -9,7 +9,7 @@ fn should_retrieve_card_management_page_cards() {
     let mut retrieve_card_management_page_card_request =
         RetrieveCardManagementPageCardRequest::new();
     retrieve_card_management_page_card_request.set_page_token("token");
-    retrieve_card_management_page_card_request.set_locale(Locale::TR.value());
+    retrieve_card_management_page_card_request.set_locale(Locale::TR);
     retrieve_card_management_page_card_request.set_conversation_id("123456");
 
     let card_management_page_card = CardManagementPageCard::retrieve(
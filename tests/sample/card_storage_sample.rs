@@ -1,6 +1,9 @@
 use iyzipay_rust::model::Card;
+use iyzipay_rust::model::CardAssociation;
+use iyzipay_rust::model::CardFamily;
 use iyzipay_rust::model::CardInformation;
 use iyzipay_rust::model::CardList;
+use iyzipay_rust::model::CardType;
 use iyzipay_rust::model::Locale;
 use iyzipay_rust::model::Status;
 use iyzipay_rust::requests::CreateCardRequest;
@@ -13,7 +16,7 @@ use crate::get_test_options;
 fn should_create_user_and_add_card() {
     let mut request: CreateCardRequest = CreateCardRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_email("email@email.com");
     request.set_external_id("external id");
@@ -38,9 +41,9 @@ fn should_create_user_and_add_card() {
     assert_eq!(None, card.error_group());
     assert_eq!(Some(&String::from("552879")), card.bin_number());
     assert_eq!(Some(&String::from("card alias")), card.card_alias());
-    assert_eq!(Some(&String::from("CREDIT_CARD")), card.card_type());
-    assert_eq!(Some(&String::from("MASTER_CARD")), card.card_association());
-    assert_eq!(Some(&String::from("Paraf")), card.card_family());
+    assert_eq!(Some(&CardType::CreditCard), card.card_type());
+    assert_eq!(Some(&CardAssociation::MasterCard), card.card_association());
+    assert_eq!(Some(&CardFamily::Paraf), card.card_family());
     assert_eq!(Some(&String::from("Halk Bankası")), card.card_bank_name());
     assert_eq!(Some(&i64::from(12)), card.card_bank_code());
     assert_ne!(None, card.card_user_key());
@@ -53,7 +56,7 @@ fn should_create_user_and_add_card() {
 fn should_create_card() {
     let mut request: CreateCardRequest = CreateCardRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_external_id("external id");
     request.set_card_user_key("card user key");
@@ -78,9 +81,9 @@ fn should_create_card() {
     assert_eq!(None, card.error_group());
     assert_eq!(Some(&String::from("552879")), card.bin_number());
     assert_eq!(Some(&String::from("card alias")), card.card_alias());
-    assert_eq!(Some(&String::from("CREDIT_CARD")), card.card_type());
-    assert_eq!(Some(&String::from("MASTER_CARD")), card.card_association());
-    assert_eq!(Some(&String::from("Paraf")), card.card_family());
+    assert_eq!(Some(&CardType::CreditCard), card.card_type());
+    assert_eq!(Some(&CardAssociation::MasterCard), card.card_association());
+    assert_eq!(Some(&CardFamily::Paraf), card.card_family());
     assert_eq!(Some(&String::from("Halk Bankası")), card.card_bank_name());
     assert_eq!(Some(&i64::from(12)), card.card_bank_code());
     assert_ne!(None, card.card_user_key());
@@ -92,7 +95,7 @@ fn should_create_card() {
 #[test]
 fn should_delete_card() {
     let mut request: DeleteCardRequest = DeleteCardRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_card_token("card token");
     request.set_card_user_key("card user key");
@@ -121,7 +124,7 @@ fn should_delete_card() {
 #[test]
 fn should_retrieve_cards() {
     let mut request: RetrieveCardListRequest = RetrieveCardListRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_card_user_key("card user key");
 
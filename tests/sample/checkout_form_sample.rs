@@ -20,13 +20,13 @@ use crate::get_test_options;
 #[test]
 fn should_initialize_checkout_form() {
     let mut request = CreateCheckoutFormInitializeRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_basket_id("B67832");
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
     request.set_debit_card_allowed(true);
 
@@ -72,7 +72,7 @@ fn should_initialize_checkout_form() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
     basket_items.push(first_basket_item);
 
@@ -81,7 +81,7 @@ fn should_initialize_checkout_form() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
     basket_items.push(second_basket_item);
 
@@ -90,7 +90,7 @@ fn should_initialize_checkout_form() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
     basket_items.push(third_basket_item);
     request.set_basket_items(basket_items);
@@ -121,7 +121,7 @@ fn should_initialize_checkout_form() {
 #[test]
 fn should_retrieve_checkout_form_result() {
     let mut request = RetrieveCheckoutFormRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_token("token");
 
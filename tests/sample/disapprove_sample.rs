@@ -9,7 +9,7 @@ use crate::get_test_options;
 fn should_disapprove_payment_item() {
     let _ = env_logger::try_init();
     let mut request = CreateApprovalRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id("1");
 
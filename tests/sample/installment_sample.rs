@@ -15,7 +15,7 @@ fn should_retrieve_installments() {
 
     let mut request: RetrieveInstallmentInfoRequest = RetrieveInstallmentInfoRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_bin_number("550960");
     request.set_price(BigDecimal::from_str("100").unwrap());
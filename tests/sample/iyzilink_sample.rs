@@ -21,7 +21,7 @@ use crate::get_test_options;
 pub fn should_create_iyzi_link() {
     let _ = env_logger::try_init();
     let mut request = IyziLinkSaveRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_name("sample-name");
     request.set_description("sample-description");
@@ -29,7 +29,7 @@ pub fn should_create_iyzi_link() {
     path.push("tests/images/sample_image.jpg");
     request.set_base64_encoded_image(encode(&fs::read(path).unwrap()));
     request.set_price(BigDecimal::one());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_address_ignorable(false);
     request.set_sold_limit(1);
     request.set_installment_requested(false);
@@ -51,12 +51,12 @@ pub fn should_create_iyzi_link() {
 pub fn should_update_iyzi_link() {
     let _ = env_logger::try_init();
     let mut request = IyziLinkSaveRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_name("ft-name-updated");
     request.set_description("ft-description-updated");
     request.set_price(BigDecimal::from_str("10").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let response = IyziLink::update("TOKEN", &request, &get_test_options()).unwrap();
 
@@ -77,7 +77,7 @@ pub fn should_retrieve_iyzi_links_with_pagination() {
     let mut paging_request = PagingRequest::new();
     paging_request.set_page(Some(1));
     paging_request.set_count(Some(1));
-    paging_request.set_locale(Locale::TR.value());
+    paging_request.set_locale(Locale::TR);
     paging_request.set_conversation_id("123456789");
 
     let response = IyziLink::retrieve_all(&paging_request, &get_test_options()).unwrap();
@@ -21,14 +21,14 @@ use crate::get_test_options;
 fn should_initialize_iyziup_form() {
     let _ = env_logger::try_init();
     let mut request = CreateIyziupFormInitializeRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
     request.set_shipping_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_merchant_order_id("B67832");
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
     request.set_terms_url("https://www.merchant.com/terms");
     request.set_pre_sales_contract_url("https://www.merchant.com/preSalesContractUrl");
@@ -48,7 +48,7 @@ fn should_initialize_iyziup_form() {
     first_order_item.set_category2("Accessories");
     first_order_item.set_item_url("www.merchant.biz/item1");
     first_order_item.set_item_description("item1 description");
-    first_order_item.set_item_type(OrderItemType::Physical.value());
+    first_order_item.set_item_type(OrderItemType::Physical);
     first_order_item.set_price(BigDecimal::from_str("0.3").unwrap());
     order_items.push(first_order_item);
 
@@ -59,7 +59,7 @@ fn should_initialize_iyziup_form() {
     second_order_item.set_category2("Online Game Items");
     second_order_item.set_item_url("www.merchant.biz/item2");
     second_order_item.set_item_description("item2 description");
-    second_order_item.set_item_type(OrderItemType::Virtual.value());
+    second_order_item.set_item_type(OrderItemType::Virtual);
     second_order_item.set_price(BigDecimal::from_str("0.5").unwrap());
     order_items.push(second_order_item);
 
@@ -70,7 +70,7 @@ fn should_initialize_iyziup_form() {
     third_order_item.set_category2("Usb / Cable");
     third_order_item.set_item_url("www.merchant.biz/item3");
     third_order_item.set_item_description("item3 description");
-    third_order_item.set_item_type(OrderItemType::Physical.value());
+    third_order_item.set_item_type(OrderItemType::Physical);
     third_order_item.set_price(BigDecimal::from_str("0.2").unwrap());
     order_items.push(third_order_item);
     request.set_order_items(order_items);
@@ -97,14 +97,14 @@ fn should_initialize_iyziup_form() {
 fn should_initialize_iyziup_form_with_initial_consumer_data() {
     let _ = env_logger::try_init();
     let mut request = CreateIyziupFormInitializeRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
     request.set_shipping_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_merchant_order_id("B67832");
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
     request.set_terms_url("https://www.merchant.com/terms");
     request.set_pre_sales_contract_url("https://www.merchant.com/preSalesContractUrl");
@@ -124,7 +124,7 @@ fn should_initialize_iyziup_form_with_initial_consumer_data() {
     first_order_item.set_category2("Accessories");
     first_order_item.set_item_url("www.merchant.biz/item1");
     first_order_item.set_item_description("item1 description");
-    first_order_item.set_item_type(OrderItemType::Physical.value());
+    first_order_item.set_item_type(OrderItemType::Physical);
     first_order_item.set_price(BigDecimal::from_str("0.3").unwrap());
     order_items.push(first_order_item);
 
@@ -135,7 +135,7 @@ fn should_initialize_iyziup_form_with_initial_consumer_data() {
     second_order_item.set_category2("Online Game Items");
     second_order_item.set_item_url("www.merchant.biz/item2");
     second_order_item.set_item_description("item2 description");
-    second_order_item.set_item_type(OrderItemType::Virtual.value());
+    second_order_item.set_item_type(OrderItemType::Virtual);
     second_order_item.set_price(BigDecimal::from_str("0.5").unwrap());
     order_items.push(second_order_item);
 
@@ -146,7 +146,7 @@ fn should_initialize_iyziup_form_with_initial_consumer_data() {
     third_order_item.set_category2("Usb / Cable");
     third_order_item.set_item_url("www.merchant.biz/item3");
     third_order_item.set_item_description("item3 description");
-    third_order_item.set_item_type(OrderItemType::Physical.value());
+    third_order_item.set_item_type(OrderItemType::Physical);
     third_order_item.set_price(BigDecimal::from_str("0.2").unwrap());
     order_items.push(third_order_item);
     request.set_order_items(order_items);
@@ -203,7 +203,7 @@ fn should_initialize_iyziup_form_with_initial_consumer_data() {
 fn should_retrieve_checkout_form_result() {
     let _ = env_logger::try_init();
     let mut request = RetrieveIyziupFormRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_token("18125922-f220-4b7a-b3cd-4ae68f82fd4c");
 
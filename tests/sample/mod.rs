@@ -15,6 +15,7 @@ mod iyziup_form_sample;
 mod payment_item_sample;
 mod payment_sample;
 mod pecco_sample;
+mod post_auth_sample;
 mod refund_sample;
 mod retrieve_payout_transaction_sample;
 mod sub_merchant_sample;
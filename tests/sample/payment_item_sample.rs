@@ -11,7 +11,7 @@ use crate::get_test_options;
 #[test]
 fn should_update_payment_item() {
     let mut request = UpdatePaymentItemRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id(9999999);
     request.set_sub_merchant_price(BigDecimal::from_str("sub-merchant-price").unwrap());
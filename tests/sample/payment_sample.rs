@@ -21,15 +21,15 @@ use crate::get_test_options;
 #[test]
 fn should_create_payment() {
     let mut request = CreatePaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::TRY.to_string());
+    request.set_currency(Currency::TRY);
     request.set_installment(1);
     request.set_basket_id("B67832".to_string());
-    request.set_payment_channel(PaymentChannel::Web.value());
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_channel(PaymentChannel::Web);
+    request.set_payment_group(PaymentGroup::Product);
 
     let mut payment_card = PaymentCard::new();
     payment_card.set_card_holder_name("John Doe");
@@ -81,7 +81,7 @@ fn should_create_payment() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
 
     basket_items.push(first_basket_item);
@@ -92,7 +92,7 @@ fn should_create_payment() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
 
     basket_items.push(second_basket_item);
@@ -102,7 +102,7 @@ fn should_create_payment() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
 
     basket_items.push(third_basket_item);
@@ -125,15 +125,15 @@ fn should_create_payment() {
 #[test]
 fn should_create_marketplace_payment() {
     let mut request = CreatePaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::TRY.to_string());
+    request.set_currency(Currency::TRY);
     request.set_installment(1);
     request.set_basket_id("B67832");
-    request.set_payment_channel(PaymentChannel::Web.value());
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_channel(PaymentChannel::Web);
+    request.set_payment_group(PaymentGroup::Product);
 
     let mut payment_card = PaymentCard::new();
     payment_card.set_card_holder_name("John Doe");
@@ -185,7 +185,7 @@ fn should_create_marketplace_payment() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
     first_basket_item.set_sub_merchant_key("sub merchant key");
     first_basket_item.set_sub_merchant_price(BigDecimal::from_str("0.27").unwrap());
@@ -198,7 +198,7 @@ fn should_create_marketplace_payment() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
     second_basket_item.set_sub_merchant_key("sub merchant key");
     second_basket_item.set_sub_merchant_price(BigDecimal::from_str("0.42").unwrap());
@@ -210,7 +210,7 @@ fn should_create_marketplace_payment() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
     third_basket_item.set_sub_merchant_key("sub merchant key");
     third_basket_item.set_sub_merchant_price(BigDecimal::from_str("0.18").unwrap());
@@ -235,15 +235,15 @@ fn should_create_marketplace_payment() {
 #[test]
 fn should_create_payment_with_registered_card() {
     let mut request = CreatePaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::TRY.to_string());
+    request.set_currency(Currency::TRY);
     request.set_installment(1);
     request.set_basket_id("B67832");
-    request.set_payment_channel(PaymentChannel::Web.value());
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_channel(PaymentChannel::Web);
+    request.set_payment_group(PaymentGroup::Product);
 
     let mut payment_card = PaymentCard::new();
     payment_card.set_card_user_key("card user key");
@@ -292,7 +292,7 @@ fn should_create_payment_with_registered_card() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
 
     basket_items.push(first_basket_item);
@@ -303,7 +303,7 @@ fn should_create_payment_with_registered_card() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
 
     basket_items.push(second_basket_item);
@@ -313,7 +313,7 @@ fn should_create_payment_with_registered_card() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
 
     basket_items.push(third_basket_item);
@@ -337,7 +337,7 @@ fn should_create_payment_with_registered_card() {
 fn should_retrieve_payment_result() {
     let mut request = RetrievePaymentRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id("1");
     request.set_payment_conversation_id("123456789");
@@ -21,13 +21,13 @@ use crate::get_test_options;
 fn should_initialize_pecco() {
     let _ = env_logger::try_init();
     let mut request = CreatePeccoInitializeRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("100000").unwrap());
     request.set_paid_price(BigDecimal::from_str("120000").unwrap());
-    request.set_currency(Currency::IRR.value());
+    request.set_currency(Currency::IRR);
     request.set_basket_id("B67832");
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
 
     let mut buyer = Buyer::new();
@@ -68,7 +68,7 @@ fn should_initialize_pecco() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("30000").unwrap());
     basket_items.push(first_basket_item);
 
@@ -77,7 +77,7 @@ fn should_initialize_pecco() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("50000").unwrap());
     basket_items.push(second_basket_item);
 
@@ -86,7 +86,7 @@ fn should_initialize_pecco() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / Cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("20000").unwrap());
     basket_items.push(third_basket_item);
     request.set_basket_items(basket_items);
@@ -115,7 +115,7 @@ fn should_initialize_pecco() {
 fn should_create_pecco_payment() {
     let _ = env_logger::try_init();
     let mut request = CreatePeccoPaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_token("token");
 
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+
+use iyzipay_rust::model::Currency;
+use iyzipay_rust::model::Locale;
+use iyzipay_rust::model::PaymentPostAuth;
+use iyzipay_rust::model::Status;
+use iyzipay_rust::requests::CreatePaymentPostAuthRequest;
+
+use crate::get_test_options;
+
+#[test]
+fn should_capture_pre_auth_payment() {
+    let _ = env_logger::try_init();
+    let mut request = CreatePaymentPostAuthRequest::new();
+    request.set_locale(Locale::TR);
+    request.set_conversation_id("123456789");
+    request.set_payment_id("1");
+    request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
+    request.set_ip("85.34.78.112");
+    request.set_currency(Currency::TRY);
+
+    let post_auth = PaymentPostAuth::create(&request, &get_test_options()).unwrap();
+
+    println!("{:?}", post_auth);
+
+    assert_eq!(Some(&Locale::TR.to_string()), post_auth.locale());
+    assert_eq!(Some(&Status::Success.to_string()), post_auth.status());
+    assert_eq!(
+        Some(&String::from("123456789")),
+        post_auth.conversation_id()
+    );
+    assert_ne!(None, post_auth.system_time());
+    assert_eq!(None, post_auth.error_code());
+    assert_eq!(None, post_auth.error_message());
+    assert_eq!(None, post_auth.error_group());
+}
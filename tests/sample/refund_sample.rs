@@ -15,11 +15,11 @@ use crate::get_test_options;
 fn should_refund_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateRefundRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id("1");
     request.set_price(BigDecimal::from_str("0.5").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_ip("85.34.78.112");
 
     let refund = Refund::create(&request, &get_test_options()).unwrap();
@@ -39,11 +39,11 @@ fn should_refund_payment() {
 fn should_refund_payment_with_reason() {
     let _ = env_logger::try_init();
     let mut request = CreateRefundRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id("13");
     request.set_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_ip("85.34.78.112");
     request.set_reason(RefundReason::DoublePayment);
     request.set_description("customer requested for default sample");
@@ -65,11 +65,11 @@ fn should_refund_payment_with_reason() {
 fn should_refund_fraudulent_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateRefundRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_transaction_id("13");
     request.set_price(BigDecimal::from_str("0.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_ip("85.34.78.112");
     request.set_reason(RefundReason::Fraud);
     request.set_description("stolen card request with 11000 try payment for default sample");
@@ -10,7 +10,7 @@ use crate::get_test_options;
 fn should_retrieve_payout_completed_transactions() {
     let _ = env_logger::try_init();
     let mut request = RetrieveTransactionsRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_date("2016-01-22 19:13:00");
 
@@ -41,7 +41,7 @@ fn should_retrieve_payout_completed_transactions() {
 fn should_retrieve_bounced_bank_transfers() {
     let _ = env_logger::try_init();
     let mut request = RetrieveTransactionsRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_date("2016-01-22 19:13:00");
 
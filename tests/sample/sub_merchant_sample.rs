@@ -12,7 +12,7 @@ use crate::get_test_options;
 #[test]
 fn should_create_personal_sub_merchant() {
     let mut request = CreateSubMerchantRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_external_id("B49224");
     request.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
@@ -23,8 +23,8 @@ fn should_create_personal_sub_merchant() {
     request.set_name("John's market");
     request.set_iban("TR180006200119000006672315");
     request.set_identity_number("31300864726");
-    request.set_currency(Currency::TRY.value());
-    request.set_sub_merchant_type(SubMerchantType::Personal.value());
+    request.set_currency(Currency::TRY);
+    request.set_sub_merchant_type(SubMerchantType::Personal);
 
     let sub_merchant = SubMerchant::create(&request, &get_test_options()).unwrap();
 
@@ -45,10 +45,10 @@ fn should_create_personal_sub_merchant() {
 #[test]
 fn should_create_private_sub_merchant() {
     let mut request = CreateSubMerchantRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_external_id("S49222");
-    request.set_sub_merchant_type(SubMerchantType::PrivateCompany.value());
+    request.set_sub_merchant_type(SubMerchantType::PrivateCompany);
     request.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
     request.set_tax_office("Tax office");
     request.set_legal_company_title("John Doe inc");
@@ -57,7 +57,7 @@ fn should_create_private_sub_merchant() {
     request.set_name("John's market");
     request.set_iban("TR180006200119000006672315");
     request.set_identity_number("31300864726");
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let sub_merchant = SubMerchant::create(&request, &get_test_options()).unwrap();
 
@@ -78,10 +78,10 @@ fn should_create_private_sub_merchant() {
 #[test]
 fn should_create_limited_company_sub_merchant() {
     let mut request = CreateSubMerchantRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_external_id("AS49224");
-    request.set_sub_merchant_type(SubMerchantType::LimitedOrJointStockCompany.value());
+    request.set_sub_merchant_type(SubMerchantType::LimitedOrJointStockCompany);
     request.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
     request.set_tax_office("Tax office");
     request.set_tax_number("9261877");
@@ -91,7 +91,7 @@ fn should_create_limited_company_sub_merchant() {
     request.set_name("John's market");
     request.set_iban("TR180006200119000006672315");
     request.set_identity_number("31300864726");
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let sub_merchant = SubMerchant::create(&request, &get_test_options()).unwrap();
 
@@ -113,7 +113,7 @@ fn should_create_limited_company_sub_merchant() {
 fn should_update_personal_sub_merchant() {
     let mut update_request = UpdateSubMerchantRequest::new();
 
-    update_request.set_locale(Locale::TR.value());
+    update_request.set_locale(Locale::TR);
     update_request.set_conversation_id("123456789");
     update_request.set_sub_merchant_key("sub merchant key");
     update_request.set_iban("TR630006200027700006678204");
@@ -124,7 +124,7 @@ fn should_update_personal_sub_merchant() {
     update_request.set_gsm_number("+905350000000");
     update_request.set_name("Jane's market");
     update_request.set_identity_number("31300864726");
-    update_request.set_currency(Currency::TRY.value());
+    update_request.set_currency(Currency::TRY);
 
     let sub_merchant = SubMerchant::update(&update_request, &get_test_options()).unwrap();
 
@@ -146,7 +146,7 @@ fn should_update_personal_sub_merchant() {
 fn should_update_private_sub_merchant() {
     let mut request = UpdateSubMerchantRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_key("sub merchant key");
     request.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
@@ -157,7 +157,7 @@ fn should_update_private_sub_merchant() {
     request.set_name("Jane's market");
     request.set_iban("TR630006200027700006678204");
     request.set_identity_number("31300864726");
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let sub_merchant = SubMerchant::update(&request, &get_test_options()).unwrap();
 
@@ -179,7 +179,7 @@ fn should_update_private_sub_merchant() {
 fn should_update_limited_company_sub_merchant() {
     let mut request = UpdateSubMerchantRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_key("sub merchant key");
     request.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
@@ -190,7 +190,7 @@ fn should_update_limited_company_sub_merchant() {
     request.set_gsm_number("+905350000000");
     request.set_name("Jane's market");
     request.set_iban("TR630006200027700006678204");
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
 
     let sub_merchant = SubMerchant::update(&request, &get_test_options()).unwrap();
 
@@ -212,7 +212,7 @@ fn should_update_limited_company_sub_merchant() {
 fn should_retrieve_sub_merchant() {
     let mut request = RetrieveSubMerchantRequest::new();
 
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_sub_merchant_external_id("AS49224");
 
@@ -23,15 +23,15 @@ use crate::get_test_options;
 fn should_initialize_threeds() {
     let _ = env_logger::try_init();
     let mut request = CreatePaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_price(BigDecimal::from_str("1").unwrap());
     request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
-    request.set_currency(Currency::TRY.value());
+    request.set_currency(Currency::TRY);
     request.set_installment(1);
     request.set_basket_id("B67832");
-    request.set_payment_channel(PaymentChannel::Web.value());
-    request.set_payment_group(PaymentGroup::Product.value());
+    request.set_payment_channel(PaymentChannel::Web);
+    request.set_payment_group(PaymentGroup::Product);
     request.set_callback_url("https://www.merchant.com/callback");
 
     let mut payment_card = PaymentCard::new();
@@ -81,7 +81,7 @@ fn should_initialize_threeds() {
     first_basket_item.set_name("Binocular");
     first_basket_item.set_category1("Collectibles");
     first_basket_item.set_category2("Accessories");
-    first_basket_item.set_item_type(BasketItemType::Physical.value());
+    first_basket_item.set_item_type(BasketItemType::Physical);
     first_basket_item.set_price(BigDecimal::from_str("0.3").unwrap());
     basket_items.push(first_basket_item);
 
@@ -90,7 +90,7 @@ fn should_initialize_threeds() {
     second_basket_item.set_name("Game code");
     second_basket_item.set_category1("Game");
     second_basket_item.set_category2("Online Game Items");
-    second_basket_item.set_item_type(BasketItemType::Virtual.value());
+    second_basket_item.set_item_type(BasketItemType::Virtual);
     second_basket_item.set_price(BigDecimal::from_str("0.5").unwrap());
     basket_items.push(second_basket_item);
 
@@ -99,7 +99,7 @@ fn should_initialize_threeds() {
     third_basket_item.set_name("Usb");
     third_basket_item.set_category1("Electronics");
     third_basket_item.set_category2("Usb / cable");
-    third_basket_item.set_item_type(BasketItemType::Physical.value());
+    third_basket_item.set_item_type(BasketItemType::Physical);
     third_basket_item.set_price(BigDecimal::from_str("0.2").unwrap());
     basket_items.push(third_basket_item);
     request.set_basket_items(basket_items);
@@ -128,7 +128,7 @@ fn should_initialize_threeds() {
 fn should_create_threeds_payment() {
     let _ = env_logger::try_init();
     let mut request = CreateThreedsPaymentRequest::new();
-    request.set_locale(Locale::TR.value());
+    request.set_locale(Locale::TR);
     request.set_conversation_id("123456789");
     request.set_payment_id("1");
     request.set_conversation_data("conversation data");
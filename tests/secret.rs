@@ -0,0 +1,74 @@
+pub mod secret_test {
+    use iyzipay_rust::model::CardInformation;
+    use iyzipay_rust::model::PaymentCard;
+    use iyzipay_rust::options::Options;
+    use iyzipay_rust::requests::PKISerialize;
+    use iyzipay_rust::secret::Secret;
+
+    fn payment_card() -> PaymentCard {
+        let mut payment_card = PaymentCard::new();
+        payment_card.set_card_holder_name("John Doe");
+        payment_card.set_card_number("5528790000000008");
+        payment_card.set_expire_month("12");
+        payment_card.set_expire_year("2030");
+        payment_card.set_cvc("123");
+        payment_card
+    }
+
+    #[test]
+    fn should_mask_secret_in_debug() {
+        let secret = Secret::new("5528790000000008");
+
+        assert_eq!("***", format!("{:?}", secret));
+        assert_eq!("5528790000000008", secret.expose());
+        assert_eq!("5528790000000008", secret.clone().expose());
+
+        let card_number = Secret::card_number("5528790000000008");
+        assert_eq!("552879******0008", format!("{:?}", card_number));
+        assert_eq!("5528790000000008", card_number.expose());
+    }
+
+    #[test]
+    fn should_mask_card_fields_in_debug() {
+        let debug = format!("{:?}", payment_card());
+
+        assert!(!debug.contains("5528790000000008"));
+        assert!(!debug.contains("123"));
+        assert!(!debug.contains("2030"));
+        assert!(debug.contains("card_number: Some(552879******0008)"));
+        assert!(debug.contains("John Doe"));
+
+        let mut card_information = CardInformation::new();
+        card_information.set_card_number("5528790000000008");
+        assert!(!format!("{:?}", card_information).contains("5528790000000008"));
+    }
+
+    #[test]
+    fn should_serialize_real_values() {
+        let payment_card = payment_card();
+
+        let json = serde_json::to_string(&payment_card).unwrap();
+        assert!(json.contains(r#""cardNumber":"5528790000000008""#));
+        assert!(json.contains(r#""cvc":"123""#));
+        assert_eq!(
+            Some(String::from("[cardHolderName=John Doe,cardNumber=5528790000000008,expireYear=2030,expireMonth=12,cvc=123]")),
+            PKISerialize::serialize(&payment_card)
+        );
+
+        let deserialized: PaymentCard = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            Some("5528790000000008"),
+            deserialized.card_number().map(Secret::expose)
+        );
+        assert!(format!("{:?}", deserialized).contains("552879******0008"));
+    }
+
+    #[test]
+    fn should_keep_secret_key_out_of_debug() {
+        let mut options = Options::new();
+        options.set_secret_key(String::from("secret key"));
+
+        assert_eq!("secret key", options.secret_key());
+        assert!(!format!("{:?}", options).contains("secret key"));
+    }
+}
@@ -0,0 +1,166 @@
+pub mod subscription_test {
+    use bigdecimal::BigDecimal;
+    use chrono::NaiveDate;
+    use chrono::TimeZone;
+    use chrono::Utc;
+
+    use iyzipay_rust::model::Address;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::PaymentInterval;
+    use iyzipay_rust::model::SubscriptionItem;
+    use iyzipay_rust::model::SubscriptionOrderStatus;
+    use iyzipay_rust::model::SubscriptionPaging;
+    use iyzipay_rust::model::SubscriptionResource;
+    use iyzipay_rust::model::SubscriptionStatus;
+    use iyzipay_rust::requests::CreatePricingPlanRequest;
+    use iyzipay_rust::requests::PagingRequest;
+    use iyzipay_rust::requests::Request;
+    use iyzipay_rust::requests::RequestQueryParams;
+    use iyzipay_rust::requests::SearchSubscriptionsRequest;
+    use iyzipay_rust::requests::SubscriptionCustomerDetails;
+    use iyzipay_rust::requests::SubscriptionCustomerRequest;
+    use iyzipay_rust::requests::Validate;
+
+    #[test]
+    fn should_build_search_query_params() {
+        let mut request = SearchSubscriptionsRequest::new();
+        request.set_conversation_id("123456789");
+        request.set_customer_reference_code("customer ref");
+        request.set_subscription_status(SubscriptionStatus::Active);
+        request.set_start_date(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+        request.set_page(2u32);
+        request.set_count(10u32);
+
+        assert_eq!(
+            "?conversationId=123456789&customerReferenceCode=customer%20ref\
+             &subscriptionStatus=ACTIVE&startDate=2020-01-01%2000%3A00%3A00&page=2&count=10",
+            request.get_query_params()
+        );
+        assert_eq!("", SearchSubscriptionsRequest::new().get_query_params());
+    }
+
+    #[test]
+    fn should_build_request_query_params() {
+        let mut request = Request::default();
+        request.set_conversation_id("123456789");
+        assert_eq!("?conversationId=123456789", request.get_query_params());
+
+        request.set_conversation_id("12 & 34=?");
+        request.set_locale_raw("tr");
+        assert_eq!(
+            "?conversationId=12%20%26%2034%3D%3F&locale=tr",
+            request.get_query_params()
+        );
+
+        let mut paging_request = PagingRequest::new();
+        paging_request.set_page(300);
+        assert_eq!("?page=300", paging_request.get_query_params());
+
+        paging_request.set_count(10);
+        paging_request.set_locale_raw("en");
+        assert_eq!(
+            "?locale=en&page=300&count=10",
+            paging_request.get_query_params()
+        );
+        assert_eq!("", PagingRequest::new().get_query_params());
+    }
+
+    #[test]
+    fn should_flatten_customer_into_request() {
+        let mut address = Address::new();
+        address.set_contact_name("Jane Doe");
+        address.set_city("Istanbul");
+        address.set_country("Turkey");
+        address.set_address("Nidakule Göztepe, Merdivenköy Mah. Bora Sok. No:1");
+        let mut customer = SubscriptionCustomerDetails::new();
+        customer.set_name("John");
+        customer.set_surname("Doe");
+        customer.set_email(" email@email.com ");
+        customer.set_gsm_number("0535 000 00 00");
+        customer.set_billing_address(address);
+        let mut request = SubscriptionCustomerRequest::new();
+        request.set_locale_raw("tr");
+        request.set_customer(customer);
+
+        let json = serde_json::to_value(&request).unwrap();
+
+        assert_eq!("John", json["name"]);
+        assert_eq!("email@email.com", json["email"]);
+        assert_eq!("+905350000000", json["gsmNumber"]);
+        assert_eq!("Istanbul", json["billingAddress"]["city"]);
+        assert_eq!("tr", json["locale"]);
+        assert!(request.validate().is_ok());
+    }
+
+    #[test]
+    fn should_validate_pricing_plan() {
+        let mut request = CreatePricingPlanRequest::new();
+        request.set_name("monthly");
+        request.set_payment_interval_count(0u32);
+
+        let errors = request.validate().unwrap_err().to_string();
+
+        assert!(errors.contains("price"));
+        assert!(errors.contains("currencyCode"));
+        assert!(errors.contains("paymentInterval"));
+        assert!(errors.contains("paymentIntervalCount"));
+
+        request.set_price(BigDecimal::from(10));
+        request.set_currency(Currency::TRY);
+        request.set_payment_interval(PaymentInterval::Monthly);
+        request.set_payment_interval_count(1u32);
+
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            "RECURRING",
+            serde_json::to_value(&request).unwrap()["planPaymentType"]
+        );
+    }
+
+    #[test]
+    fn should_deserialize_subscription_search_response() {
+        let response: SubscriptionResource<SubscriptionPaging<SubscriptionItem>> =
+            serde_json::from_str(
+                r#"{
+                    "status": "success",
+                    "systemTime": 1577836800000,
+                    "data": {
+                        "totalCount": 1,
+                        "currentPage": 1,
+                        "pageCount": 1,
+                        "items": [{
+                            "referenceCode": "subscription-ref",
+                            "subscriptionStatus": "ACTIVE",
+                            "trialDays": 0,
+                            "createdDate": 1577836800000,
+                            "orders": [{
+                                "referenceCode": "order-ref",
+                                "price": 10.0,
+                                "currencyCode": "TRY",
+                                "orderStatus": "WAITING"
+                            }]
+                        }]
+                    }
+                }"#,
+            )
+            .unwrap();
+
+        assert_eq!(Some(&String::from("success")), response.status());
+        let paging = response.data().unwrap();
+        assert_eq!(Some(&1), paging.total_count());
+        let subscription = &paging.items().unwrap()[0];
+        assert_eq!(
+            Some(&SubscriptionStatus::Active),
+            subscription.subscription_status()
+        );
+        assert_eq!(
+            Some(&Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap()),
+            subscription.created_date()
+        );
+        let order = &subscription.orders().unwrap()[0];
+        assert_eq!(
+            Some(&SubscriptionOrderStatus::Waiting),
+            order.order_status()
+        );
+    }
+}
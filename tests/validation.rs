@@ -0,0 +1,310 @@
+pub mod validation_test {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+
+    use iyzipay_rust::model::BasicThreedsInitialize;
+    use iyzipay_rust::model::BasicThreedsInitializePreAuth;
+    use iyzipay_rust::model::BasketItem;
+    use iyzipay_rust::model::BasketItemType;
+    use iyzipay_rust::model::Buyer;
+    use iyzipay_rust::model::Currency;
+    use iyzipay_rust::model::InstallmentPrice;
+    use iyzipay_rust::model::Payment;
+    use iyzipay_rust::model::PaymentCard;
+    use iyzipay_rust::model::PaymentPreAuth;
+    use iyzipay_rust::model::Reward;
+    use iyzipay_rust::model::SubMerchant;
+    use iyzipay_rust::options::Options;
+    use iyzipay_rust::requests::CreateBasicPaymentRequest;
+    use iyzipay_rust::requests::CreateBkmInitializeRequest;
+    use iyzipay_rust::requests::CreateCheckoutFormInitializeRequest;
+    use iyzipay_rust::requests::CreateCrossBookingRequest;
+    use iyzipay_rust::requests::CreatePaymentRequest;
+    use iyzipay_rust::requests::CreatePeccoInitializeRequest;
+    use iyzipay_rust::requests::Validate;
+    use iyzipay_rust::requests::ValidationErrors;
+
+    fn basket_item(id: &str, price: &str) -> BasketItem {
+        let mut basket_item = BasketItem::new();
+        basket_item.set_id(id);
+        basket_item.set_name("Binocular");
+        basket_item.set_category1("Collectibles");
+        basket_item.set_item_type(BasketItemType::Physical);
+        basket_item.set_price(BigDecimal::from_str(price).unwrap());
+        basket_item
+    }
+
+    fn buyer() -> Buyer {
+        let mut buyer = Buyer::new();
+        buyer.set_name("John");
+        buyer.set_surname("Doe");
+        buyer.set_identity_number("10000000146");
+        buyer
+    }
+
+    fn payment_card() -> PaymentCard {
+        let mut payment_card = PaymentCard::new();
+        payment_card.set_card_holder_name("John Doe");
+        payment_card.set_card_number("5528790000000008");
+        payment_card.set_expire_month("12");
+        payment_card.set_expire_year("2099");
+        payment_card.set_cvc("123");
+        payment_card
+    }
+
+    fn payment_request() -> CreatePaymentRequest {
+        let mut request = CreatePaymentRequest::new();
+        request.set_payment_card(payment_card());
+        request.set_price(BigDecimal::from_str("1").unwrap());
+        request.set_paid_price(BigDecimal::from_str("1.2").unwrap());
+        request.set_installment(1);
+        request.set_buyer(buyer());
+        request.set_basket_items(vec![
+            basket_item("BI101", "0.3"),
+            basket_item("BI102", "0.5"),
+            basket_item("BI103", "0.2"),
+        ]);
+        request
+    }
+
+    fn paths(errors: ValidationErrors) -> Vec<String> {
+        errors
+            .violations()
+            .iter()
+            .map(|violation| violation.path().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn should_accept_valid_payment_request() {
+        assert_eq!(Ok(()), payment_request().validate());
+    }
+
+    #[test]
+    fn should_return_every_violation_of_payment_request() {
+        let mut request = payment_request();
+        request.set_paid_price(BigDecimal::from_str("0.9").unwrap());
+        request.set_installment(13);
+        let mut payment_card = payment_card();
+        payment_card.set_cvc("12");
+        request.set_payment_card(payment_card);
+        let mut buyer = buyer();
+        buyer.set_name("J".repeat(256));
+        buyer.set_identity_number("10000000147");
+        request.set_buyer(buyer);
+        let mut incomplete_item = BasketItem::new();
+        incomplete_item.set_price(BigDecimal::from_str("0.3").unwrap());
+        request.set_basket_items(vec![incomplete_item, basket_item("BI102", "0.5")]);
+
+        let errors = request.validate().unwrap_err();
+
+        assert_eq!(
+            vec![
+                "basketItems[0].id",
+                "basketItems[0].name",
+                "basketItems[0].category1",
+                "basketItems[0].itemType",
+                "price",
+                "paidPrice",
+                "installment",
+                "paymentCard.cvc",
+                "buyer.name",
+                "buyer.identityNumber",
+            ],
+            paths(errors.clone())
+        );
+        assert_eq!(
+            "must equal the sum of basket item prices (0.8)",
+            errors.of("price")[0].message()
+        );
+        assert_eq!(
+            "installment: must be between 1 and 12",
+            errors.of("installment")[0].to_string()
+        );
+    }
+
+    #[test]
+    fn should_reject_empty_basket() {
+        let mut request = payment_request();
+        request.set_basket_items(vec![]);
+
+        assert_eq!(vec!["basketItems"], paths(request.validate().unwrap_err()));
+    }
+
+    #[test]
+    fn should_require_sub_merchants_in_marketplace() {
+        let mut request = payment_request();
+        let mut item = basket_item("BI101", "1");
+        item.set_sub_merchant_key("sub merchant key");
+        request.set_basket_items(vec![item]);
+
+        assert_eq!(
+            vec!["basketItems[0].subMerchantPrice"],
+            paths(request.validate().unwrap_err())
+        );
+
+        let mut item = basket_item("BI101", "1");
+        item.set_sub_merchant_key("sub merchant key");
+        item.set_sub_merchant_price(BigDecimal::from_str("1.1").unwrap());
+        request.set_basket_items(vec![item, basket_item("BI102", "0")]);
+        request.set_price(BigDecimal::from_str("1").unwrap());
+
+        assert_eq!(
+            vec![
+                "basketItems[0].subMerchantPrice",
+                "basketItems[1].price",
+                "basketItems[1].subMerchantKey",
+                "basketItems[1].subMerchantPrice",
+            ],
+            paths(request.validate_marketplace().unwrap_err())
+        );
+    }
+
+    #[test]
+    fn should_validate_reward_amount() {
+        let mut request = payment_request();
+        let mut reward = Reward::new();
+        reward.set_reward_amount(BigDecimal::from_str("1.5").unwrap());
+        reward.set_reward_usage(1);
+        let mut rewarded_card = payment_card();
+        rewarded_card.set_reward(reward);
+        request.set_payment_card(rewarded_card);
+
+        assert_eq!(
+            vec!["paymentCard.reward.rewardAmount"],
+            paths(request.validate().unwrap_err())
+        );
+
+        let mut rewarded_card = payment_card();
+        rewarded_card.set_reward(Reward::new());
+        request.set_payment_card(rewarded_card);
+
+        assert_eq!(
+            vec!["paymentCard.reward.rewardAmount"],
+            paths(request.validate().unwrap_err())
+        );
+    }
+
+    #[test]
+    fn should_select_campaign_installment_price() {
+        let installment_price: InstallmentPrice = serde_json::from_str(
+            r#"{"installmentNumber": 3, "totalPrice": "1.2", "campaignId": "campaign id", "plusInstallment": 2, "deferralMonths": 1}"#,
+        )
+        .unwrap();
+        let mut request = payment_request();
+        request.select_installment_price(&installment_price);
+
+        assert!(installment_price.is_campaign());
+        assert_eq!(Some(&2), installment_price.plus_installment());
+        assert_eq!(Some(&1), installment_price.deferral_months());
+        assert_eq!(Some(&3), request.installment());
+        assert_eq!(Some(&String::from("campaign id")), request.campaign_id());
+        assert_eq!(Ok(()), request.validate());
+
+        let mut request = CreatePaymentRequest::new();
+        request.set_campaign_id("campaign id");
+
+        assert!(paths(request.validate().unwrap_err()).contains(&String::from("installment")));
+    }
+
+    #[test]
+    fn should_validate_other_payment_requests() {
+        let mut checkout_request = CreateCheckoutFormInitializeRequest::new();
+        checkout_request.set_price(BigDecimal::from_str("1").unwrap());
+        checkout_request.set_buyer(buyer());
+        checkout_request.set_basket_items(vec![basket_item("BI101", "1")]);
+        checkout_request.set_enabled_installments(vec![1, 0]);
+        assert_eq!(
+            vec!["paidPrice", "enabledInstallments[1]"],
+            paths(checkout_request.validate().unwrap_err())
+        );
+
+        let mut bkm_request = CreateBkmInitializeRequest::new();
+        bkm_request.set_price(BigDecimal::from_str("1").unwrap());
+        bkm_request.set_buyer(buyer());
+        bkm_request.set_basket_items(vec![basket_item("BI101", "1")]);
+        assert_eq!(Ok(()), bkm_request.validate());
+
+        let pecco_request = CreatePeccoInitializeRequest::new();
+        assert_eq!(
+            vec!["price", "basketItems", "paidPrice", "buyer"],
+            paths(pecco_request.validate().unwrap_err())
+        );
+    }
+
+    #[test]
+    fn should_require_currency_of_cross_booking() {
+        let mut request = CreateCrossBookingRequest::new();
+        request.set_sub_merchant_key("sub merchant key");
+        request.set_price(BigDecimal::from_str("1").unwrap());
+
+        assert_eq!(vec!["currency"], paths(request.validate().unwrap_err()));
+
+        request.set_currency(Currency::TRY);
+        assert_eq!(Ok(()), request.validate());
+    }
+
+    #[test]
+    fn should_not_settle_to_balance_without_sub_merchant_key() {
+        let mut options = Options::new();
+        options.set_base_url("http://127.0.0.1:9");
+        let sub_merchant: SubMerchant =
+            serde_json::from_str(r#"{"status": "failure", "errorCode": "12"}"#).unwrap();
+
+        let error = sub_merchant
+            .init_settlement_to_balance("https://www.merchant.com/callback", &options)
+            .unwrap_err();
+
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(vec!["subMerchantKey"], paths(errors.clone()));
+    }
+
+    #[test]
+    fn should_validate_before_sending_when_enabled() {
+        let mut options = Options::new();
+        options.set_base_url("http://127.0.0.1:9");
+        options.set_validate_requests(true);
+        let request = CreatePaymentRequest::new();
+
+        let error = Payment::create(&request, &options).unwrap_err();
+
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(Ok(()), payment_request().validate_for(&options));
+        assert_ne!(0, errors.violations().len());
+
+        options.set_marketplace(true);
+        assert!(payment_request().validate_for(&options).is_err());
+    }
+
+    #[test]
+    fn should_not_void_pre_auth_without_payment_id() {
+        let mut options = Options::new();
+        options.set_base_url("http://127.0.0.1:9");
+        let pre_auth: PaymentPreAuth =
+            serde_json::from_str(r#"{"status": "failure", "errorCode": "12"}"#).unwrap();
+
+        let error = pre_auth.void(&options).unwrap_err();
+
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(vec!["paymentId"], paths(errors.clone()));
+    }
+
+    #[test]
+    fn should_not_initialize_basic_threeds_without_callback_url() {
+        let mut options = Options::new();
+        options.set_base_url("http://127.0.0.1:9");
+        let mut request = CreateBasicPaymentRequest::new();
+        request.set_price(BigDecimal::from_str("1").unwrap());
+        request.set_paid_price(BigDecimal::from_str("1").unwrap());
+        request.set_payment_card(payment_card());
+
+        let error = BasicThreedsInitialize::create(&request, &options).unwrap_err();
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(vec!["callbackUrl"], paths(errors.clone()));
+
+        let error = BasicThreedsInitializePreAuth::create(&request, &options).unwrap_err();
+        let errors = error.downcast_ref::<ValidationErrors>().unwrap();
+        assert_eq!(vec!["callbackUrl"], paths(errors.clone()));
+    }
+}
@@ -0,0 +1,202 @@
+pub mod verification_test {
+    use std::time::Duration;
+
+    use chrono::Utc;
+
+    use iyzipay_rust::hash::HashGenerator;
+    use iyzipay_rust::options::AuthScheme;
+    use iyzipay_rust::options::Options;
+    use iyzipay_rust::requests::PKISerialize;
+    use iyzipay_rust::requests::Request;
+    use iyzipay_rust::resource::IyzipayResource;
+    use iyzipay_rust::verification::AuthVerificationError;
+    use iyzipay_rust::verification::AuthVerifier;
+    use iyzipay_rust::verification::ReplayProtection;
+
+    const URI: &str = "https://sandbox-api.iyzipay.com/payment/auth";
+    const BODY: &str = "{\"locale\":\"tr\",\"conversationId\":\"123456789\"}";
+
+    fn options(secret_key: &'static str) -> Options {
+        let mut options = Options::new();
+        options.set_api_key("apiKey");
+        options.set_secret_key(secret_key);
+        options.set_base_url("https://sandbox-api.iyzipay.com");
+        options
+    }
+
+    fn secret_provider(api_key: &str) -> Option<String> {
+        match api_key {
+            "apiKey" => Some(String::from("secretKey")),
+            _ => None,
+        }
+    }
+
+    fn headers(auth_scheme: AuthScheme, secret_key: &'static str) -> (String, String) {
+        let mut options = options(secret_key);
+        options.set_auth_scheme(auth_scheme);
+        let request = Request::new("123456789", "tr");
+        let headers = IyzipayResource::get_http_headers_for(URI, BODY, &request, &options);
+        (
+            headers
+                .get("Authorization")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string(),
+            headers
+                .get("x-iyzi-rnd")
+                .unwrap()
+                .to_str()
+                .unwrap()
+                .to_string(),
+        )
+    }
+
+    #[test]
+    fn should_verify_headers_generated_by_client() {
+        let verifier = AuthVerifier::new(secret_provider);
+        let request = Request::new("123456789", "tr");
+
+        for auth_scheme in [AuthScheme::V1, AuthScheme::V2].iter() {
+            let (authorization, random_key) = headers(*auth_scheme, "secretKey");
+            let verified = verifier
+                .verify(
+                    authorization.as_str(),
+                    Some(random_key.as_str()),
+                    URI,
+                    BODY,
+                    &request,
+                )
+                .unwrap();
+            assert_eq!("apiKey", verified.api_key());
+            assert_eq!(&random_key, verified.random_key());
+            assert_eq!(*auth_scheme, verified.auth_scheme());
+        }
+    }
+
+    #[test]
+    fn should_reject_wrong_secret_key() {
+        let verifier = AuthVerifier::new(secret_provider);
+        let request = Request::new("123456789", "tr");
+
+        for auth_scheme in [AuthScheme::V1, AuthScheme::V2].iter() {
+            let (authorization, random_key) = headers(*auth_scheme, "otherSecretKey");
+            assert_eq!(
+                Err(AuthVerificationError::SignatureMismatch),
+                verifier.verify(
+                    authorization.as_str(),
+                    Some(random_key.as_str()),
+                    URI,
+                    BODY,
+                    &request,
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn should_reject_tampered_request() {
+        let verifier = AuthVerifier::new(secret_provider);
+        let request = Request::new("987654321", "tr");
+
+        let (authorization, random_key) = headers(AuthScheme::V1, "secretKey");
+        assert_eq!(
+            Err(AuthVerificationError::SignatureMismatch),
+            verifier.verify_v1(
+                authorization.as_str(),
+                Some(random_key.as_str()),
+                request.serialize().unwrap().as_str()
+            )
+        );
+
+        let (authorization, random_key) = headers(AuthScheme::V2, "secretKey");
+        assert_eq!(
+            Err(AuthVerificationError::SignatureMismatch),
+            verifier.verify_v2(
+                authorization.as_str(),
+                Some(random_key.as_str()),
+                "https://sandbox-api.iyzipay.com/payment/refund",
+                BODY
+            )
+        );
+        assert_eq!(
+            Err(AuthVerificationError::RandomKeyMismatch),
+            verifier.verify_v2(authorization.as_str(), Some("other"), URI, BODY)
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_api_key_and_malformed_header() {
+        let verifier = AuthVerifier::new(secret_provider);
+        let random_key = format!("{}abcdefgh", Utc::now().timestamp_millis());
+
+        assert_eq!(
+            Err(AuthVerificationError::UnknownApiKey(String::from("other"))),
+            verifier.verify_v1("IYZWS other:signature", Some(random_key.as_str()), "")
+        );
+        assert_eq!(
+            Err(AuthVerificationError::MalformedHeader),
+            verifier.verify_v1("Bearer token", Some(random_key.as_str()), "")
+        );
+        assert_eq!(
+            Err(AuthVerificationError::MalformedHeader),
+            verifier.verify_v2("IYZWSv2 !!!", None, URI, BODY)
+        );
+        assert_eq!(
+            Err(AuthVerificationError::MissingRandomKey),
+            verifier.verify_v1("IYZWS apiKey:signature", None, "")
+        );
+    }
+
+    #[test]
+    fn should_reject_replayed_random_key() {
+        let mut verifier = AuthVerifier::new(secret_provider);
+        verifier.set_replay_protection(ReplayProtection::new(
+            Duration::from_secs(60),
+            Duration::from_secs(5),
+        ));
+        let now = Utc::now().timestamp_millis();
+        let pki_request = "[locale=tr]";
+
+        let verify = |random_key: String| {
+            let signature = HashGenerator::generate_hash(
+                "apiKey",
+                "secretKey",
+                random_key.as_str(),
+                pki_request,
+            );
+            verifier.verify_v1(
+                format!("IYZWS apiKey:{}", signature).as_str(),
+                Some(random_key.as_str()),
+                pki_request,
+            )
+        };
+
+        assert!(verify(format!("{}abcdefgh", now)).is_ok());
+        assert_eq!(
+            Err(AuthVerificationError::Expired),
+            verify(format!("{}abcdefgh", now - 120_000))
+        );
+        assert_eq!(
+            Err(AuthVerificationError::Expired),
+            verify(format!("{}abcdefgh", now + 60_000))
+        );
+        assert_eq!(
+            Err(AuthVerificationError::InvalidTimestamp),
+            verify(String::from("abcdefgh"))
+        );
+
+        verifier.set_replay_protection(None);
+        assert!(verifier
+            .verify_v1(
+                format!(
+                    "IYZWS apiKey:{}",
+                    HashGenerator::generate_hash("apiKey", "secretKey", "abcdefgh", pki_request)
+                )
+                .as_str(),
+                Some("abcdefgh"),
+                pki_request,
+            )
+            .is_ok());
+    }
+}